@@ -0,0 +1,378 @@
+#![warn(clippy::pedantic)]
+#![warn(missing_docs)]
+//! Procedural macros for the [`fluent-ansi`](https://docs.rs/fluent-ansi) crate.
+//!
+//! This crate is an implementation detail. Enable the `macros` feature of `fluent-ansi` and use the macros from
+//! there.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Span, TokenStream, TokenTree};
+
+/// The placeholder for the path of the `fluent-ansi` crate in the generated code.
+const CRATE_PLACEHOLDER: &str = "__FLUENT_ANSI_CRATE";
+/// The placeholder for the formatting arguments in the generated code.
+const ARGS_PLACEHOLDER: &str = "__FLUENT_ANSI_ARGS";
+/// The name of the formatting argument that resets the styling.
+const RESET_ARG: &str = "__fluent_ansi_reset";
+
+/// The effect names accepted in tags, with their aliases, mapped to the effect names accepted by `style!`.
+const EFFECTS: &[(&str, &str)] = &[
+    ("bold", "bold"),
+    ("b", "bold"),
+    ("faint", "faint"),
+    ("dim", "faint"),
+    ("italic", "italic"),
+    ("i", "italic"),
+    ("underline", "underline"),
+    ("u", "underline"),
+    ("curly_underline", "curly_underline"),
+    ("dotted_underline", "dotted_underline"),
+    ("dashed_underline", "dashed_underline"),
+    ("double_underline", "double_underline"),
+    ("blink", "blink"),
+    ("rapid_blink", "rapid_blink"),
+    ("reverse", "reverse"),
+    ("conceal", "conceal"),
+    ("hidden", "conceal"),
+    ("strikethrough", "strikethrough"),
+    ("s", "strikethrough"),
+    ("fraktur", "fraktur"),
+    ("framed", "framed"),
+    ("encircled", "encircled"),
+    ("overline", "overline"),
+    ("superscript", "superscript"),
+    ("subscript", "subscript"),
+];
+
+/// Implements the `styled!` macro of `fluent-ansi`.
+///
+/// The input is the path of the `fluent-ansi` crate, followed by the template string literal and the formatting
+/// arguments.
+#[proc_macro]
+pub fn styled(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let krate = tokens.next();
+    let _comma = tokens.next();
+    let Some(TokenTree::Literal(literal)) = tokens.next() else {
+        return compile_error("expected a template string literal", Span::call_site());
+    };
+    let mut args: Vec<TokenTree> = tokens.collect();
+    if matches!(args.last(), Some(TokenTree::Punct(punct)) if punct.as_char() == ',') {
+        args.pop();
+    }
+
+    let template = match translate_literal(&literal.to_string()) {
+        Ok(template) => template,
+        Err(message) => return compile_error(&message, literal.span()),
+    };
+
+    let mut named_args: Vec<String> = template
+        .styles
+        .iter()
+        .enumerate()
+        .map(|(i, items)| {
+            format!(
+                ", {} = const {{ {CRATE_PLACEHOLDER}::style!({}) }}",
+                style_arg(i),
+                items.join(", ")
+            )
+        })
+        .collect();
+    if template.resets {
+        named_args.push(format!(", {RESET_ARG} = {CRATE_PLACEHOLDER}::Reset"));
+    }
+    let code = format!(
+        "::core::format_args!({} {ARGS_PLACEHOLDER} {})",
+        template.format,
+        named_args.concat()
+    );
+    let Ok(code) = code.parse::<TokenStream>() else {
+        return compile_error("invalid template", literal.span());
+    };
+
+    let krate = TokenStream::from_iter(krate);
+    let args = TokenStream::from_iter(args);
+    let mut literal_span = Some(literal.span());
+    replace_placeholders(code, &krate, &args, &mut literal_span)
+}
+
+/// Replaces the placeholders in the generated code, and gives the template's span to the format string, so that
+/// it can capture variables from the surrounding scope.
+fn replace_placeholders(
+    code: TokenStream,
+    krate: &TokenStream,
+    args: &TokenStream,
+    literal_span: &mut Option<Span>,
+) -> TokenStream {
+    code.into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(ident) if ident.to_string() == CRATE_PLACEHOLDER => krate.clone(),
+            TokenTree::Ident(ident) if ident.to_string() == ARGS_PLACEHOLDER => args.clone(),
+            TokenTree::Group(group) => {
+                let stream = replace_placeholders(group.stream(), krate, args, literal_span);
+                TokenStream::from(TokenTree::Group(Group::new(group.delimiter(), stream)))
+            }
+            TokenTree::Literal(mut literal) => {
+                if let Some(span) = literal_span.take() {
+                    literal.set_span(span);
+                }
+                TokenStream::from(TokenTree::Literal(literal))
+            }
+            token => TokenStream::from(token),
+        })
+        .collect()
+}
+
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut literal = Literal::string(message);
+    literal.set_span(span);
+    [
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(proc_macro::Punct::new('!', proc_macro::Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            TokenStream::from(TokenTree::Literal(literal)),
+        )),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// A template translated to a format string.
+#[derive(Debug, PartialEq)]
+struct Template {
+    /// The format string literal, with the tags replaced by formatting arguments.
+    format: String,
+    /// The `style!` items of each style formatting argument.
+    styles: Vec<Vec<String>>,
+    /// Whether the reset formatting argument is used.
+    resets: bool,
+}
+
+/// Translates the source code of a template string literal.
+fn translate_literal(source: &str) -> Result<Template, String> {
+    let (Some(start), Some(end)) = (source.find('"'), source.rfind('"')) else {
+        return Err("expected a string literal".into());
+    };
+    let prefix = &source[..start];
+    if start == end || !(prefix.is_empty() || prefix.starts_with('r')) {
+        return Err("expected a string literal".into());
+    }
+
+    let mut template = translate(&source[start + 1..end])?;
+    template.format = format!("{}{}{}", &source[..=start], template.format, &source[end..]);
+    Ok(template)
+}
+
+/// Translates the content of a template to the content of a format string.
+fn translate(content: &str) -> Result<Template, String> {
+    let mut template = Template {
+        format: String::new(),
+        styles: Vec::new(),
+        resets: false,
+    };
+    // The tags that are open, with the `style!` items of each one
+    let mut open_tags: Vec<(&str, Vec<String>)> = Vec::new();
+    // The `style!` items of the last style written
+    let mut written_items: Vec<String> = Vec::new();
+
+    let mut rest = content;
+    while let Some(c) = rest.chars().next() {
+        let text_len = match c {
+            '<' if rest.starts_with("<<") => {
+                rest = &rest[1..];
+                1
+            }
+            '<' => {
+                let end = rest
+                    .find('>')
+                    .ok_or_else(|| format!("unterminated tag: `{rest}`"))?;
+                let tag = rest[1..end].trim();
+                rest = &rest[end + 1..];
+                if let Some(name) = tag.strip_prefix('/') {
+                    close_tag(&mut open_tags, name.trim())?;
+                } else {
+                    open_tags.push((tag, parse_tag(tag)?));
+                }
+                continue;
+            }
+            '{' if rest.starts_with("{{") => 2,
+            '{' => rest
+                .find('}')
+                .map(|end| end + 1)
+                .ok_or("unterminated format placeholder")?,
+            _ => c.len_utf8(),
+        };
+
+        let items: Vec<String> = open_tags
+            .iter()
+            .flat_map(|(_, items)| items.clone())
+            .collect();
+        if items != written_items {
+            if !written_items.is_empty() {
+                push_placeholder(&mut template.format, RESET_ARG);
+                template.resets = true;
+            }
+            if !items.is_empty() {
+                let index = template.styles.len();
+                push_placeholder(&mut template.format, &style_arg(index));
+                template.styles.push(items.clone());
+            }
+            written_items = items;
+        }
+
+        template.format.push_str(&rest[..text_len]);
+        rest = &rest[text_len..];
+    }
+
+    if let Some((tag, _)) = open_tags.last() {
+        return Err(format!("unclosed tag: `<{tag}>`"));
+    }
+    if !written_items.is_empty() {
+        push_placeholder(&mut template.format, RESET_ARG);
+        template.resets = true;
+    }
+    Ok(template)
+}
+
+/// Returns the name of the formatting argument for the style with the given index.
+fn style_arg(index: usize) -> String {
+    format!("__fluent_ansi_style_{index}")
+}
+
+fn push_placeholder(format: &mut String, arg: &str) {
+    format.push('{');
+    format.push_str(arg);
+    format.push('}');
+}
+
+fn close_tag(open_tags: &mut Vec<(&str, Vec<String>)>, name: &str) -> Result<(), String> {
+    match open_tags.pop() {
+        Some((tag, _)) if name.is_empty() || name == tag => Ok(()),
+        Some((tag, _)) => Err(format!("closing tag `</{name}>` does not match `<{tag}>`")),
+        None => Err(format!("closing tag `</{name}>` has no opening tag")),
+    }
+}
+
+/// Parses the content of a tag, which is a style specification, to `style!` items.
+fn parse_tag(tag: &str) -> Result<Vec<String>, String> {
+    let mut items = Vec::new();
+    let mut words = tag.split_whitespace();
+    while let Some(word) = words.next() {
+        let target = match word.to_ascii_lowercase().as_str() {
+            "on" => Some("bg"),
+            "under" => Some("underline_color"),
+            _ => None,
+        };
+        if let Some(target) = target {
+            let color = words
+                .next()
+                .ok_or_else(|| format!("`{word}` must be followed by a color"))?;
+            items.push(format!("{target} = {color:?}"));
+        } else if let Some(effect) = effect_name(word) {
+            items.push(effect.to_string());
+        } else {
+            items.push(format!("fg = {word:?}"));
+        }
+    }
+    if items.is_empty() {
+        return Err("empty tag".into());
+    }
+    Ok(items)
+}
+
+fn effect_name(word: &str) -> Option<&'static str> {
+    let word = word.to_ascii_lowercase().replace('-', "_");
+    EFFECTS
+        .iter()
+        .find(|(name, _)| *name == word)
+        .map(|&(_, effect)| effect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn plain() {
+        let template = translate("plain {x:<5} {{x}}").unwrap();
+        assert_eq!(template.format, "plain {x:<5} {{x}}");
+        assert!(template.styles.is_empty());
+        assert!(!template.resets);
+    }
+
+    #[test]
+    fn nested_tags() {
+        let template = translate("<b><red>{code}</red> x</b>: {msg}").unwrap();
+        assert_eq!(
+            template.format,
+            "{__fluent_ansi_style_0}{code}{__fluent_ansi_reset}{__fluent_ansi_style_1} x\
+             {__fluent_ansi_reset}: {msg}"
+        );
+        assert_eq!(
+            template.styles,
+            [strings(&["bold", "fg = \"red\""]), strings(&["bold"])]
+        );
+        assert!(template.resets);
+    }
+
+    #[test]
+    fn specifications() {
+        let template = translate("<Curly-Underline #222 on bright-blue under 42>x</>").unwrap();
+        assert_eq!(
+            template.styles,
+            [strings(&[
+                "curly_underline",
+                "fg = \"#222\"",
+                "bg = \"bright-blue\"",
+                "underline_color = \"42\""
+            ])]
+        );
+    }
+
+    #[test]
+    fn escaped_tag() {
+        let template = translate("a <<b> c").unwrap();
+        assert_eq!(template.format, "a <b> c");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(translate("<b>x"), Err("unclosed tag: `<b>`".into()));
+        assert_eq!(translate("<b x"), Err("unterminated tag: `<b x`".into()));
+        assert_eq!(
+            translate("<b>x</i>"),
+            Err("closing tag `</i>` does not match `<b>`".into())
+        );
+        assert_eq!(
+            translate("x</b>"),
+            Err("closing tag `</b>` has no opening tag".into())
+        );
+        assert_eq!(translate("<>x</>"), Err("empty tag".into()));
+        assert_eq!(
+            translate("<red on>x</>"),
+            Err("`on` must be followed by a color".into())
+        );
+        assert_eq!(
+            translate("{x"),
+            Err("unterminated format placeholder".into())
+        );
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!(
+            translate_literal("\"<b>x</b>\"").unwrap().format,
+            "\"{__fluent_ansi_style_0}x{__fluent_ansi_reset}\""
+        );
+        assert_eq!(
+            translate_literal("r#\"<b>\"x\"</b>\"#").unwrap().format,
+            "r#\"{__fluent_ansi_style_0}\"x\"{__fluent_ansi_reset}\"#"
+        );
+        assert!(translate_literal("b\"x\"").is_err());
+        assert!(translate_literal("42").is_err());
+    }
+}
@@ -0,0 +1,262 @@
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{Display, Formatter, Result, Write as _},
+    ops::RangeBounds,
+};
+
+use crate::{
+    Style, Styled, StyledSpans,
+    slice::column_bounds,
+    width::{EscapeState, char_width, visible_width},
+};
+
+/// An owned string made of styled and plain segments.
+///
+/// Segments are appended with [`push_styled()`](AnsiString::push_styled) and [`push_str()`](AnsiString::push_str).
+/// Adjacent segments with the same style are merged. When rendered, only the changes between the styles of
+/// adjacent segments are written, as in [`StyledSpans`]:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, AnsiString};
+///
+/// let mut line = AnsiString::new();
+/// line.push_styled(Color::RED.bold().applied_to("Error"));
+/// line.push_str(": ");
+/// line.push_styled(Effect::Italic.applied_to(42));
+///
+/// assert_eq!(line.visible_width(), 9);
+/// assert_eq!(format!("{line}"), "\x1b[1;31mError\x1b[0m: \x1b[3m42\x1b[0m");
+/// ```
+///
+/// Requires the `alloc` feature.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AnsiString {
+    segments: Vec<Styled<String>>,
+}
+
+impl AnsiString {
+    /// Creates a new, empty `AnsiString` value.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            segments: Vec::new(),
+        }
+    }
+
+    /// Appends a styled segment, rendering its content to a string.
+    pub fn push_styled<C: Display>(&mut self, styled: Styled<C>) {
+        let style = styled.get_style();
+        let content = styled.into_content();
+        let text = self.text_with_style(style);
+        // Writing to a `String` never fails, but the content's `Display` implementation may
+        let _ = write!(text, "{content}");
+        if text.is_empty() {
+            self.segments.pop();
+        }
+    }
+
+    /// Appends a plain segment, with no styling.
+    pub fn push_str(&mut self, s: &str) {
+        if !s.is_empty() {
+            self.text_with_style(Style::new()).push_str(s);
+        }
+    }
+
+    /// Gets the segments.
+    #[must_use]
+    pub fn get_segments(&self) -> &[Styled<String>] {
+        &self.segments
+    }
+
+    /// Returns whether there is no content.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Returns the visible width of the content, in columns.
+    ///
+    /// Escape sequences in the content are skipped. See the `unicode-width` feature for how the width of each
+    /// character is measured.
+    #[must_use]
+    pub fn visible_width(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|segment| visible_width(segment.get_content()))
+            .sum()
+    }
+
+    /// Returns the part of the content between the given visible columns, keeping the styles of the segments.
+    ///
+    /// Only the characters that fit entirely within the range of columns are kept, so a wide character that is cut
+    /// by either end is dropped. Escape sequences and control characters in the content are kept if they are within
+    /// the range.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, AnsiString};
+    ///
+    /// let line: AnsiString = [Color::RED.applied_to("abc"), Color::BLUE.applied_to("def")].into_iter().collect();
+    /// assert_eq!(format!("{}", line.slice_visible(2..4)), "\x1b[31mc\x1b[34md\x1b[0m");
+    /// ```
+    #[must_use]
+    pub fn slice_visible(&self, range: impl RangeBounds<usize>) -> AnsiString {
+        let (start, end) = column_bounds(&range);
+        let mut sliced = AnsiString::new();
+        let mut column = 0;
+        for segment in &self.segments {
+            if column >= end {
+                break;
+            }
+            let text = sliced.text_with_style(segment.get_style());
+            let mut state = EscapeState::Text;
+            for c in segment.get_content().chars() {
+                if state.advance(c) {
+                    let width = char_width(c);
+                    if column >= start && column + width <= end {
+                        text.push(c);
+                    }
+                    column += width;
+                } else if start <= column && column < end {
+                    text.push(c);
+                }
+            }
+            if text.is_empty() {
+                sliced.segments.pop();
+            }
+        }
+        sliced
+    }
+
+    /// Returns the text of the last segment if it has the given style, or of a new segment with that style.
+    fn text_with_style(&mut self, style: Style) -> &mut String {
+        if self
+            .segments
+            .last()
+            .is_none_or(|last| last.get_style() != style)
+        {
+            self.segments
+                .push(Styled::new(String::new()).with_style(style));
+        }
+        let last = self.segments.len() - 1;
+        self.segments[last].get_content_mut()
+    }
+}
+
+impl Display for AnsiString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        StyledSpans::new(&self.segments).fmt(f)
+    }
+}
+
+impl<C: Display> From<Styled<C>> for AnsiString {
+    fn from(styled: Styled<C>) -> Self {
+        let mut ansi_string = AnsiString::new();
+        ansi_string.push_styled(styled);
+        ansi_string
+    }
+}
+
+impl From<&str> for AnsiString {
+    fn from(s: &str) -> Self {
+        let mut ansi_string = AnsiString::new();
+        ansi_string.push_str(s);
+        ansi_string
+    }
+}
+
+impl<C: Display> Extend<Styled<C>> for AnsiString {
+    fn extend<I: IntoIterator<Item = Styled<C>>>(&mut self, iter: I) {
+        for styled in iter {
+            self.push_styled(styled);
+        }
+    }
+}
+
+impl<C: Display> FromIterator<Styled<C>> for AnsiString {
+    fn from_iter<I: IntoIterator<Item = Styled<C>>>(iter: I) -> Self {
+        let mut ansi_string = AnsiString::new();
+        ansi_string.extend(iter);
+        ansi_string
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let ansi_string = AnsiString::new();
+        assert!(ansi_string.is_empty());
+        assert_eq!(ansi_string.visible_width(), 0);
+        assert_eq!(ansi_string.to_string(), "");
+        assert_eq!(ansi_string, AnsiString::default());
+    }
+
+    #[test]
+    fn push() {
+        let mut ansi_string = AnsiString::new();
+        ansi_string.push_str("a");
+        ansi_string.push_styled(Styled::new("b").bold());
+        ansi_string.push_styled(Styled::new('c').bold());
+        ansi_string.push_str("");
+        ansi_string.push_styled(Styled::new("").italic());
+        ansi_string.push_styled(Styled::new(1).fg(Color::RED));
+        ansi_string.push_str("d");
+
+        assert!(!ansi_string.is_empty());
+        assert_eq!(
+            ansi_string.get_segments(),
+            [
+                Styled::new("a".into()),
+                Styled::new("bc".into()).bold(),
+                Styled::new("1".into()).fg(Color::RED),
+                Styled::new("d".into()),
+            ]
+        );
+        assert_eq!(ansi_string.visible_width(), 5);
+        assert_eq!(ansi_string.to_string(), "a\x1b[1mbc\x1b[0;31m1\x1b[0md");
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(AnsiString::from("a").to_string(), "a");
+        assert_eq!(
+            AnsiString::from(Color::RED.applied_to("a")).to_string(),
+            "\x1b[31ma\x1b[0m"
+        );
+
+        let ansi_string: AnsiString = [Styled::new("a").bold(), Styled::new("b")]
+            .into_iter()
+            .collect();
+        assert_eq!(ansi_string.to_string(), "\x1b[1ma\x1b[0mb");
+    }
+    #[test]
+    fn slice_visible() {
+        let ansi_string: AnsiString = [
+            Styled::new("ab").bold(),
+            Styled::new("cd"),
+            Styled::new("ef").fg(Color::RED),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(ansi_string.slice_visible(..), ansi_string);
+        assert_eq!(
+            ansi_string.slice_visible(1..5).get_segments(),
+            [
+                Styled::new("b".into()).bold(),
+                Styled::new("cd".into()),
+                Styled::new("e".into()).fg(Color::RED),
+            ]
+        );
+        assert_eq!(
+            ansi_string.slice_visible(2..=3).get_segments(),
+            [Styled::new("cd".into())]
+        );
+        assert!(ansi_string.slice_visible(6..).is_empty());
+        assert!(ansi_string.slice_visible(3..3).is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+use std::io::{Result, Write};
+
+use crate::{Style, spans::Transition};
+
+/// A writer that keeps track of the style of the terminal, and writes only the escape sequences needed to change it.
+///
+/// The style is changed with [`set_style()`](AnsiWriter::set_style), and the content is written with
+/// [`write_str()`](AnsiWriter::write_str), or with the [`Write`] implementation. When the style changes, only the
+/// SGR parameters that differ from the current style are written, like in [`StyledSpans`](crate::StyledSpans), and
+/// nothing is written when setting the current style again:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, AnsiWriter};
+///
+/// let mut writer = AnsiWriter::new(Vec::new());
+/// writer.set_style(Color::RED.bold())?;
+/// writer.write_str("Error")?;
+/// writer.set_style(Color::RED)?;
+/// writer.write_str(": ")?;
+/// writer.set_style(Color::RED)?;
+/// writer.write_str("disk full")?;
+/// writer.reset()?;
+///
+/// assert_eq!(writer.into_inner(), b"\x1b[1;31mError\x1b[22m: disk full\x1b[0m");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// The terminal is assumed to have no styling when the writer is created. The styling is not reset when the writer
+/// is dropped, so [`reset()`](AnsiWriter::reset) must be called at the end of the output.
+///
+/// Requires the `std` feature.
+#[derive(Debug)]
+pub struct AnsiWriter<W: Write> {
+    inner: W,
+    style: Style,
+}
+
+impl<W: Write> AnsiWriter<W> {
+    /// Creates a new `AnsiWriter` that writes to the given writer.
+    #[must_use]
+    pub const fn new(inner: W) -> Self {
+        Self {
+            inner,
+            style: Style::new(),
+        }
+    }
+
+    /// Gets the current style.
+    #[must_use]
+    pub const fn get_style(&self) -> Style {
+        self.style
+    }
+
+    /// Changes the current style, writing the escape sequence needed to change it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying writer fails.
+    pub fn set_style(&mut self, style: impl Into<Style>) -> Result<()> {
+        let style = style.into();
+        write!(
+            self.inner,
+            "{}",
+            Transition {
+                from: self.style,
+                to: style
+            }
+        )?;
+        self.style = style;
+        Ok(())
+    }
+
+    /// Resets the styling. It is the same as setting a plain style.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying writer fails.
+    pub fn reset(&mut self) -> Result<()> {
+        self.set_style(Style::new())
+    }
+
+    /// Writes a string with the current style.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying writer fails.
+    pub fn write_str(&mut self, s: &str) -> Result<()> {
+        self.inner.write_all(s.as_bytes())
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Writing escape sequences directly to the underlying writer makes the current style out of sync with the
+    /// terminal.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer, without resetting the styling.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Writes the bytes with the current style.
+impl<W: Write> Write for AnsiWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CustomCode, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn transitions() {
+        let mut writer = AnsiWriter::new(Vec::new());
+        assert_eq!(writer.get_style(), Style::new());
+
+        writer.write_str("a").unwrap();
+        writer.set_style(Style::new().bold()).unwrap();
+        writer.write_str("b").unwrap();
+        writer.set_style(Style::new().bold()).unwrap();
+        writer.write_all(b"c").unwrap();
+        writer
+            .set_style(Style::new().italic().fg(Color::RED))
+            .unwrap();
+        assert_eq!(writer.get_style(), Style::new().italic().fg(Color::RED));
+        writer.write_str("d").unwrap();
+        writer.reset().unwrap();
+        writer.reset().unwrap();
+        writer.write_str("e").unwrap();
+
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "a\x1b[1mbc\x1b[0;3;31md\x1b[0me"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "underline-color"))]
+    fn without_underline_color() {
+        let mut writer = AnsiWriter::new(Vec::new());
+        writer
+            .set_style(Style::new().bold().underline_color(Color::RED))
+            .unwrap();
+        writer.write_str("a").unwrap();
+        writer.set_style(Style::new().bold()).unwrap();
+        writer.write_str("b").unwrap();
+        writer.reset().unwrap();
+        writer
+            .set_style(Style::new().underline_color(Color::RED))
+            .unwrap();
+        writer.write_str("c").unwrap();
+        writer.reset().unwrap();
+
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "\x1b[1mab\x1b[0mc"
+        );
+    }
+
+    #[test]
+    fn custom_code() {
+        let mut writer = AnsiWriter::new(Vec::new());
+        writer.set_style(CustomCode::new("60").bold()).unwrap();
+        writer.set_style(Style::new().bold()).unwrap();
+        assert_eq!(writer.get_ref(), b"\x1b[1;60m\x1b[0;1m");
+
+        writer.get_mut().clear();
+        writer.flush().unwrap();
+        assert_eq!(writer.get_ref(), b"");
+    }
+}
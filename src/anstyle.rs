@@ -0,0 +1,286 @@
+//! Conversions from and to [`anstyle`] types, enabled by the `anstyle` feature.
+//!
+//! The conversions are lossless, except for:
+//! - [`Color::Default`], which is represented in `anstyle` by the absence of a color;
+//! - the effects with no counterpart in `anstyle`, like [`Effect::Overline`], which are dropped.
+
+use crate::{
+    Effect, Style, StyleSet as _,
+    color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor},
+};
+
+const EFFECTS: [(Effect, anstyle::Effects); 12] = [
+    (Effect::Bold, anstyle::Effects::BOLD),
+    (Effect::Faint, anstyle::Effects::DIMMED),
+    (Effect::Italic, anstyle::Effects::ITALIC),
+    (Effect::Underline, anstyle::Effects::UNDERLINE),
+    (Effect::CurlyUnderline, anstyle::Effects::CURLY_UNDERLINE),
+    (Effect::DottedUnderline, anstyle::Effects::DOTTED_UNDERLINE),
+    (Effect::DashedUnderline, anstyle::Effects::DASHED_UNDERLINE),
+    (Effect::Blink, anstyle::Effects::BLINK),
+    (Effect::Reverse, anstyle::Effects::INVERT),
+    (Effect::Conceal, anstyle::Effects::HIDDEN),
+    (Effect::Strikethrough, anstyle::Effects::STRIKETHROUGH),
+    (Effect::DoubleUnderline, anstyle::Effects::DOUBLE_UNDERLINE),
+];
+
+impl From<Style> for anstyle::Style {
+    fn from(style: Style) -> Self {
+        let effects = EFFECTS
+            .iter()
+            .filter(|(effect, _)| style.get_effect(*effect))
+            .fold(anstyle::Effects::new(), |effects, (_, e)| {
+                effects.insert(*e)
+            });
+
+        anstyle::Style::new()
+            .fg_color(style.fg.and_then(Into::into))
+            .bg_color(style.bg.and_then(Into::into))
+            .underline_color(style.underline_color.and_then(Into::into))
+            .effects(effects)
+    }
+}
+
+impl From<anstyle::Style> for Style {
+    fn from(style: anstyle::Style) -> Self {
+        let effects = style.get_effects();
+        let mut result = EFFECTS
+            .iter()
+            .filter(|(_, e)| effects.contains(*e))
+            .fold(Style::new(), |result, (effect, _)| {
+                result.set_effect(*effect, true)
+            });
+
+        result.fg = style.get_fg_color().map(Into::into);
+        result.bg = style.get_bg_color().map(Into::into);
+        result.underline_color = style.get_underline_color().map(Into::into);
+        result
+    }
+}
+
+/// Converts to an `anstyle` color, where [`Color::Default`] is converted to `None`.
+impl From<Color> for Option<anstyle::Color> {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Simple(simple) => Some(simple.into()),
+            Color::Indexed(indexed) => Some(indexed.into()),
+            Color::RGB(rgb) => Some(rgb.into()),
+            Color::Default => None,
+        }
+    }
+}
+
+impl From<anstyle::Color> for Color {
+    fn from(color: anstyle::Color) -> Self {
+        match color {
+            anstyle::Color::Ansi(ansi) => Color::Simple(ansi.into()),
+            anstyle::Color::Ansi256(indexed) => Color::Indexed(indexed.into()),
+            anstyle::Color::Rgb(rgb) => Color::RGB(rgb.into()),
+        }
+    }
+}
+
+impl From<BasicColor> for anstyle::Color {
+    fn from(color: BasicColor) -> Self {
+        color.to_simple_color().into()
+    }
+}
+
+impl From<BasicColor> for anstyle::AnsiColor {
+    fn from(color: BasicColor) -> Self {
+        color.to_simple_color().into()
+    }
+}
+
+impl From<SimpleColor> for anstyle::Color {
+    fn from(color: SimpleColor) -> Self {
+        anstyle::Color::Ansi(color.into())
+    }
+}
+
+impl From<SimpleColor> for anstyle::AnsiColor {
+    fn from(color: SimpleColor) -> Self {
+        use anstyle::AnsiColor as A;
+
+        match (color.get_basic_color(), color.is_bright()) {
+            (BasicColor::Black, false) => A::Black,
+            (BasicColor::Red, false) => A::Red,
+            (BasicColor::Green, false) => A::Green,
+            (BasicColor::Yellow, false) => A::Yellow,
+            (BasicColor::Blue, false) => A::Blue,
+            (BasicColor::Magenta, false) => A::Magenta,
+            (BasicColor::Cyan, false) => A::Cyan,
+            (BasicColor::White, false) => A::White,
+            (BasicColor::Black, true) => A::BrightBlack,
+            (BasicColor::Red, true) => A::BrightRed,
+            (BasicColor::Green, true) => A::BrightGreen,
+            (BasicColor::Yellow, true) => A::BrightYellow,
+            (BasicColor::Blue, true) => A::BrightBlue,
+            (BasicColor::Magenta, true) => A::BrightMagenta,
+            (BasicColor::Cyan, true) => A::BrightCyan,
+            (BasicColor::White, true) => A::BrightWhite,
+        }
+    }
+}
+
+impl From<anstyle::AnsiColor> for SimpleColor {
+    fn from(color: anstyle::AnsiColor) -> Self {
+        use anstyle::AnsiColor as A;
+
+        let (basic_color, bright) = match color {
+            A::Black => (BasicColor::Black, false),
+            A::Red => (BasicColor::Red, false),
+            A::Green => (BasicColor::Green, false),
+            A::Yellow => (BasicColor::Yellow, false),
+            A::Blue => (BasicColor::Blue, false),
+            A::Magenta => (BasicColor::Magenta, false),
+            A::Cyan => (BasicColor::Cyan, false),
+            A::White => (BasicColor::White, false),
+            A::BrightBlack => (BasicColor::Black, true),
+            A::BrightRed => (BasicColor::Red, true),
+            A::BrightGreen => (BasicColor::Green, true),
+            A::BrightYellow => (BasicColor::Yellow, true),
+            A::BrightBlue => (BasicColor::Blue, true),
+            A::BrightMagenta => (BasicColor::Magenta, true),
+            A::BrightCyan => (BasicColor::Cyan, true),
+            A::BrightWhite => (BasicColor::White, true),
+        };
+        if bright {
+            SimpleColor::new_bright(basic_color)
+        } else {
+            SimpleColor::new(basic_color)
+        }
+    }
+}
+
+impl From<anstyle::AnsiColor> for Color {
+    fn from(color: anstyle::AnsiColor) -> Self {
+        Color::Simple(color.into())
+    }
+}
+
+impl From<IndexedColor> for anstyle::Color {
+    fn from(color: IndexedColor) -> Self {
+        anstyle::Color::Ansi256(color.into())
+    }
+}
+
+impl From<IndexedColor> for anstyle::Ansi256Color {
+    fn from(color: IndexedColor) -> Self {
+        anstyle::Ansi256Color(color.get_index())
+    }
+}
+
+impl From<anstyle::Ansi256Color> for IndexedColor {
+    fn from(color: anstyle::Ansi256Color) -> Self {
+        IndexedColor::new(color.index())
+    }
+}
+
+impl From<anstyle::Ansi256Color> for Color {
+    fn from(color: anstyle::Ansi256Color) -> Self {
+        Color::Indexed(color.into())
+    }
+}
+
+impl From<RGBColor> for anstyle::Color {
+    fn from(color: RGBColor) -> Self {
+        anstyle::Color::Rgb(color.into())
+    }
+}
+
+impl From<RGBColor> for anstyle::RgbColor {
+    fn from(color: RGBColor) -> Self {
+        anstyle::RgbColor(color.r, color.g, color.b)
+    }
+}
+
+impl From<anstyle::RgbColor> for RGBColor {
+    fn from(color: anstyle::RgbColor) -> Self {
+        RGBColor::new(color.r(), color.g(), color.b())
+    }
+}
+
+impl From<anstyle::RgbColor> for Color {
+    fn from(color: anstyle::RgbColor) -> Self {
+        Color::RGB(color.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ToStyleSet as _, UnderlineStyle, color::ColorKind as _};
+
+    use super::*;
+
+    #[test]
+    fn style() {
+        let style = Style::new()
+            .bold()
+            .italic()
+            .curly_underline()
+            .reverse()
+            .fg(BasicColor::Red.bright())
+            .bg(IndexedColor::new(42))
+            .underline_color(RGBColor::new(0, 128, 255));
+        let expected = anstyle::Style::new()
+            .effects(
+                anstyle::Effects::BOLD
+                    | anstyle::Effects::ITALIC
+                    | anstyle::Effects::CURLY_UNDERLINE
+                    | anstyle::Effects::INVERT,
+            )
+            .fg_color(Some(anstyle::AnsiColor::BrightRed.into()))
+            .bg_color(Some(anstyle::Ansi256Color(42).into()))
+            .underline_color(Some(anstyle::RgbColor(0, 128, 255).into()));
+
+        assert_eq!(anstyle::Style::from(style), expected);
+        assert_eq!(Style::from(expected), style);
+    }
+
+    #[test]
+    fn all_effects() {
+        for effect in Effect::iter().filter(|&e| EFFECTS.iter().any(|&(effect, _)| effect == e)) {
+            let style = Style::new().effect(effect);
+            assert_eq!(Style::from(anstyle::Style::from(style)), style);
+        }
+        for underline_style in UnderlineStyle::iter() {
+            let style = Style::new().underline_style(underline_style);
+            assert_eq!(Style::from(anstyle::Style::from(style)), style);
+        }
+    }
+
+    #[test]
+    fn lossy() {
+        assert_eq!(
+            anstyle::Style::from(Style::new().overline().framed().fg(Color::Default)),
+            anstyle::Style::new()
+        );
+    }
+
+    #[test]
+    fn colors() {
+        for index in 0..=255 {
+            let color = IndexedColor::new(index);
+            assert_eq!(
+                IndexedColor::from(anstyle::Ansi256Color::from(color)),
+                color
+            );
+        }
+        for basic_color in [BasicColor::Black, BasicColor::Yellow, BasicColor::White] {
+            for simple_color in [basic_color.to_simple_color(), basic_color.bright()] {
+                let ansi = anstyle::AnsiColor::from(simple_color);
+                assert_eq!(SimpleColor::from(ansi), simple_color);
+            }
+        }
+        assert_eq!(
+            Color::from(anstyle::Color::from(RGBColor::new(1, 2, 3))),
+            Color::rgb(1, 2, 3)
+        );
+        assert_eq!(Option::<anstyle::Color>::from(Color::Default), None);
+        assert_eq!(
+            Option::<anstyle::Color>::from(Color::RED.to_color()),
+            Some(anstyle::Color::Ansi(anstyle::AnsiColor::Red))
+        );
+    }
+}
@@ -1,6 +1,6 @@
-use core::fmt::Display;
+use core::fmt::{Debug, Display};
 
-use crate::{Style, Styled, ToStyle, ToStyleSet};
+use crate::{Style, Styled, StyledDebug, StyledRef, ToStyle, ToStyleSet};
 
 /// A trait to apply styling to some content.
 pub trait AppliedTo: ToStyle + ToStyleSet<StyleSet = Style> {
@@ -9,4 +9,29 @@ pub trait AppliedTo: ToStyle + ToStyleSet<StyleSet = Style> {
     fn applied_to<C: Display>(self, content: C) -> Styled<C> {
         self.to_style().applied_to(content)
     }
+
+    /// Applies the styling to the given borrowed content, returning a [`StyledRef`] instance.
+    ///
+    /// It is the same as [`applied_to()`](AppliedTo::applied_to), but the content type is always `&dyn Display`, so
+    /// no code is generated for each type of content, and unsized values, like the errors of an error chain, can be
+    /// styled as they are:
+    ///
+    /// ```
+    /// use std::error::Error;
+    /// use fluent_ansi::prelude::*;
+    ///
+    /// let error: Box<dyn Error> = "disk full".into();
+    /// assert_eq!(Color::RED.display(&*error).to_string(), "\x1b[31mdisk full\x1b[0m");
+    /// ```
+    #[must_use]
+    fn display(self, content: &dyn Display) -> StyledRef<'_> {
+        self.applied_to(content)
+    }
+
+    /// Applies the styling to the given content, returning a [`StyledDebug<C>`](StyledDebug) instance, which renders
+    /// the content with its [`Debug`] implementation.
+    #[must_use]
+    fn applied_to_debug<C: Debug>(self, content: C) -> StyledDebug<C> {
+        StyledDebug::new(content).with_style(self.to_style())
+    }
 }
@@ -0,0 +1,303 @@
+use std::{
+    env,
+    ffi::OsString,
+    fmt,
+    io::{IsTerminal, Result, Stderr, Stdout, Write, stderr, stdout},
+};
+
+use crate::width::EscapeState;
+
+/// Whether to write styled output.
+///
+/// It is usually set from a command line option, like `--color=auto`, and is resolved by an [`AutoStream`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColorChoice {
+    /// Write styled output only to terminals, following the conventions of environment variables.
+    ///
+    /// See [`should_color()`](ColorChoice::should_color).
+    #[default]
+    Auto,
+    /// Always write styled output.
+    Always,
+    /// Never write styled output.
+    Never,
+}
+
+impl ColorChoice {
+    /// Returns the name of the choice, like `"auto"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            ColorChoice::Auto => "auto",
+            ColorChoice::Always => "always",
+            ColorChoice::Never => "never",
+        }
+    }
+
+    /// Returns the choice with the given name, ignoring ASCII case.
+    ///
+    /// ```
+    /// use fluent_ansi::ColorChoice;
+    ///
+    /// assert_eq!(ColorChoice::from_name("never"), Some(ColorChoice::Never));
+    /// assert_eq!(ColorChoice::from_name("sometimes"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        [ColorChoice::Auto, ColorChoice::Always, ColorChoice::Never]
+            .into_iter()
+            .find(|choice| name.eq_ignore_ascii_case(choice.name()))
+    }
+
+    /// Returns whether styled output should be written to a destination.
+    ///
+    /// For [`Auto`](ColorChoice::Auto), the environment variables are checked in this order:
+    /// - if [`NO_COLOR`](https://no-color.org) is set and not empty, output is not styled;
+    /// - if [`CLICOLOR_FORCE`](https://bixense.com/clicolors/) is set and is not `0`, output is styled;
+    /// - if `CLICOLOR` is `0`, or `TERM` is `dumb`, output is not styled;
+    /// - otherwise, output is styled only if the destination is a terminal.
+    #[must_use]
+    pub fn should_color(self, is_terminal: bool) -> bool {
+        self.should_color_with(is_terminal, |name| env::var_os(name))
+    }
+
+    fn should_color_with(self, is_terminal: bool, var: impl Fn(&str) -> Option<OsString>) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+                    false
+                } else if var("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+                    true
+                } else if var("CLICOLOR").is_some_and(|value| value == "0")
+                    || var("TERM").is_some_and(|value| value == "dumb")
+                {
+                    false
+                } else {
+                    is_terminal
+                }
+            }
+        }
+    }
+}
+
+/// A writer that passes styled output through, or removes its escape sequences, depending on its destination.
+///
+/// The decision is made when the stream is created, from a [`ColorChoice`], whether the destination is a terminal,
+/// and the environment variables (see [`ColorChoice::should_color()`]). Code that writes styled output can then
+/// write to the stream without checking anything:
+///
+/// ```
+/// use std::io::Write as _;
+/// use fluent_ansi::{prelude::*, AutoStream, ColorChoice};
+///
+/// let mut stream = AutoStream::stderr(ColorChoice::Auto);
+/// writeln!(stream, "{}: disk full", Color::RED.bold().applied_to("Error"))?;
+///
+/// let mut stream = AutoStream::with_colors(Vec::new(), false);
+/// write!(stream, "{}: disk full", Color::RED.bold().applied_to("Error"))?;
+/// assert_eq!(stream.into_inner(), b"Error: disk full");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// The escape sequences are removed as in [`Stripper`](crate::Stripper), even if they are split across several
+/// writes. Both [`std::io::Write`] and [`core::fmt::Write`] are implemented.
+///
+/// Requires the `std` feature.
+#[derive(Debug)]
+pub struct AutoStream<W: Write> {
+    inner: W,
+    strip: Option<EscapeState>,
+}
+
+impl AutoStream<Stdout> {
+    /// Creates a new `AutoStream` that writes to the standard output.
+    #[must_use]
+    pub fn stdout(choice: ColorChoice) -> Self {
+        Self::new(stdout(), choice)
+    }
+}
+
+impl AutoStream<Stderr> {
+    /// Creates a new `AutoStream` that writes to the standard error.
+    #[must_use]
+    pub fn stderr(choice: ColorChoice) -> Self {
+        Self::new(stderr(), choice)
+    }
+}
+
+impl<W: Write + IsTerminal> AutoStream<W> {
+    /// Creates a new `AutoStream` that writes to the given writer, resolving the choice with
+    /// [`ColorChoice::should_color()`].
+    #[must_use]
+    pub fn new(inner: W, choice: ColorChoice) -> Self {
+        let colored = choice.should_color(inner.is_terminal());
+        Self::with_colors(inner, colored)
+    }
+}
+
+impl<W: Write> AutoStream<W> {
+    /// Creates a new `AutoStream` that writes to the given writer, passing the escape sequences through or not.
+    ///
+    /// It allows writers that can't be checked for being a terminal, like buffers, to be used.
+    #[must_use]
+    pub const fn with_colors(inner: W, enabled: bool) -> Self {
+        Self {
+            inner,
+            strip: if enabled {
+                None
+            } else {
+                Some(EscapeState::Text)
+            },
+        }
+    }
+
+    /// Returns whether the escape sequences are passed through.
+    #[must_use]
+    pub const fn is_colored(&self) -> bool {
+        self.strip.is_none()
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for AutoStream<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let Some(state) = &mut self.strip else {
+            return self.inner.write(buf);
+        };
+        let mut start = None;
+        for (i, &byte) in buf.iter().enumerate() {
+            let in_text = *state == EscapeState::Text && byte != 0x1b;
+            state.advance(char::from(byte));
+            match (in_text, start) {
+                (true, None) => start = Some(i),
+                (false, Some(st)) => {
+                    self.inner.write_all(&buf[st..i])?;
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(st) = start {
+            self.inner.write_all(&buf[st..])?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> fmt::Write for AutoStream<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_all(s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::String;
+
+    use crate::{AppliedTo as _, Styled, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn choice_names() {
+        for choice in [ColorChoice::Auto, ColorChoice::Always, ColorChoice::Never] {
+            assert_eq!(ColorChoice::from_name(choice.name()), Some(choice));
+        }
+        assert_eq!(ColorChoice::from_name("ALWAYS"), Some(ColorChoice::Always));
+        assert_eq!(ColorChoice::from_name(""), None);
+        assert_eq!(ColorChoice::default(), ColorChoice::Auto);
+    }
+
+    #[test]
+    fn should_color() {
+        let should_color = |choice: ColorChoice, is_terminal, vars: &[(&str, &str)]| {
+            choice.should_color_with(is_terminal, |name| {
+                vars.iter()
+                    .find(|(n, _)| *n == name)
+                    .map(|(_, value)| OsString::from(value))
+            })
+        };
+
+        assert!(should_color(
+            ColorChoice::Always,
+            false,
+            &[("NO_COLOR", "1")]
+        ));
+        assert!(!should_color(
+            ColorChoice::Never,
+            true,
+            &[("CLICOLOR_FORCE", "1")]
+        ));
+
+        assert!(should_color(ColorChoice::Auto, true, &[]));
+        assert!(!should_color(ColorChoice::Auto, false, &[]));
+        assert!(!should_color(ColorChoice::Auto, true, &[("NO_COLOR", "1")]));
+        assert!(should_color(ColorChoice::Auto, true, &[("NO_COLOR", "")]));
+        assert!(!should_color(
+            ColorChoice::Auto,
+            false,
+            &[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]
+        ));
+        assert!(should_color(
+            ColorChoice::Auto,
+            false,
+            &[("CLICOLOR_FORCE", "1")]
+        ));
+        assert!(!should_color(
+            ColorChoice::Auto,
+            false,
+            &[("CLICOLOR_FORCE", "0")]
+        ));
+        assert!(!should_color(ColorChoice::Auto, true, &[("CLICOLOR", "0")]));
+        assert!(should_color(ColorChoice::Auto, true, &[("CLICOLOR", "1")]));
+        assert!(!should_color(ColorChoice::Auto, true, &[("TERM", "dumb")]));
+        assert!(should_color(
+            ColorChoice::Auto,
+            true,
+            &[("TERM", "dumb"), ("CLICOLOR_FORCE", "1")]
+        ));
+    }
+
+    #[test]
+    fn colored() {
+        let mut stream = AutoStream::with_colors(Vec::new(), true);
+        assert!(stream.is_colored());
+        write!(stream, "{}", Color::RED.applied_to("a")).unwrap();
+        assert_eq!(stream.get_ref(), b"\x1b[31ma\x1b[0m");
+    }
+
+    #[test]
+    fn stripped() {
+        let mut stream = AutoStream::with_colors(Vec::new(), false);
+        assert!(!stream.is_colored());
+        write!(stream, "{}", Styled::new("a\tb").bold()).unwrap();
+        stream.write_all(b"c\x1b[3").unwrap();
+        stream.write_all("1mé\x1b]8;;url\x1b".as_bytes()).unwrap();
+        fmt::Write::write_str(&mut stream, "\\e\n").unwrap();
+        stream.flush().unwrap();
+        assert_eq!(String::from_utf8(stream.into_inner()).unwrap(), "a\tbcée\n");
+    }
+}
@@ -0,0 +1,249 @@
+use core::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use crate::{Code, Reset, Style, Styled, color::RGBColor};
+
+/// The error returned when rendering into a byte buffer that is too small.
+///
+/// See [`Style::write_bytes()`], [`Styled::write_bytes()`] and [`Reset::write_bytes()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferOverflowError;
+
+impl Display for BufferOverflowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("buffer too small")
+    }
+}
+
+impl Error for BufferOverflowError {}
+
+type Result = core::result::Result<(), BufferOverflowError>;
+
+impl Style {
+    /// Renders the escape sequence of this style into a byte buffer, returning the number of bytes written.
+    ///
+    /// The bytes are the same as the ones written by the [`Display`] implementation, but they are produced without
+    /// going through [`core::fmt`], which may be too costly for embedded targets:
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let mut buf = [0; 32];
+    /// let len = Style::new().bold().fg(Color::RED).write_bytes(&mut buf).unwrap();
+    /// assert_eq!(&buf[..len], b"\x1b[1;31m");
+    /// ```
+    ///
+    /// [`rendered_len()`](Style::rendered_len) tells the size of the buffer that is needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferOverflowError`] if the buffer is too small. The contents of the buffer are unspecified in
+    /// that case.
+    pub fn write_bytes(self, buf: &mut [u8]) -> core::result::Result<usize, BufferOverflowError> {
+        let mut writer = ByteWriter { buf, len: 0 };
+        writer.write_style(self)?;
+        Ok(writer.len)
+    }
+}
+
+impl<C: Display + AsRef<str>> Styled<C> {
+    /// Renders this value into a byte buffer, returning the number of bytes written.
+    ///
+    /// The bytes are the same as the ones written by the [`Display`] implementation, but they are produced without
+    /// going through [`core::fmt`], which may be too costly for embedded targets. The content is written as is, so
+    /// formatting options are not supported:
+    ///
+    /// ```
+    /// use fluent_ansi::prelude::*;
+    ///
+    /// let mut buf = [0; 32];
+    /// let len = Color::GREEN.applied_to("OK").write_bytes(&mut buf).unwrap();
+    /// assert_eq!(&buf[..len], b"\x1b[32mOK\x1b[0m");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferOverflowError`] if the buffer is too small. The contents of the buffer are unspecified in
+    /// that case.
+    pub fn write_bytes(&self, buf: &mut [u8]) -> core::result::Result<usize, BufferOverflowError> {
+        let mut writer = ByteWriter { buf, len: 0 };
+        let content = self.get_content().as_ref().as_bytes();
+        if self.get_style().emits_codes() {
+            writer.write_style(self.get_style())?;
+            writer.write(content)?;
+            writer.write_style(Style::new())?;
+        } else {
+            writer.write(content)?;
+        }
+        Ok(writer.len)
+    }
+}
+
+impl Reset {
+    /// Renders the reset escape sequence into a byte buffer, returning the number of bytes written.
+    ///
+    /// See [`Style::write_bytes()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferOverflowError`] if the buffer is too small.
+    pub fn write_bytes(self, buf: &mut [u8]) -> core::result::Result<usize, BufferOverflowError> {
+        Style::new().write_bytes(buf)
+    }
+}
+
+/// A writer of bytes into a fixed buffer.
+struct ByteWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl ByteWriter<'_> {
+    fn write(&mut self, bytes: &[u8]) -> Result {
+        let end = self.len + bytes.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(BufferOverflowError)?
+            .copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+
+    fn write_decimal(&mut self, value: u8) -> Result {
+        let digits = [
+            b'0' + value / 100,
+            b'0' + value / 10 % 10,
+            b'0' + value % 10,
+        ];
+        let skip = match value {
+            0..10 => 2,
+            10..100 => 1,
+            100.. => 0,
+        };
+        self.write(&digits[skip..])
+    }
+
+    fn write_style(&mut self, style: Style) -> Result {
+        if style.is_plain() {
+            return self.write(b"\x1b[0m");
+        }
+        let mut codes = style.codes().peekable();
+        if codes.peek().is_none() {
+            return Ok(());
+        }
+        self.write(b"\x1b[")?;
+        for (index, code) in codes.enumerate() {
+            if index > 0 {
+                self.write(b";")?;
+            }
+            self.write_code(code)?;
+        }
+        self.write(b"m")
+    }
+
+    fn write_code(&mut self, code: Code) -> Result {
+        match code {
+            Code::Single(param) => self.write_decimal(param),
+            Code::Sub(param, sub_param) => {
+                self.write_decimal(param)?;
+                self.write(b":")?;
+                self.write_decimal(sub_param)
+            }
+            Code::Indexed(param, index) => {
+                self.write_decimal(param)?;
+                self.write(b";5;")?;
+                self.write_decimal(index)
+            }
+            Code::RGB(param, RGBColor { r, g, b }) => {
+                self.write_decimal(param)?;
+                self.write(b";2;")?;
+                self.write_decimal(r)?;
+                self.write(b";")?;
+                self.write_decimal(g)?;
+                self.write(b";")?;
+                self.write_decimal(b)
+            }
+            Code::Custom(params) => self.write(params.as_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AppliedTo as _, CustomCode, ToStyleSet as _,
+        color::{Color, IndexedColor},
+    };
+
+    use super::*;
+
+    #[test]
+    fn style() {
+        let mut buf = [0; 64];
+        for style in [
+            Style::new(),
+            Style::new().bold(),
+            Style::new().italic().curly_underline().rapid_blink(),
+            Style::new().fg(Color::RED).bg(Color::RED.bright()),
+            Style::new()
+                .fg(IndexedColor(7))
+                .bg(IndexedColor(42))
+                .underline_color(RGBColor::new(1, 22, 133)),
+            Style::new().bold().add(CustomCode::new("60;61")),
+        ] {
+            let len = style.write_bytes(&mut buf).unwrap();
+            assert_eq!(&buf[..len], style.to_string().as_bytes(), "{style:?}");
+        }
+    }
+
+    #[test]
+    fn styled() {
+        let mut buf = [0; 64];
+
+        let styled = Color::RED.bold().applied_to("content");
+        let len = styled.write_bytes(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"\x1b[1;31mcontent\x1b[0m");
+
+        let styled = Styled::new("content");
+        let len = styled.write_bytes(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"content");
+    }
+
+    #[cfg(not(feature = "underline-color"))]
+    #[test]
+    fn without_underline_color() {
+        let mut buf = [0; 64];
+
+        let style = Style::new().underline_color(Color::RED);
+        let len = style.write_bytes(&mut buf).unwrap();
+        assert_eq!(&buf[..len], style.to_string().as_bytes());
+        assert_eq!(len, style.rendered_len());
+        assert_eq!(len, 0);
+
+        let styled = style.applied_to("content");
+        let len = styled.write_bytes(&mut buf).unwrap();
+        assert_eq!(&buf[..len], styled.to_string().as_bytes());
+    }
+
+    #[test]
+    fn reset() {
+        let mut buf = [0; 4];
+        assert_eq!(Reset.write_bytes(&mut buf), Ok(4));
+        assert_eq!(&buf, b"\x1b[0m");
+    }
+
+    #[test]
+    fn overflow() {
+        let style = Style::new().bold();
+        assert_eq!(style.write_bytes(&mut [0; 3]), Err(BufferOverflowError));
+        assert_eq!(style.write_bytes(&mut [0; 4]), Ok(4));
+        assert_eq!(Reset.write_bytes(&mut []), Err(BufferOverflowError));
+
+        let styled = style.applied_to("abc");
+        assert_eq!(styled.write_bytes(&mut [0; 10]), Err(BufferOverflowError));
+        assert_eq!(styled.write_bytes(&mut [0; 11]), Ok(11));
+        assert_eq!(BufferOverflowError.to_string(), "buffer too small");
+    }
+}
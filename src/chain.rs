@@ -0,0 +1,122 @@
+use core::{
+    fmt::{Display, Formatter, Result},
+    ops::Add,
+};
+
+use crate::{
+    Styled,
+    width::{visible_width, write_padded},
+};
+
+/// A value that renders two values, one after the other.
+///
+/// It is returned by the `+` operator on [`Styled<C>`] and `Chain` values, so composite lines can be built without
+/// allocating:
+///
+/// ```
+/// use fluent_ansi::prelude::*;
+///
+/// let line = Color::RED.bold().applied_to("Error") + ": " + Effect::Italic.applied_to("not found");
+/// assert_eq!(
+///     format!("{line}"),
+///     "\x1b[1;31mError\x1b[0m: \x1b[3mnot found\x1b[0m"
+/// );
+/// ```
+///
+/// When formatted with a width, the padding is computed from the visible width of the whole chain, as for
+/// [`Styled<C>`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: Display, B: Display> Chain<A, B> {
+    /// Creates a new `Chain<A, B>` value that renders `first` and then `second`.
+    #[must_use]
+    pub const fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Gets a reference to the value rendered first.
+    #[must_use]
+    pub const fn get_first(&self) -> &A {
+        &self.first
+    }
+
+    /// Gets a reference to the value rendered second.
+    #[must_use]
+    pub const fn get_second(&self) -> &B {
+        &self.second
+    }
+
+    fn fmt_unpadded(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}{}", self.first, self.second)
+    }
+}
+
+impl<A: Display, B: Display> Display for Chain<A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if f.width().is_none() {
+            return self.fmt_unpadded(f);
+        }
+        write_padded(f, visible_width(self), |f| self.fmt_unpadded(f))
+    }
+}
+
+impl<A: Display, B: Display, R: Display> Add<R> for Chain<A, B> {
+    type Output = Chain<Self, R>;
+
+    fn add(self, rhs: R) -> Self::Output {
+        Chain::new(self, rhs)
+    }
+}
+
+impl<C: Display, R: Display> Add<R> for Styled<C> {
+    type Output = Chain<Self, R>;
+
+    fn add(self, rhs: R) -> Self::Output {
+        Chain::new(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn chain() {
+        let chain = Chain::new("a", 1);
+        assert_eq!(chain.get_first(), &"a");
+        assert_eq!(chain.get_second(), &1);
+        assert_eq!(chain.to_string(), "a1");
+    }
+
+    #[test]
+    fn add() {
+        let a = Styled::new("a").bold();
+        let b = Color::RED.applied_to("b");
+
+        assert_eq!((a + b).to_string(), "\x1b[1ma\x1b[0m\x1b[31mb\x1b[0m");
+        assert_eq!(
+            (a + " " + b + '!').to_string(),
+            "\x1b[1ma\x1b[0m \x1b[31mb\x1b[0m!"
+        );
+        assert_eq!(
+            (a + (b + 42)).to_string(),
+            "\x1b[1ma\x1b[0m\x1b[31mb\x1b[0m42"
+        );
+    }
+
+    #[test]
+    fn padding() {
+        let chain = Styled::new("ab").italic() + "cd";
+
+        assert_eq!(format!("{chain:6}"), "\x1b[3mab\x1b[0mcd  ");
+        assert_eq!(format!("{chain:>6}"), "  \x1b[3mab\x1b[0mcd");
+        assert_eq!(format!("{chain:*^7}"), "*\x1b[3mab\x1b[0mcd**");
+        assert_eq!(format!("{chain:2}"), "\x1b[3mab\x1b[0mcd");
+    }
+}
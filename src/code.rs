@@ -0,0 +1,392 @@
+use core::fmt::{Display, Formatter, Result};
+
+use crate::{
+    ColorTarget, Effect, Style, StyleSet as _,
+    color::{Color, ColorCode as _, RGBColor},
+    effect::EffectIter,
+};
+
+/// An SGR parameter, or group of parameters, that sets a single style attribute.
+///
+/// Values of this type are yielded by [`Style::codes()`]. When formatted with [`Display`], they render the
+/// parameters as they appear in an escape sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Code {
+    /// A single parameter, like `1` or `31`.
+    Single(u8),
+    /// A parameter with a sub-parameter, written in colon form, like `4:3`.
+    Sub(u8, u8),
+    /// An 8-bit color, with the color parameter (`38`, `48` or `58`) and the color index, like `38;5;123`.
+    Indexed(u8, u8),
+    /// An RGB color, with the color parameter (`38`, `48` or `58`), like `38;2;0;128;255`.
+    RGB(u8, RGBColor),
+    /// Raw parameters from a [`CustomCode`](crate::CustomCode).
+    Custom(&'static str),
+}
+
+impl Code {
+    /// Returns the number of bytes that this code occupies when rendered, without rendering it.
+    #[must_use]
+    pub const fn rendered_len(self) -> usize {
+        match self {
+            Code::Single(param) => digits(param),
+            Code::Sub(param, sub_param) => digits(param) + 1 + digits(sub_param),
+            Code::Indexed(param, index) => digits(param) + 3 + digits(index),
+            Code::RGB(param, RGBColor { r, g, b }) => {
+                digits(param) + 3 + digits(r) + 1 + digits(g) + 1 + digits(b)
+            }
+            Code::Custom(params) => params.len(),
+        }
+    }
+}
+
+/// Returns the number of decimal digits of a value.
+const fn digits(value: u8) -> usize {
+    match value {
+        0..10 => 1,
+        10..100 => 2,
+        100.. => 3,
+    }
+}
+
+impl Display for Code {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match *self {
+            Code::Single(param) => write!(f, "{param}"),
+            Code::Sub(param, sub_param) => write!(f, "{param}:{sub_param}"),
+            Code::Indexed(param, index) => write!(f, "{param};5;{index}"),
+            Code::RGB(param, RGBColor { r, g, b }) => write!(f, "{param};2;{r};{g};{b}"),
+            Code::Custom(params) => f.write_str(params),
+        }
+    }
+}
+
+impl Effect {
+    /// Returns the effect that is emitted in place of this effect.
+    ///
+    /// Without the `extended-underline` feature, the curly, dotted and dashed underlines, which are emitted with
+    /// colon-separated sub-parameters, are emitted as a solid underline.
+    pub(crate) fn emitted_effect(self) -> Effect {
+        #[cfg(not(feature = "extended-underline"))]
+        if matches!(
+            self,
+            Effect::CurlyUnderline | Effect::DottedUnderline | Effect::DashedUnderline
+        ) {
+            return Effect::Underline;
+        }
+        self
+    }
+
+    /// Returns the SGR parameter that is emitted to set this effect.
+    pub(crate) fn emitted_code(self) -> Code {
+        self.emitted_effect().code()
+    }
+}
+
+impl Color {
+    /// Returns the SGR parameters that are emitted to set this color in a target, if any.
+    ///
+    /// Without the `underline-color` feature, no underline colors are emitted. Without the `truecolor` feature, RGB
+    /// colors are emitted as the nearest simple colors.
+    #[cfg_attr(
+        feature = "underline-color",
+        expect(
+            clippy::unnecessary_wraps,
+            reason = "no code is emitted for underline colors without the feature"
+        )
+    )]
+    pub(crate) fn emitted_code(self, target: ColorTarget) -> Option<Code> {
+        #[cfg(not(feature = "underline-color"))]
+        if target == ColorTarget::Underline {
+            return None;
+        }
+        #[cfg(not(feature = "truecolor"))]
+        if let Color::RGB(rgb) = self {
+            return Some(rgb.to_xterm_simple().color_code(target));
+        }
+        Some(self.color_code(target))
+    }
+}
+
+impl Style {
+    /// The maximum number of bytes that the escape sequence of a style without a custom code occupies when rendered.
+    ///
+    /// It is reached by a style with all the effects that can be combined, and RGB colors for all the targets. It
+    /// allows sizing fixed buffers, like for [`write_bytes()`](Style::write_bytes).
+    pub const MAX_RENDERED_LEN: usize = 86;
+
+    /// Returns the number of bytes that the escape sequence of this style occupies when rendered, without rendering
+    /// it.
+    ///
+    /// This allows reserving buffer space, or computing how many bytes of a line are taken by escape sequences:
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let style = Style::new().bold().fg(Color::rgb(0, 128, 255));
+    /// # #[cfg(feature = "truecolor")]
+    /// assert_eq!(format!("{style}"), "\x1b[1;38;2;0;128;255m");
+    /// # #[cfg(feature = "truecolor")]
+    /// assert_eq!(style.rendered_len(), 19);
+    ///
+    /// assert_eq!(Style::new().rendered_len(), "\x1b[0m".len());
+    /// ```
+    #[must_use]
+    pub fn rendered_len(self) -> usize {
+        if self.is_plain() {
+            return "\x1b[0m".len();
+        }
+        let (count, len) = self.codes().fold((0, 0), |(count, len), code| {
+            (count + 1, len + code.rendered_len())
+        });
+        if count == 0 {
+            return 0;
+        }
+        "\x1b[".len() + len + (count - 1) + "m".len()
+    }
+
+    /// Returns an iterator over the SGR parameters that this style emits, in the order they are emitted.
+    ///
+    /// An empty style yields no codes, even though it is rendered as the reset sequence `ESC [ 0 m`.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Code, Style};
+    ///
+    /// let style = Style::new().bold().curly_underline().fg(Color::rgb(0, 128, 255));
+    /// let codes: Vec<String> = style.codes().map(|code| code.to_string()).collect();
+    /// # #[cfg(all(feature = "extended-underline", feature = "truecolor"))]
+    /// assert_eq!(codes, ["1", "4:3", "38;2;0;128;255"]);
+    ///
+    /// assert_eq!(Style::new().bold().codes().next(), Some(Code::Single(1)));
+    /// ```
+    #[must_use]
+    pub fn codes(self) -> Codes {
+        Codes {
+            style: self,
+            effects: Effect::iter(),
+            targets: ColorTarget::ALL.into_iter(),
+        }
+    }
+
+    /// Returns whether the style emits any SGR parameter, which is not the case for a plain style, or for a style
+    /// whose codes are all compiled out by the disabled Cargo features.
+    #[must_use]
+    pub(crate) fn emits_codes(self) -> bool {
+        self.codes().next().is_some()
+    }
+}
+
+/// An iterator over the SGR parameters of a style.
+///
+/// It is returned by [`Style::codes()`].
+#[derive(Debug, Clone)]
+pub struct Codes {
+    style: Style,
+    effects: EffectIter,
+    targets: core::array::IntoIter<ColorTarget, 3>,
+}
+
+impl Iterator for Codes {
+    type Item = Code;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(effect) = self.effects.find(|&effect| self.style.get_effect(effect)) {
+            return Some(effect.emitted_code());
+        }
+        if let Some(code) = self.targets.find_map(|target| {
+            self.style
+                .get_color(target)
+                .and_then(|color: Color| color.emitted_code(target))
+        }) {
+            return Some(code);
+        }
+        self.style
+            .custom_code
+            .take()
+            .map(|custom_code| Code::Custom(custom_code.params()))
+    }
+}
+
+impl core::iter::FusedIterator for Codes {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ToStyleSet as _,
+        color::{BasicColor, IndexedColor},
+    };
+
+    use super::*;
+
+    #[test]
+    fn rendered_len() {
+        for code in [
+            Code::Single(0),
+            Code::Single(1),
+            Code::Single(31),
+            Code::Single(107),
+            Code::Sub(4, 3),
+            Code::Indexed(48, 5),
+            Code::Indexed(48, 123),
+            Code::RGB(58, RGBColor::new(0, 10, 255)),
+            Code::Custom("1;2;3"),
+        ] {
+            assert_eq!(code.rendered_len(), code.to_string().len(), "{code:?}");
+        }
+
+        for style in [
+            Style::new(),
+            Style::new().bold(),
+            Style::new().bold().curly_underline().rapid_blink(),
+            Style::new()
+                .fg(BasicColor::Red)
+                .bg(BasicColor::Red.bright()),
+            Style::new()
+                .fg(IndexedColor(7))
+                .underline_color(RGBColor::new(1, 22, 133)),
+            Style::new().italic().add(crate::CustomCode::new("60;61")),
+        ] {
+            assert_eq!(style.rendered_len(), style.to_string().len(), "{style:?}");
+        }
+    }
+
+    #[test]
+    fn max_rendered_len() {
+        let white = RGBColor::new(255, 255, 255);
+        let style = Style::new()
+            .bold()
+            .faint()
+            .italic()
+            .curly_underline()
+            .blink()
+            .reverse()
+            .conceal()
+            .strikethrough()
+            .fraktur()
+            .framed()
+            .encircled()
+            .overline()
+            .superscript()
+            .fg(white)
+            .bg(white)
+            .underline_color(white);
+        #[cfg(all(
+            feature = "extended-underline",
+            feature = "truecolor",
+            feature = "underline-color"
+        ))]
+        assert_eq!(style.rendered_len(), Style::MAX_RENDERED_LEN);
+        #[cfg(not(all(
+            feature = "extended-underline",
+            feature = "truecolor",
+            feature = "underline-color"
+        )))]
+        assert!(style.rendered_len() < Style::MAX_RENDERED_LEN);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Code::Single(1).to_string(), "1");
+        assert_eq!(Code::Sub(4, 3).to_string(), "4:3");
+        assert_eq!(Code::Indexed(48, 123).to_string(), "48;5;123");
+        assert_eq!(
+            Code::RGB(58, RGBColor::new(0, 128, 255)).to_string(),
+            "58;2;0;128;255"
+        );
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(Style::new().codes().next(), None);
+
+        let style = Style::new()
+            .italic()
+            .dotted_underline()
+            .fg(BasicColor::Red)
+            .bg(BasicColor::Blue.bright())
+            .underline_color(IndexedColor(42));
+        let codes: Vec<Code> = style.codes().collect();
+        #[cfg(feature = "extended-underline")]
+        let underline = Code::Sub(4, 4);
+        #[cfg(not(feature = "extended-underline"))]
+        let underline = Code::Single(4);
+        #[cfg(feature = "underline-color")]
+        assert_eq!(
+            codes,
+            [
+                Code::Single(3),
+                underline,
+                Code::Single(31),
+                Code::Single(104),
+                Code::Indexed(58, 42),
+            ]
+        );
+        #[cfg(not(feature = "underline-color"))]
+        assert_eq!(
+            codes,
+            [
+                Code::Single(3),
+                underline,
+                Code::Single(31),
+                Code::Single(104),
+            ]
+        );
+    }
+
+    #[test]
+    fn default_colors() {
+        let style = Style::new()
+            .fg(Color::Default)
+            .bg(Color::Default)
+            .underline_color(Color::Default);
+        let codes: Vec<Code> = style.codes().collect();
+        #[cfg(feature = "underline-color")]
+        assert_eq!(
+            codes,
+            [Code::Single(39), Code::Single(49), Code::Single(59)]
+        );
+        #[cfg(not(feature = "underline-color"))]
+        assert_eq!(codes, [Code::Single(39), Code::Single(49)]);
+    }
+
+    #[cfg(not(feature = "extended-underline"))]
+    #[test]
+    fn without_extended_underline() {
+        assert_eq!(Style::new().dotted_underline().to_string(), "\x1b[4m");
+        let style = Style::new().bold().curly_underline();
+        assert_eq!(style.to_string(), "\x1b[1;4m");
+        assert_eq!(Style::new().double_underline().to_string(), "\x1b[21m");
+        assert_eq!(
+            Style::new().bold().double_underline().to_string(),
+            "\x1b[1;21m"
+        );
+        assert_eq!(
+            crate::spans::Transition {
+                from: Style::new().bold().double_underline(),
+                to: style,
+            }
+            .to_string(),
+            "\x1b[24;4m"
+        );
+    }
+
+    #[cfg(not(feature = "underline-color"))]
+    #[test]
+    fn without_underline_color() {
+        let style = Style::new().underline().underline_color(BasicColor::Red);
+        assert_eq!(style.to_string(), "\x1b[4m");
+        let style = Style::new().underline_color(Color::Default);
+        assert_eq!(style.to_string(), "");
+        assert_eq!(style.rendered_len(), 0);
+    }
+
+    #[cfg(not(feature = "truecolor"))]
+    #[test]
+    fn without_truecolor() {
+        let style = Style::new()
+            .fg(RGBColor::new(0xf0, 0x10, 0x10))
+            .bg(RGBColor::new(0x00, 0x00, 0xe0));
+        assert_eq!(style.to_string(), "\x1b[91;44m");
+        assert_eq!(style.fg(IndexedColor(196)).to_string(), "\x1b[38;5;196;44m");
+    }
+}
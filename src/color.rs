@@ -42,21 +42,52 @@
 //!
 //! The trait [`ColorKind`] is implemented for all color types, and provides methods to associate a color
 //! to a [`ColorTarget`] (foreground or background), returning a [`TargetedColor`](crate::TargetedColor) value.
+//!
+//! The [`Color::Default`] variant represents the terminal's default color for a target. It can be used
+//! to revert a single color target without resetting the whole style:
+//!
+//! ```
+//! use fluent_ansi::prelude::*;
+//!
+//! assert_eq!(format!("{}", Color::Default.for_bg()), "\x1b[49m");
+//! ```
+//!
+//! The actual RGB values of the non-RGB colors depend on the terminal's palette, which is modeled by [`Palette`].
+//!
+//! [`RGBColor::ensure_contrast()`] adjusts a foreground color to be readable on a background color, following the
+//! [WCAG](https://www.w3.org/TR/WCAG21/#contrast-minimum) contrast requirements.
+//!
+//! The [`named`] module provides the CSS/X11 named colors as [`RGBColor`] constants.
+//!
+//! Colors can be parsed from strings with [`str::parse()`]. See the [`FromStr`](core::str::FromStr)
+//! implementation for [`Color`] for the accepted forms:
+//!
+//! ```
+//! use fluent_ansi::prelude::*;
+//!
+//! let color: Color = "bright-cyan".parse().unwrap();
+//! assert_eq!(color, Color::CYAN.bright());
+//! ```
 
-use core::fmt::Result;
-
-use crate::{CodeWriter, ColorTarget};
+use crate::{Code, ColorTarget};
 pub use basic::*;
 pub use color_kind::*;
 pub use indexed::*;
+pub use palette::*;
+pub use parse::*;
 pub use rgb::*;
 pub use simple::*;
 
 mod basic;
 mod color_kind;
+mod contrast;
 mod indexed;
+pub mod named;
+mod palette;
+mod parse;
 mod rgb;
 mod simple;
+mod xterm;
 
 /// An enum representing all supported color types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -67,6 +98,8 @@ pub enum Color {
     Indexed(IndexedColor),
     /// An RGB color (24-bit/true color).
     RGB(RGBColor),
+    /// The terminal's default color for the target where it is applied.
+    Default,
 }
 
 impl Color {
@@ -108,12 +141,17 @@ impl Color {
     }
 }
 
-impl WriteColorCodes for Color {
-    fn write_color_codes(self, target: ColorTarget, writer: &mut CodeWriter) -> Result {
+impl ColorCode for Color {
+    fn color_code(self, target: ColorTarget) -> Code {
         match self {
-            Color::Simple(simple) => simple.write_color_codes(target, writer),
-            Color::Indexed(indexed) => indexed.write_color_codes(target, writer),
-            Color::RGB(rgb) => rgb.write_color_codes(target, writer),
+            Color::Simple(simple) => simple.color_code(target),
+            Color::Indexed(indexed) => indexed.color_code(target),
+            Color::RGB(rgb) => rgb.color_code(target),
+            Color::Default => Code::Single(match target {
+                ColorTarget::Foreground => 39,
+                ColorTarget::Background => 49,
+                ColorTarget::Underline => 59,
+            }),
         }
     }
 }
@@ -166,7 +204,10 @@ impl_reflexive_partial_eq!(BasicColor::to_simple_color() -> SimpleColor);
 
 #[cfg(test)]
 mod tests {
-    use crate::{TargetedColor, test_to_style_set_methods_with_foreground_assumed};
+    use crate::{
+        TargetedColor, ToStyleSet as _, assert_display,
+        test_to_style_set_methods_with_foreground_assumed,
+    };
 
     use super::*;
 
@@ -190,6 +231,17 @@ mod tests {
         assert_eq!(Color::rgb(0, 128, 255), RGBColor::new(0, 128, 255));
     }
 
+    #[test]
+    fn default_display() {
+        assert_display!(Color::Default.for_fg(), "\x1b[39m");
+        assert_display!(Color::Default.for_bg(), "\x1b[49m");
+        #[cfg(feature = "underline-color")]
+        assert_display!(Color::Default.for_underline(), "\x1b[59m");
+        #[cfg(not(feature = "underline-color"))]
+        assert_display!(Color::Default.for_underline(), "");
+        assert_display!(Color::RED.bold().bg(Color::Default), "\x1b[1;31;49m");
+    }
+
     #[test]
     fn for_fg() {
         let color = BasicColor::Red.to_color();
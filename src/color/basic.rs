@@ -35,6 +35,18 @@ pub enum BasicColor {
 }
 
 impl BasicColor {
+    /// All the basic colors, in the order of their SGR parameters.
+    pub const ALL: [BasicColor; 8] = [
+        BasicColor::Black,
+        BasicColor::Red,
+        BasicColor::Green,
+        BasicColor::Yellow,
+        BasicColor::Blue,
+        BasicColor::Magenta,
+        BasicColor::Cyan,
+        BasicColor::White,
+    ];
+
     /// Convert this basic color into a [`SimpleColor`].
     #[must_use]
     pub fn to_simple_color(self) -> SimpleColor {
@@ -1,7 +1,5 @@
-use core::fmt::Result;
-
 use crate::{
-    AppliedTo, CodeWriter, ColorTarget, Style, StyleElement, TargetedColor, ToStyle, ToStyleSet,
+    AppliedTo, Code, ColorTarget, Style, StyleElement, TargetedColor, ToStyle, ToStyleSet,
     color::Color,
 };
 
@@ -38,8 +36,8 @@ pub trait ColorKind: Into<Color> {
     }
 }
 
-pub(crate) trait WriteColorCodes: ColorKind {
-    fn write_color_codes(self, target: ColorTarget, writer: &mut CodeWriter) -> Result;
+pub(crate) trait ColorCode: ColorKind {
+    fn color_code(self, target: ColorTarget) -> Code;
 }
 
 impl<C: Into<Color>> ColorKind for C {}
@@ -0,0 +1,188 @@
+use crate::color::RGBColor;
+
+/// The linear-light values of the sRGB component values, as defined by WCAG.
+const LINEAR_COMPONENTS: [f32; 256] = {
+    let mut components = [0.0; 256];
+    let mut value = 0;
+    while value < components.len() {
+        #[expect(clippy::cast_precision_loss, reason = "the value is less than 256")]
+        let c = value as f32 / 255.0;
+        components[value] = if c <= 0.040_45 {
+            c / 12.92
+        } else {
+            let base = (c + 0.055) / 1.055;
+            base * base * fifth_root(base * base)
+        };
+        value += 1;
+    }
+    components
+};
+
+/// Computes the fifth root of a value in the range `(0, 1]` with Newton's method, as `powf()` is not available in
+/// `core`.
+const fn fifth_root(value: f32) -> f32 {
+    let mut root = 1.0;
+    let mut iteration = 0;
+    while iteration < 50 {
+        let fourth_power = root * root * root * root;
+        root -= (fourth_power * root - value) / (5.0 * fourth_power);
+        iteration += 1;
+    }
+    root
+}
+
+impl RGBColor {
+    /// Returns the relative luminance of this color, from `0.0` for black to `1.0` for white, as defined by
+    /// [WCAG](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance).
+    #[must_use]
+    pub fn relative_luminance(self) -> f32 {
+        0.2126 * LINEAR_COMPONENTS[self.r as usize]
+            + 0.7152 * LINEAR_COMPONENTS[self.g as usize]
+            + 0.0722 * LINEAR_COMPONENTS[self.b as usize]
+    }
+
+    /// Returns the contrast ratio between two colors, from `1.0` for equal colors to `21.0` for black and white, as
+    /// defined by [WCAG](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio).
+    ///
+    /// ```
+    /// use fluent_ansi::color::RGBColor;
+    ///
+    /// let black = RGBColor::new(0, 0, 0);
+    /// let white = RGBColor::new(255, 255, 255);
+    /// assert!((RGBColor::contrast_ratio(black, white) - 21.0).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn contrast_ratio(a: Self, b: Self) -> f32 {
+        let (a, b) = (a.relative_luminance(), b.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns the foreground color adjusted to have at least the given contrast ratio against the background color.
+    ///
+    /// If the ratio is not met, the foreground color is mixed with white or black, the least possible, away from the
+    /// background color. It is made lighter if it is already lighter than the background, and darker otherwise,
+    /// unless only the other direction can meet the ratio. If neither can, the one of white and black with the highest
+    /// contrast is returned.
+    ///
+    /// WCAG requires a ratio of 4.5 for normal text, and 3 for large text:
+    ///
+    /// ```
+    /// use fluent_ansi::color::RGBColor;
+    ///
+    /// let bg = RGBColor::new(255, 255, 255);
+    /// let fg = RGBColor::ensure_contrast(RGBColor::new(255, 128, 0), bg, 4.5);
+    /// assert!(RGBColor::contrast_ratio(fg, bg) >= 4.5);
+    /// assert_eq!(fg, RGBColor::new(186, 93, 0));
+    ///
+    /// assert_eq!(RGBColor::ensure_contrast(fg, bg, 3.0), fg);
+    /// ```
+    ///
+    /// The ratio can be computed with [`contrast_ratio()`](RGBColor::contrast_ratio).
+    #[must_use]
+    pub fn ensure_contrast(fg: Self, bg: Self, ratio: f32) -> Self {
+        const WHITE: RGBColor = RGBColor::new(255, 255, 255);
+        const BLACK: RGBColor = RGBColor::new(0, 0, 0);
+
+        if Self::contrast_ratio(fg, bg) >= ratio {
+            return fg;
+        }
+        let directions = if fg.relative_luminance() >= bg.relative_luminance() {
+            [WHITE, BLACK]
+        } else {
+            [BLACK, WHITE]
+        };
+        directions
+            .into_iter()
+            .find_map(|extreme| fg.nudge_toward(extreme, bg, ratio))
+            .unwrap_or_else(|| {
+                if Self::contrast_ratio(WHITE, bg) >= Self::contrast_ratio(BLACK, bg) {
+                    WHITE
+                } else {
+                    BLACK
+                }
+            })
+    }
+
+    /// Mixes this color with an extreme the least possible to meet the contrast ratio against the background color
+    /// on the side of the extreme, if possible.
+    fn nudge_toward(self, extreme: Self, bg: Self, ratio: f32) -> Option<Self> {
+        let lighter = extreme.relative_luminance() > bg.relative_luminance();
+        let meets = |color: Self| {
+            (color.relative_luminance() > bg.relative_luminance()) == lighter
+                && Self::contrast_ratio(color, bg) >= ratio
+        };
+        if !meets(extreme) {
+            return None;
+        }
+        let (mut low, mut high) = (0, Self::MIX_SCALE);
+        while low < high {
+            let t = low + (high - low) / 2;
+            if meets(self.mix(extreme, t)) {
+                high = t;
+            } else {
+                low = t + 1;
+            }
+        }
+        Some(self.mix(extreme, high))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 0.001, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn relative_luminance() {
+        assert_close(RGBColor::new(0, 0, 0).relative_luminance(), 0.0);
+        assert_close(RGBColor::new(255, 255, 255).relative_luminance(), 1.0);
+        assert_close(RGBColor::new(255, 0, 0).relative_luminance(), 0.2126);
+        assert_close(RGBColor::new(0x77, 0x77, 0x77).relative_luminance(), 0.1845);
+        assert_close(RGBColor::new(5, 5, 5).relative_luminance(), 0.001_518);
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        let white = RGBColor::new(255, 255, 255);
+        assert_close(RGBColor::contrast_ratio(white, white), 1.0);
+        assert_close(
+            RGBColor::contrast_ratio(RGBColor::new(0x76, 0x76, 0x76), white),
+            4.542,
+        );
+        assert_close(
+            RGBColor::contrast_ratio(white, RGBColor::new(0x76, 0x76, 0x76)),
+            4.542,
+        );
+    }
+
+    #[test]
+    fn ensure_contrast() {
+        let black = RGBColor::new(0, 0, 0);
+        let white = RGBColor::new(255, 255, 255);
+        let gray = RGBColor::new(0x80, 0x80, 0x80);
+
+        let fg = RGBColor::ensure_contrast(RGBColor::new(0x40, 0x40, 0x40), black, 4.5);
+        assert!(RGBColor::contrast_ratio(fg, black) >= 4.5);
+        assert!(fg.r > 0x40);
+        let darker = RGBColor::new(fg.r - 1, fg.g - 1, fg.b - 1);
+        assert!(RGBColor::contrast_ratio(darker, black) < 4.5);
+
+        let fg = RGBColor::ensure_contrast(RGBColor::new(0x70, 0x70, 0x70), gray, 3.0);
+        assert_eq!(fg, RGBColor::new(0x37, 0x37, 0x37));
+
+        let fg = RGBColor::ensure_contrast(
+            RGBColor::new(0x10, 0x10, 0x10),
+            RGBColor::new(0x20, 0x20, 0x20),
+            7.0,
+        );
+        assert!(fg.r > 0x20);
+        assert!(RGBColor::contrast_ratio(fg, RGBColor::new(0x20, 0x20, 0x20)) >= 7.0);
+
+        assert_eq!(RGBColor::ensure_contrast(gray, gray, 21.0), black);
+        assert_eq!(RGBColor::ensure_contrast(gray, black, 30.0), white);
+        assert_eq!(RGBColor::ensure_contrast(gray, white, 1.0), gray);
+    }
+}
@@ -1,6 +1,4 @@
-use core::fmt::Result;
-
-use crate::{CodeWriter, ColorTarget, color::WriteColorCodes};
+use crate::{Code, ColorTarget, color::ColorCode};
 
 /// An 8-bit color type representing colors in the 256-color ANSI palette.
 ///
@@ -30,18 +28,15 @@ impl IndexedColor {
     }
 }
 
-impl WriteColorCodes for IndexedColor {
-    fn write_color_codes(self, target: ColorTarget, writer: &mut CodeWriter) -> Result {
+impl ColorCode for IndexedColor {
+    fn color_code(self, target: ColorTarget) -> Code {
         let target_code = match target {
             ColorTarget::Foreground => 38,
             ColorTarget::Background => 48,
             ColorTarget::Underline => 58,
         };
 
-        writer.write_code(target_code)?;
-        writer.write_code(5)?;
-        writer.write_code(self.0)?;
-        Ok(())
+        Code::Indexed(target_code, self.0)
     }
 }
 
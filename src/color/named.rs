@@ -0,0 +1,256 @@
+//! Named colors from the CSS/X11 color set.
+//!
+//! All colors are [`RGBColor`] constants named after their CSS names:
+//!
+//! ```
+//! use fluent_ansi::{prelude::*, color::{named, RGBColor}, Style};
+//!
+//! assert_eq!(named::DODGER_BLUE, RGBColor::new(30, 144, 255));
+//!
+//! let style = Style::new().fg(named::ORANGE).bg(named::TEAL);
+//! ```
+//!
+//! A color can also be looked up by its name with [`from_name()`].
+
+use crate::color::RGBColor;
+
+macro_rules! named_colors {
+    ($( $name:ident = $css:literal ($r:literal, $g:literal, $b:literal) $hex:literal; )*) => {
+        $(
+            #[doc = concat!("The CSS color `", $css, "` (`", $hex, "`).")]
+            pub const $name: RGBColor = RGBColor::new($r, $g, $b);
+        )*
+
+        const NAMED_COLORS: &[(&str, RGBColor)] = &[
+            $( ($css, $name), )*
+        ];
+    };
+}
+
+named_colors! {
+    ALICE_BLUE = "aliceblue" (240, 248, 255) "#F0F8FF";
+    ANTIQUE_WHITE = "antiquewhite" (250, 235, 215) "#FAEBD7";
+    AQUA = "aqua" (0, 255, 255) "#00FFFF";
+    AQUAMARINE = "aquamarine" (127, 255, 212) "#7FFFD4";
+    AZURE = "azure" (240, 255, 255) "#F0FFFF";
+    BEIGE = "beige" (245, 245, 220) "#F5F5DC";
+    BISQUE = "bisque" (255, 228, 196) "#FFE4C4";
+    BLACK = "black" (0, 0, 0) "#000000";
+    BLANCHED_ALMOND = "blanchedalmond" (255, 235, 205) "#FFEBCD";
+    BLUE = "blue" (0, 0, 255) "#0000FF";
+    BLUE_VIOLET = "blueviolet" (138, 43, 226) "#8A2BE2";
+    BROWN = "brown" (165, 42, 42) "#A52A2A";
+    BURLYWOOD = "burlywood" (222, 184, 135) "#DEB887";
+    CADET_BLUE = "cadetblue" (95, 158, 160) "#5F9EA0";
+    CHARTREUSE = "chartreuse" (127, 255, 0) "#7FFF00";
+    CHOCOLATE = "chocolate" (210, 105, 30) "#D2691E";
+    CORAL = "coral" (255, 127, 80) "#FF7F50";
+    CORNFLOWER_BLUE = "cornflowerblue" (100, 149, 237) "#6495ED";
+    CORNSILK = "cornsilk" (255, 248, 220) "#FFF8DC";
+    CRIMSON = "crimson" (220, 20, 60) "#DC143C";
+    CYAN = "cyan" (0, 255, 255) "#00FFFF";
+    DARK_BLUE = "darkblue" (0, 0, 139) "#00008B";
+    DARK_CYAN = "darkcyan" (0, 139, 139) "#008B8B";
+    DARK_GOLDENROD = "darkgoldenrod" (184, 134, 11) "#B8860B";
+    DARK_GRAY = "darkgray" (169, 169, 169) "#A9A9A9";
+    DARK_GREY = "darkgrey" (169, 169, 169) "#A9A9A9";
+    DARK_GREEN = "darkgreen" (0, 100, 0) "#006400";
+    DARK_KHAKI = "darkkhaki" (189, 183, 107) "#BDB76B";
+    DARK_MAGENTA = "darkmagenta" (139, 0, 139) "#8B008B";
+    DARK_OLIVE_GREEN = "darkolivegreen" (85, 107, 47) "#556B2F";
+    DARK_ORANGE = "darkorange" (255, 140, 0) "#FF8C00";
+    DARK_ORCHID = "darkorchid" (153, 50, 204) "#9932CC";
+    DARK_RED = "darkred" (139, 0, 0) "#8B0000";
+    DARK_SALMON = "darksalmon" (233, 150, 122) "#E9967A";
+    DARK_SEA_GREEN = "darkseagreen" (143, 188, 143) "#8FBC8F";
+    DARK_SLATE_BLUE = "darkslateblue" (72, 61, 139) "#483D8B";
+    DARK_SLATE_GRAY = "darkslategray" (47, 79, 79) "#2F4F4F";
+    DARK_SLATE_GREY = "darkslategrey" (47, 79, 79) "#2F4F4F";
+    DARK_TURQUOISE = "darkturquoise" (0, 206, 209) "#00CED1";
+    DARK_VIOLET = "darkviolet" (148, 0, 211) "#9400D3";
+    DEEP_PINK = "deeppink" (255, 20, 147) "#FF1493";
+    DEEP_SKY_BLUE = "deepskyblue" (0, 191, 255) "#00BFFF";
+    DIM_GRAY = "dimgray" (105, 105, 105) "#696969";
+    DIM_GREY = "dimgrey" (105, 105, 105) "#696969";
+    DODGER_BLUE = "dodgerblue" (30, 144, 255) "#1E90FF";
+    FIREBRICK = "firebrick" (178, 34, 34) "#B22222";
+    FLORAL_WHITE = "floralwhite" (255, 250, 240) "#FFFAF0";
+    FOREST_GREEN = "forestgreen" (34, 139, 34) "#228B22";
+    FUCHSIA = "fuchsia" (255, 0, 255) "#FF00FF";
+    GAINSBORO = "gainsboro" (220, 220, 220) "#DCDCDC";
+    GHOST_WHITE = "ghostwhite" (248, 248, 255) "#F8F8FF";
+    GOLD = "gold" (255, 215, 0) "#FFD700";
+    GOLDENROD = "goldenrod" (218, 165, 32) "#DAA520";
+    GRAY = "gray" (128, 128, 128) "#808080";
+    GREY = "grey" (128, 128, 128) "#808080";
+    GREEN = "green" (0, 128, 0) "#008000";
+    GREEN_YELLOW = "greenyellow" (173, 255, 47) "#ADFF2F";
+    HONEYDEW = "honeydew" (240, 255, 240) "#F0FFF0";
+    HOT_PINK = "hotpink" (255, 105, 180) "#FF69B4";
+    INDIAN_RED = "indianred" (205, 92, 92) "#CD5C5C";
+    INDIGO = "indigo" (75, 0, 130) "#4B0082";
+    IVORY = "ivory" (255, 255, 240) "#FFFFF0";
+    KHAKI = "khaki" (240, 230, 140) "#F0E68C";
+    LAVENDER = "lavender" (230, 230, 250) "#E6E6FA";
+    LAVENDER_BLUSH = "lavenderblush" (255, 240, 245) "#FFF0F5";
+    LAWN_GREEN = "lawngreen" (124, 252, 0) "#7CFC00";
+    LEMON_CHIFFON = "lemonchiffon" (255, 250, 205) "#FFFACD";
+    LIGHT_BLUE = "lightblue" (173, 216, 230) "#ADD8E6";
+    LIGHT_CORAL = "lightcoral" (240, 128, 128) "#F08080";
+    LIGHT_CYAN = "lightcyan" (224, 255, 255) "#E0FFFF";
+    LIGHT_GOLDENROD_YELLOW = "lightgoldenrodyellow" (250, 250, 210) "#FAFAD2";
+    LIGHT_GRAY = "lightgray" (211, 211, 211) "#D3D3D3";
+    LIGHT_GREY = "lightgrey" (211, 211, 211) "#D3D3D3";
+    LIGHT_GREEN = "lightgreen" (144, 238, 144) "#90EE90";
+    LIGHT_PINK = "lightpink" (255, 182, 193) "#FFB6C1";
+    LIGHT_SALMON = "lightsalmon" (255, 160, 122) "#FFA07A";
+    LIGHT_SEA_GREEN = "lightseagreen" (32, 178, 170) "#20B2AA";
+    LIGHT_SKY_BLUE = "lightskyblue" (135, 206, 250) "#87CEFA";
+    LIGHT_SLATE_GRAY = "lightslategray" (119, 136, 153) "#778899";
+    LIGHT_SLATE_GREY = "lightslategrey" (119, 136, 153) "#778899";
+    LIGHT_STEEL_BLUE = "lightsteelblue" (176, 196, 222) "#B0C4DE";
+    LIGHT_YELLOW = "lightyellow" (255, 255, 224) "#FFFFE0";
+    LIME = "lime" (0, 255, 0) "#00FF00";
+    LIME_GREEN = "limegreen" (50, 205, 50) "#32CD32";
+    LINEN = "linen" (250, 240, 230) "#FAF0E6";
+    MAGENTA = "magenta" (255, 0, 255) "#FF00FF";
+    MAROON = "maroon" (128, 0, 0) "#800000";
+    MEDIUM_AQUAMARINE = "mediumaquamarine" (102, 205, 170) "#66CDAA";
+    MEDIUM_BLUE = "mediumblue" (0, 0, 205) "#0000CD";
+    MEDIUM_ORCHID = "mediumorchid" (186, 85, 211) "#BA55D3";
+    MEDIUM_PURPLE = "mediumpurple" (147, 112, 219) "#9370DB";
+    MEDIUM_SEA_GREEN = "mediumseagreen" (60, 179, 113) "#3CB371";
+    MEDIUM_SLATE_BLUE = "mediumslateblue" (123, 104, 238) "#7B68EE";
+    MEDIUM_SPRING_GREEN = "mediumspringgreen" (0, 250, 154) "#00FA9A";
+    MEDIUM_TURQUOISE = "mediumturquoise" (72, 209, 204) "#48D1CC";
+    MEDIUM_VIOLET_RED = "mediumvioletred" (199, 21, 133) "#C71585";
+    MIDNIGHT_BLUE = "midnightblue" (25, 25, 112) "#191970";
+    MINT_CREAM = "mintcream" (245, 255, 250) "#F5FFFA";
+    MISTY_ROSE = "mistyrose" (255, 228, 225) "#FFE4E1";
+    MOCCASIN = "moccasin" (255, 228, 181) "#FFE4B5";
+    NAVAJO_WHITE = "navajowhite" (255, 222, 173) "#FFDEAD";
+    NAVY = "navy" (0, 0, 128) "#000080";
+    OLD_LACE = "oldlace" (253, 245, 230) "#FDF5E6";
+    OLIVE = "olive" (128, 128, 0) "#808000";
+    OLIVE_DRAB = "olivedrab" (107, 142, 35) "#6B8E23";
+    ORANGE = "orange" (255, 165, 0) "#FFA500";
+    ORANGE_RED = "orangered" (255, 69, 0) "#FF4500";
+    ORCHID = "orchid" (218, 112, 214) "#DA70D6";
+    PALE_GOLDENROD = "palegoldenrod" (238, 232, 170) "#EEE8AA";
+    PALE_GREEN = "palegreen" (152, 251, 152) "#98FB98";
+    PALE_TURQUOISE = "paleturquoise" (175, 238, 238) "#AFEEEE";
+    PALE_VIOLET_RED = "palevioletred" (219, 112, 147) "#DB7093";
+    PAPAYA_WHIP = "papayawhip" (255, 239, 213) "#FFEFD5";
+    PEACH_PUFF = "peachpuff" (255, 218, 185) "#FFDAB9";
+    PERU = "peru" (205, 133, 63) "#CD853F";
+    PINK = "pink" (255, 192, 203) "#FFC0CB";
+    PLUM = "plum" (221, 160, 221) "#DDA0DD";
+    POWDER_BLUE = "powderblue" (176, 224, 230) "#B0E0E6";
+    PURPLE = "purple" (128, 0, 128) "#800080";
+    REBECCA_PURPLE = "rebeccapurple" (102, 51, 153) "#663399";
+    RED = "red" (255, 0, 0) "#FF0000";
+    ROSY_BROWN = "rosybrown" (188, 143, 143) "#BC8F8F";
+    ROYAL_BLUE = "royalblue" (65, 105, 225) "#4169E1";
+    SADDLE_BROWN = "saddlebrown" (139, 69, 19) "#8B4513";
+    SALMON = "salmon" (250, 128, 114) "#FA8072";
+    SANDY_BROWN = "sandybrown" (244, 164, 96) "#F4A460";
+    SEA_GREEN = "seagreen" (46, 139, 87) "#2E8B57";
+    SEASHELL = "seashell" (255, 245, 238) "#FFF5EE";
+    SIENNA = "sienna" (160, 82, 45) "#A0522D";
+    SILVER = "silver" (192, 192, 192) "#C0C0C0";
+    SKY_BLUE = "skyblue" (135, 206, 235) "#87CEEB";
+    SLATE_BLUE = "slateblue" (106, 90, 205) "#6A5ACD";
+    SLATE_GRAY = "slategray" (112, 128, 144) "#708090";
+    SLATE_GREY = "slategrey" (112, 128, 144) "#708090";
+    SNOW = "snow" (255, 250, 250) "#FFFAFA";
+    SPRING_GREEN = "springgreen" (0, 255, 127) "#00FF7F";
+    STEEL_BLUE = "steelblue" (70, 130, 180) "#4682B4";
+    TAN = "tan" (210, 180, 140) "#D2B48C";
+    TEAL = "teal" (0, 128, 128) "#008080";
+    THISTLE = "thistle" (216, 191, 216) "#D8BFD8";
+    TOMATO = "tomato" (255, 99, 71) "#FF6347";
+    TURQUOISE = "turquoise" (64, 224, 208) "#40E0D0";
+    VIOLET = "violet" (238, 130, 238) "#EE82EE";
+    WHEAT = "wheat" (245, 222, 179) "#F5DEB3";
+    WHITE = "white" (255, 255, 255) "#FFFFFF";
+    WHITE_SMOKE = "whitesmoke" (245, 245, 245) "#F5F5F5";
+    YELLOW = "yellow" (255, 255, 0) "#FFFF00";
+    YELLOW_GREEN = "yellowgreen" (154, 205, 50) "#9ACD32";
+}
+
+/// Looks up a named color by its CSS name.
+///
+/// The lookup is case-insensitive, and the characters `_`, `-` and ` ` are ignored, so
+/// `"dodgerblue"`, `"DodgerBlue"`, `"dodger-blue"` and `"DODGER_BLUE"` all find the same color.
+///
+/// ```
+/// use fluent_ansi::color::named;
+///
+/// assert_eq!(named::from_name("dodger-blue"), Some(named::DODGER_BLUE));
+/// assert_eq!(named::from_name("not a color"), None);
+/// ```
+#[must_use]
+pub const fn from_name(name: &str) -> Option<RGBColor> {
+    let mut i = 0;
+    while i < NAMED_COLORS.len() {
+        let (css_name, color) = NAMED_COLORS[i];
+        if matches_name(css_name.as_bytes(), name.as_bytes()) {
+            return Some(color);
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn matches_name(css_name: &[u8], name: &[u8]) -> bool {
+    let mut css_index = 0;
+    let mut index = 0;
+    while index < name.len() {
+        let byte = name[index];
+        index += 1;
+        if matches!(byte, b'_' | b'-' | b' ') {
+            continue;
+        }
+        if css_index == css_name.len() || css_name[css_index] != byte.to_ascii_lowercase() {
+            return false;
+        }
+        css_index += 1;
+    }
+    css_index == css_name.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constants() {
+        assert_eq!(ORANGE, RGBColor::new(255, 165, 0));
+        assert_eq!(TEAL, RGBColor::new(0, 128, 128));
+        assert_eq!(DODGER_BLUE, RGBColor::new(30, 144, 255));
+        assert_eq!(GRAY, GREY);
+    }
+
+    #[test]
+    fn from_name_exact() {
+        for &(css_name, color) in NAMED_COLORS {
+            assert_eq!(from_name(css_name), Some(color), "{css_name}");
+        }
+    }
+
+    #[test]
+    fn from_name_normalized() {
+        assert_eq!(from_name("DodgerBlue"), Some(DODGER_BLUE));
+        assert_eq!(from_name("dodger-blue"), Some(DODGER_BLUE));
+        assert_eq!(from_name("dodger blue"), Some(DODGER_BLUE));
+        assert_eq!(from_name("DODGER_BLUE"), Some(DODGER_BLUE));
+    }
+
+    #[test]
+    fn from_name_unknown() {
+        assert_eq!(from_name(""), None);
+        assert_eq!(from_name("dodger"), None);
+        assert_eq!(from_name("dodgerbluee"), None);
+        assert_eq!(from_name("not a color"), None);
+    }
+}
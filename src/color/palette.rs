@@ -0,0 +1,198 @@
+use crate::color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor};
+
+/// The RGB values of the 256 indexed colors of a terminal.
+///
+/// The first 16 entries are the [`SimpleColor`]s, in index order. Terminals let users customize their palettes, so the
+/// actual RGB value of a non-RGB color depends on the terminal. [`Palette::XTERM`], which is also the default, has
+/// the values used by xterm:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, color::{IndexedColor, Palette, RGBColor}};
+///
+/// let solarized = Palette::XTERM.with_entry(IndexedColor(1), RGBColor::new(0xdc, 0x32, 0x2f));
+/// assert_eq!(solarized.resolve(Color::RED), Some(RGBColor::new(0xdc, 0x32, 0x2f)));
+/// assert_eq!(Palette::XTERM.resolve(Color::RED), Some(RGBColor::new(0xcd, 0x00, 0x00)));
+///
+/// assert_eq!(solarized.nearest(RGBColor::new(0xdd, 0x30, 0x30)), IndexedColor(1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Palette {
+    entries: [RGBColor; 256],
+}
+
+impl Palette {
+    /// xterm's default palette.
+    pub const XTERM: Palette = {
+        let mut entries = [RGBColor::new(0, 0, 0); 256];
+        let mut index = 0;
+        while index < entries.len() {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "the index is less than 256"
+            )]
+            let color = IndexedColor(index as u8);
+            entries[index] = color.to_xterm_rgb();
+            index += 1;
+        }
+        Palette { entries }
+    };
+
+    /// Creates a new `Palette` value with the given entries, in index order.
+    #[must_use]
+    pub const fn new(entries: [RGBColor; 256]) -> Self {
+        Self { entries }
+    }
+
+    /// Gets the entries, in index order.
+    #[must_use]
+    pub const fn get_entries(&self) -> &[RGBColor; 256] {
+        &self.entries
+    }
+
+    /// Gets the RGB value of an indexed color.
+    #[must_use]
+    pub const fn get(&self, color: IndexedColor) -> RGBColor {
+        self.entries[color.0 as usize]
+    }
+
+    /// Returns a copy of this palette with the RGB value of an indexed color replaced.
+    #[must_use]
+    pub const fn with_entry(mut self, color: IndexedColor, rgb: RGBColor) -> Self {
+        self.entries[color.0 as usize] = rgb;
+        self
+    }
+
+    /// Returns the RGB value of a color in this palette, or `None` for [`Color::Default`].
+    ///
+    /// RGB colors are returned unchanged.
+    #[must_use]
+    pub fn resolve(&self, color: impl Into<Color>) -> Option<RGBColor> {
+        match color.into() {
+            Color::Simple(simple) => Some(self.entries[simple.index() as usize]),
+            Color::Indexed(indexed) => Some(self.get(indexed)),
+            Color::RGB(rgb) => Some(rgb),
+            Color::Default => None,
+        }
+    }
+
+    /// Returns the indexed color whose entry is the nearest to an RGB value.
+    ///
+    /// The distance is the Euclidean distance between the RGB components. On ties, the lowest index wins.
+    #[must_use]
+    pub fn nearest(&self, rgb: RGBColor) -> IndexedColor {
+        IndexedColor(nearest_index(&self.entries, rgb))
+    }
+
+    /// Returns the simple color whose entry is the nearest to an RGB value.
+    ///
+    /// It is like [`nearest()`](Palette::nearest), but only the first 16 entries are considered.
+    #[must_use]
+    pub fn nearest_simple(&self, rgb: RGBColor) -> SimpleColor {
+        nearest_simple(&self.entries[..16], rgb)
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::XTERM
+    }
+}
+
+/// Returns the simple color whose entry is the nearest to an RGB value, given the entries of the 16 simple colors.
+pub(super) fn nearest_simple(entries: &[RGBColor], rgb: RGBColor) -> SimpleColor {
+    let index = nearest_index(entries, rgb);
+    let basic = BasicColor::ALL[index as usize % 8];
+    if index < 8 {
+        SimpleColor::new(basic)
+    } else {
+        SimpleColor::new_bright(basic)
+    }
+}
+
+fn nearest_index(entries: &[RGBColor], rgb: RGBColor) -> u8 {
+    let distance = |entry: &RGBColor| {
+        let component = |a: u8, b: u8| u32::from(a.abs_diff(b)).pow(2);
+        component(entry.r, rgb.r) + component(entry.g, rgb.g) + component(entry.b, rgb.b)
+    };
+    let (index, _) = entries
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, entry)| distance(entry))
+        .expect("the palette is not empty");
+    u8::try_from(index).expect("the palette has 256 entries")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xterm() {
+        assert_eq!(Palette::default(), Palette::XTERM);
+        assert_eq!(
+            Palette::XTERM.get(IndexedColor(12)),
+            RGBColor::new(0x5c, 0x5c, 0xff)
+        );
+        assert_eq!(
+            Palette::XTERM.get(IndexedColor(33)),
+            RGBColor::new(0x00, 0x87, 0xff)
+        );
+        assert_eq!(
+            Palette::XTERM.get(IndexedColor(255)),
+            RGBColor::new(0xee, 0xee, 0xee)
+        );
+        for index in 0..=255 {
+            assert_eq!(
+                Palette::XTERM.get_entries()[usize::from(index)],
+                IndexedColor(index).to_xterm_rgb()
+            );
+        }
+    }
+
+    #[test]
+    fn custom_entries() {
+        let black = RGBColor::new(0x1e, 0x1e, 0x2e);
+        let palette = Palette::XTERM.with_entry(IndexedColor(0), black);
+        assert_eq!(palette.get(IndexedColor(0)), black);
+        assert_eq!(palette.resolve(Color::BLACK), Some(black));
+        assert_eq!(palette.resolve(Color::indexed(0)), Some(black));
+        assert_eq!(
+            palette.resolve(Color::BLACK.bright()),
+            Some(RGBColor::new(0x7f, 0x7f, 0x7f))
+        );
+        assert_eq!(
+            palette.resolve(Color::rgb(1, 2, 3)),
+            Some(RGBColor::new(1, 2, 3))
+        );
+        assert_eq!(palette.resolve(Color::Default), None);
+
+        let palette = Palette::new([RGBColor::new(9, 9, 9); 256]);
+        assert_eq!(palette.get(IndexedColor(200)), RGBColor::new(9, 9, 9));
+    }
+
+    #[test]
+    fn nearest() {
+        let palette = Palette::XTERM;
+        assert_eq!(palette.nearest(RGBColor::new(0, 0, 0)), IndexedColor(0));
+        assert_eq!(
+            palette.nearest(RGBColor::new(0, 0x88, 0xfe)),
+            IndexedColor(33)
+        );
+        assert_eq!(palette.nearest(RGBColor::new(9, 9, 9)), IndexedColor(232));
+
+        assert_eq!(
+            palette.nearest_simple(RGBColor::new(0xc0, 0x10, 0x10)),
+            SimpleColor::new(BasicColor::Red)
+        );
+        assert_eq!(
+            palette.nearest_simple(RGBColor::new(0x60, 0x60, 0xf0)),
+            SimpleColor::new_bright(BasicColor::Blue)
+        );
+
+        let palette = palette.with_entry(IndexedColor(1), RGBColor::new(0x60, 0x60, 0xf0));
+        assert_eq!(
+            palette.nearest_simple(RGBColor::new(0x60, 0x60, 0xf0)),
+            SimpleColor::new(BasicColor::Red)
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use core::{
+    error::Error,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use crate::color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor, named};
+
+/// The error returned when parsing a [`Color`] from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseColorError;
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid color")
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// Parses a color from a string.
+///
+/// The accepted forms are:
+/// - a basic color name (`"red"`), optionally prefixed with `bright` (`"bright-cyan"`, `"brightcyan"`),
+///   resulting in a [`Color::Simple`] color;
+/// - a decimal index from 0 to 255 (`"123"`), resulting in a [`Color::Indexed`] color;
+/// - a hexadecimal RGB value in the form `#rrggbb` or `#rgb` (`"#00ff88"`), resulting in a [`Color::RGB`] color;
+/// - a [named color](named) (`"dodgerblue"`), resulting in a [`Color::RGB`] color;
+/// - `"default"`, resulting in [`Color::Default`].
+///
+/// Names are case-insensitive.
+///
+/// ```
+/// use fluent_ansi::{prelude::*, color::named};
+///
+/// assert_eq!("red".parse::<Color>(), Ok(Color::RED.to_color()));
+/// assert_eq!("bright-cyan".parse::<Color>(), Ok(Color::CYAN.bright().to_color()));
+/// assert_eq!("#00ff88".parse::<Color>(), Ok(Color::rgb(0x00, 0xff, 0x88).to_color()));
+/// assert_eq!("123".parse::<Color>(), Ok(Color::indexed(123).to_color()));
+/// assert_eq!("orange".parse::<Color>(), Ok(named::ORANGE.to_color()));
+/// assert!("not-a-color".parse::<Color>().is_err());
+/// ```
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_color(s).ok_or(ParseColorError)
+    }
+}
+
+/// Parses a color as described in the [`FromStr`] implementation for [`Color`], in `const` contexts too.
+pub(crate) const fn parse_color(s: &str) -> Option<Color> {
+    if s.as_bytes().eq_ignore_ascii_case(b"default") {
+        return Some(Color::Default);
+    }
+    if let Some(simple) = parse_simple_color(s) {
+        return Some(Color::Simple(simple));
+    }
+    match s.as_bytes() {
+        [b'#', hex @ ..] => match parse_hex(hex) {
+            Some(rgb) => Some(Color::RGB(rgb)),
+            None => None,
+        },
+        digits @ [b'0'..=b'9', ..] => match parse_index(digits) {
+            Some(index) => Some(Color::Indexed(IndexedColor(index))),
+            None => None,
+        },
+        _ => match named::from_name(s) {
+            Some(rgb) => Some(Color::RGB(rgb)),
+            None => None,
+        },
+    }
+}
+
+/// The names of the basic colors, used both for parsing and for rendering them.
+pub(crate) const BASIC_COLOR_NAMES: [(BasicColor, &str); 8] = [
+    (BasicColor::Black, "black"),
+    (BasicColor::Red, "red"),
+    (BasicColor::Green, "green"),
+    (BasicColor::Yellow, "yellow"),
+    (BasicColor::Blue, "blue"),
+    (BasicColor::Magenta, "magenta"),
+    (BasicColor::Cyan, "cyan"),
+    (BasicColor::White, "white"),
+];
+
+pub(crate) const fn parse_basic_color(s: &str) -> Option<BasicColor> {
+    let mut i = 0;
+    while i < BASIC_COLOR_NAMES.len() {
+        let (basic_color, name) = BASIC_COLOR_NAMES[i];
+        if s.as_bytes().eq_ignore_ascii_case(name.as_bytes()) {
+            return Some(basic_color);
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn parse_simple_color(s: &str) -> Option<SimpleColor> {
+    let rest = match s.split_at_checked("bright".len()) {
+        Some((prefix, rest)) if prefix.as_bytes().eq_ignore_ascii_case(b"bright") => rest,
+        _ => {
+            return match parse_basic_color(s) {
+                Some(basic_color) => Some(SimpleColor::new(basic_color)),
+                None => None,
+            };
+        }
+    };
+    let rest = match rest.as_bytes() {
+        [b'-' | b'_' | b' ', ..] => rest.split_at(1).1,
+        _ => rest,
+    };
+    match parse_basic_color(rest) {
+        Some(basic_color) => Some(SimpleColor::new_bright(basic_color)),
+        None => None,
+    }
+}
+
+const fn parse_index(digits: &[u8]) -> Option<u8> {
+    let mut index: u8 = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        let digit = match digits[i] {
+            byte @ b'0'..=b'9' => byte - b'0',
+            _ => return None,
+        };
+        index = match index.checked_mul(10) {
+            Some(index) => match index.checked_add(digit) {
+                Some(index) => index,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(index)
+}
+
+pub(crate) const fn parse_hex(hex: &[u8]) -> Option<RGBColor> {
+    const fn digit(byte: u8) -> Option<u8> {
+        match byte {
+            b'0'..=b'9' => Some(byte - b'0'),
+            b'a'..=b'f' => Some(byte - b'a' + 10),
+            b'A'..=b'F' => Some(byte - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    const fn component(high: u8, low: u8) -> Option<u8> {
+        match (digit(high), digit(low)) {
+            (Some(high), Some(low)) => Some(high << 4 | low),
+            _ => None,
+        }
+    }
+
+    let components = match *hex {
+        [r1, r2, g1, g2, b1, b2] => (component(r1, r2), component(g1, g2), component(b1, b2)),
+        [r, g, b] => (component(r, r), component(g, g), component(b, b)),
+        _ => return None,
+    };
+    match components {
+        (Some(r), Some(g), Some(b)) => Some(RGBColor::new(r, g, b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::color::ColorKind as _;
+
+    use super::*;
+
+    #[test]
+    fn basic_names() {
+        assert_eq!("black".parse(), Ok(BasicColor::Black.to_color()));
+        assert_eq!("red".parse(), Ok(BasicColor::Red.to_color()));
+        assert_eq!("green".parse(), Ok(BasicColor::Green.to_color()));
+        assert_eq!("yellow".parse(), Ok(BasicColor::Yellow.to_color()));
+        assert_eq!("blue".parse(), Ok(BasicColor::Blue.to_color()));
+        assert_eq!("magenta".parse(), Ok(BasicColor::Magenta.to_color()));
+        assert_eq!("cyan".parse(), Ok(BasicColor::Cyan.to_color()));
+        assert_eq!("white".parse(), Ok(BasicColor::White.to_color()));
+        assert_eq!("RED".parse(), Ok(BasicColor::Red.to_color()));
+    }
+
+    #[test]
+    fn bright_names() {
+        let bright_cyan = BasicColor::Cyan.bright().to_color();
+        assert_eq!("bright-cyan".parse(), Ok(bright_cyan));
+        assert_eq!("bright_cyan".parse(), Ok(bright_cyan));
+        assert_eq!("brightcyan".parse(), Ok(bright_cyan));
+        assert_eq!("Bright Cyan".parse(), Ok(bright_cyan));
+        assert_eq!("bright-".parse::<Color>(), Err(ParseColorError));
+    }
+
+    #[test]
+    fn indexes() {
+        assert_eq!("0".parse(), Ok(IndexedColor(0).to_color()));
+        assert_eq!("123".parse(), Ok(IndexedColor(123).to_color()));
+        assert_eq!("255".parse(), Ok(IndexedColor(255).to_color()));
+        assert_eq!("256".parse::<Color>(), Err(ParseColorError));
+        assert_eq!("12a".parse::<Color>(), Err(ParseColorError));
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!("#00ff88".parse(), Ok(RGBColor::new(0, 255, 136).to_color()));
+        assert_eq!("#00FF88".parse(), Ok(RGBColor::new(0, 255, 136).to_color()));
+        assert_eq!("#0f8".parse(), Ok(RGBColor::new(0, 255, 136).to_color()));
+        assert_eq!("#00ff8".parse::<Color>(), Err(ParseColorError));
+        assert_eq!("#00ff8g".parse::<Color>(), Err(ParseColorError));
+        assert_eq!("#".parse::<Color>(), Err(ParseColorError));
+    }
+
+    #[test]
+    fn named_colors() {
+        assert_eq!("orange".parse(), Ok(named::ORANGE.to_color()));
+        assert_eq!("dodger-blue".parse(), Ok(named::DODGER_BLUE.to_color()));
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!("default".parse(), Ok(Color::Default));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!("".parse::<Color>(), Err(ParseColorError));
+        assert_eq!("not-a-color".parse::<Color>(), Err(ParseColorError));
+    }
+}
@@ -1,6 +1,4 @@
-use core::fmt::Result;
-
-use crate::{CodeWriter, ColorTarget, color::WriteColorCodes};
+use crate::{Code, ColorTarget, color::ColorCode};
 
 /// A type alias for [`RGBColor`].
 pub type RGB = RGBColor;
@@ -32,22 +30,69 @@ impl RGBColor {
     pub const fn new(r: u8, g: u8, b: u8) -> Self {
         Self { r, g, b }
     }
+
+    /// Interpolates linearly between two colors.
+    ///
+    /// `t` is the fraction of the way from `a` to `b`, and is clamped to the range from `0.0` to `1.0`:
+    ///
+    /// ```
+    /// use fluent_ansi::color::RGBColor;
+    ///
+    /// let black = RGBColor::new(0, 0, 0);
+    /// let orange = RGBColor::new(255, 128, 0);
+    /// assert_eq!(RGBColor::lerp(black, orange, 0.5), RGBColor::new(128, 64, 0));
+    /// assert_eq!(RGBColor::lerp(black, orange, 2.0), orange);
+    /// ```
+    #[must_use]
+    pub fn lerp(a: Self, b: Self, t: f32) -> Self {
+        a.mix(b, fixed_point_fraction(t))
+    }
+
+    /// The fixed-point representation of the fraction `1` in [`mix()`](RGBColor::mix).
+    pub(crate) const MIX_SCALE: u64 = 1 << 16;
+
+    /// Mixes this color with another one, with `t` being the fraction of the other color, from `0` to
+    /// [`MIX_SCALE`](RGBColor::MIX_SCALE).
+    pub(crate) fn mix(self, other: Self, t: u64) -> Self {
+        let component = |start: u8, end: u8| {
+            let (start, end) = (u64::from(start), u64::from(end));
+            let value = if end >= start {
+                start + ((end - start) * t + Self::MIX_SCALE / 2) / Self::MIX_SCALE
+            } else {
+                start - ((start - end) * t + Self::MIX_SCALE / 2) / Self::MIX_SCALE
+            };
+            u8::try_from(value).unwrap_or(u8::MAX)
+        };
+        Self::new(
+            component(self.r, other.r),
+            component(self.g, other.g),
+            component(self.b, other.b),
+        )
+    }
+}
+
+/// Converts a fraction to fixed point, clamping it to the range from `0` to [`RGBColor::MIX_SCALE`].
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "the value is clamped to the range of the fixed-point fraction"
+)]
+fn fixed_point_fraction(t: f32) -> u64 {
+    #[expect(clippy::cast_precision_loss, reason = "the scale is a power of 2")]
+    let scale = RGBColor::MIX_SCALE as f32;
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    (t * scale + 0.5) as u64
 }
 
-impl WriteColorCodes for RGBColor {
-    fn write_color_codes(self, target: ColorTarget, writer: &mut CodeWriter) -> Result {
+impl ColorCode for RGBColor {
+    fn color_code(self, target: ColorTarget) -> Code {
         let target_code = match target {
             ColorTarget::Foreground => 38,
             ColorTarget::Background => 48,
             ColorTarget::Underline => 58,
         };
 
-        writer.write_code(target_code)?;
-        writer.write_code(2)?;
-        writer.write_code(self.r)?;
-        writer.write_code(self.g)?;
-        writer.write_code(self.b)?;
-        Ok(())
+        Code::RGB(target_code, self)
     }
 }
 
@@ -86,6 +131,19 @@ mod tests {
         assert_eq!(color_1, color_2);
     }
 
+    #[test]
+    fn lerp() {
+        let a = RGBColor::new(0, 100, 200);
+        let b = RGBColor::new(200, 100, 0);
+        assert_eq!(RGBColor::lerp(a, b, 0.0), a);
+        assert_eq!(RGBColor::lerp(a, b, 0.25), RGBColor::new(50, 100, 150));
+        assert_eq!(RGBColor::lerp(a, b, 0.5), RGBColor::new(100, 100, 100));
+        assert_eq!(RGBColor::lerp(a, b, 1.0), b);
+        assert_eq!(RGBColor::lerp(a, b, -1.0), a);
+        assert_eq!(RGBColor::lerp(a, b, 1.5), b);
+        assert_eq!(RGBColor::lerp(a, b, f32::NAN), a);
+    }
+
     #[test]
     fn applied_to() {
         let stld = RGBColor::new(0, 128, 255).applied_to("CONTENT");
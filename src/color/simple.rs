@@ -1,8 +1,6 @@
-use core::fmt::Result;
-
 use crate::{
-    CodeWriter, ColorTarget,
-    color::{BasicColor, IndexedColor, WriteColorCodes},
+    Code, ColorTarget,
+    color::{BasicColor, ColorCode, IndexedColor},
 };
 
 /// A simple color type representing the 16 basic terminal colors (8 basic colors + bright variants).
@@ -62,21 +60,17 @@ impl SimpleColor {
     }
 }
 
-impl WriteColorCodes for SimpleColor {
-    fn write_color_codes(self, target: ColorTarget, writer: &mut CodeWriter) -> Result {
+impl ColorCode for SimpleColor {
+    fn color_code(self, target: ColorTarget) -> Code {
         let offset = self.basic_color.code_offset();
 
         match (target, self.bright) {
-            (ColorTarget::Foreground, false) => writer.write_code(30 + offset),
-            (ColorTarget::Background, false) => writer.write_code(40 + offset),
-            (ColorTarget::Foreground, true) => writer.write_code(90 + offset),
-            (ColorTarget::Background, true) => writer.write_code(100 + offset),
-            (ColorTarget::Underline, false) => {
-                IndexedColor(offset).write_color_codes(target, writer)
-            }
-            (ColorTarget::Underline, true) => {
-                IndexedColor(offset + 8).write_color_codes(target, writer)
-            }
+            (ColorTarget::Foreground, false) => Code::Single(30 + offset),
+            (ColorTarget::Background, false) => Code::Single(40 + offset),
+            (ColorTarget::Foreground, true) => Code::Single(90 + offset),
+            (ColorTarget::Background, true) => Code::Single(100 + offset),
+            (ColorTarget::Underline, false) => IndexedColor(offset).color_code(target),
+            (ColorTarget::Underline, true) => IndexedColor(offset + 8).color_code(target),
         }
     }
 }
@@ -0,0 +1,100 @@
+use crate::color::{Color, IndexedColor, Palette, RGBColor, SimpleColor};
+
+/// The RGB values used by xterm for the 16 simple colors, in index order.
+const SIMPLE_COLORS: [RGBColor; 16] = [
+    RGBColor::new(0x00, 0x00, 0x00),
+    RGBColor::new(0xcd, 0x00, 0x00),
+    RGBColor::new(0x00, 0xcd, 0x00),
+    RGBColor::new(0xcd, 0xcd, 0x00),
+    RGBColor::new(0x00, 0x00, 0xee),
+    RGBColor::new(0xcd, 0x00, 0xcd),
+    RGBColor::new(0x00, 0xcd, 0xcd),
+    RGBColor::new(0xe5, 0xe5, 0xe5),
+    RGBColor::new(0x7f, 0x7f, 0x7f),
+    RGBColor::new(0xff, 0x00, 0x00),
+    RGBColor::new(0x00, 0xff, 0x00),
+    RGBColor::new(0xff, 0xff, 0x00),
+    RGBColor::new(0x5c, 0x5c, 0xff),
+    RGBColor::new(0xff, 0x00, 0xff),
+    RGBColor::new(0x00, 0xff, 0xff),
+    RGBColor::new(0xff, 0xff, 0xff),
+];
+
+const CUBE_LEVELS: [u8; 6] = [0x00, 0x5f, 0x87, 0xaf, 0xd7, 0xff];
+
+impl SimpleColor {
+    pub(crate) const fn index(self) -> u8 {
+        self.get_basic_color() as u8 + if self.is_bright() { 8 } else { 0 }
+    }
+}
+
+impl IndexedColor {
+    /// Returns the RGB value of this color in xterm's default palette.
+    pub(crate) const fn to_xterm_rgb(self) -> RGBColor {
+        match self.0 {
+            index @ 0..16 => SIMPLE_COLORS[index as usize],
+            index @ 16..232 => {
+                let index = index - 16;
+                RGBColor::new(
+                    CUBE_LEVELS[(index / 36) as usize],
+                    CUBE_LEVELS[(index / 6 % 6) as usize],
+                    CUBE_LEVELS[(index % 6) as usize],
+                )
+            }
+            index => {
+                let level = 8 + (index - 232) * 10;
+                RGBColor::new(level, level, level)
+            }
+        }
+    }
+}
+
+impl RGBColor {
+    /// Returns the simple color that is the nearest to this color in xterm's default palette.
+    #[cfg(not(feature = "truecolor"))]
+    pub(crate) fn to_xterm_simple(self) -> SimpleColor {
+        super::palette::nearest_simple(&SIMPLE_COLORS, self)
+    }
+}
+
+impl Color {
+    /// Returns the RGB value of this color in xterm's default palette, or `None` for [`Color::Default`].
+    pub(crate) fn to_xterm_rgb(self) -> Option<RGBColor> {
+        Palette::XTERM.resolve(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::color::BasicColor;
+
+    use super::*;
+
+    #[test]
+    fn to_xterm_rgb() {
+        assert_eq!(
+            Color::from(BasicColor::Red).to_xterm_rgb(),
+            Some(RGBColor::new(0xcd, 0, 0))
+        );
+        assert_eq!(
+            Color::from(BasicColor::Blue.bright()).to_xterm_rgb(),
+            Some(RGBColor::new(0x5c, 0x5c, 0xff))
+        );
+        assert_eq!(IndexedColor(9).to_xterm_rgb(), RGBColor::new(0xff, 0, 0));
+        assert_eq!(IndexedColor(16).to_xterm_rgb(), RGBColor::new(0, 0, 0));
+        assert_eq!(
+            IndexedColor(33).to_xterm_rgb(),
+            RGBColor::new(0x00, 0x87, 0xff)
+        );
+        assert_eq!(
+            IndexedColor(231).to_xterm_rgb(),
+            RGBColor::new(0xff, 0xff, 0xff)
+        );
+        assert_eq!(IndexedColor(232).to_xterm_rgb(), RGBColor::new(8, 8, 8));
+        assert_eq!(
+            IndexedColor(255).to_xterm_rgb(),
+            RGBColor::new(0xee, 0xee, 0xee)
+        );
+        assert_eq!(Color::Default.to_xterm_rgb(), None);
+    }
+}
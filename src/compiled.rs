@@ -0,0 +1,125 @@
+use core::fmt::{Display, Formatter, Result};
+
+use crate::{BufferOverflowError, Style};
+
+/// A style whose escape sequence is rendered once, when it is created, and then written as is.
+///
+/// Rendering a [`Style`] formats its SGR parameters every time. When the same style is written over and over, like
+/// in log line prefixes, a `CompiledStyle` avoids that cost:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, Reset, Style};
+///
+/// let error = Style::new().bold().fg(Color::RED).compile().unwrap();
+/// assert_eq!(error.as_str(), "\x1b[1;31m");
+/// assert_eq!(format!("{error}ERROR{Reset} disk full"), "\x1b[1;31mERROR\x1b[0m disk full");
+/// ```
+///
+/// The escape sequence is kept in a fixed buffer of [`Style::MAX_RENDERED_LEN`] bytes, so no allocation is involved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompiledStyle {
+    style: Style,
+    bytes: [u8; Style::MAX_RENDERED_LEN],
+    len: usize,
+}
+
+impl CompiledStyle {
+    /// Renders the escape sequence of a style.
+    ///
+    /// See [`Style::compile()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferOverflowError`] if the style has a custom code that makes it exceed
+    /// [`Style::MAX_RENDERED_LEN`].
+    pub fn new(style: Style) -> core::result::Result<Self, BufferOverflowError> {
+        let mut bytes = [0; Style::MAX_RENDERED_LEN];
+        let len = style.write_bytes(&mut bytes)?;
+        Ok(CompiledStyle { style, bytes, len })
+    }
+
+    /// Gets the style whose escape sequence was rendered.
+    #[must_use]
+    pub const fn get_style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns the escape sequence as bytes.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Returns the escape sequence as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+}
+
+impl Style {
+    /// Renders the escape sequence of this style once, into a [`CompiledStyle`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferOverflowError`] if the style has a custom code that makes it exceed
+    /// [`Style::MAX_RENDERED_LEN`].
+    pub fn compile(self) -> core::result::Result<CompiledStyle, BufferOverflowError> {
+        CompiledStyle::new(self)
+    }
+}
+
+impl Display for CompiledStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for CompiledStyle {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<CompiledStyle> for Style {
+    fn from(compiled: CompiledStyle) -> Self {
+        compiled.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CustomCode, ToStyleSet as _,
+        color::{Color, RGBColor},
+    };
+
+    use super::*;
+
+    #[test]
+    fn compiled() {
+        for style in [
+            Style::new(),
+            Style::new().bold(),
+            Style::new()
+                .italic()
+                .fg(Color::RED)
+                .bg(RGBColor::new(1, 22, 133)),
+        ] {
+            let compiled = style.compile().unwrap();
+            assert_eq!(compiled.get_style(), style);
+            assert_eq!(compiled.as_str(), style.to_string());
+            assert_eq!(compiled.as_bytes(), style.to_string().as_bytes());
+            assert_eq!(compiled.to_string(), style.to_string());
+            assert_eq!(Style::from(compiled), style);
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let code = CustomCode::new(
+            "1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1",
+        );
+        assert_eq!(Style::new().add(code).compile(), Err(BufferOverflowError));
+    }
+}
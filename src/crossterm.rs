@@ -0,0 +1,256 @@
+//! Conversions from and to [`crossterm`] style types, enabled by the `crossterm` feature.
+//!
+//! [`Color::Default`] corresponds to [`Reset`](crossterm::style::Color::Reset), and every [`Effect`] except
+//! [`Superscript`](Effect::Superscript) and [`Subscript`](Effect::Subscript) has a corresponding [`Attribute`]. The
+//! conversions are lossy in some cases:
+//! - the superscript and subscript effects are dropped;
+//! - the other attributes with no corresponding effect are dropped.
+
+use crossterm::style::{Attribute, Attributes, ContentStyle};
+
+use crate::{
+    Effect, Style, StyleSet as _,
+    color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor},
+};
+
+const EFFECTS: [(Effect, Attribute); 17] = [
+    (Effect::Bold, Attribute::Bold),
+    (Effect::Faint, Attribute::Dim),
+    (Effect::Italic, Attribute::Italic),
+    (Effect::Underline, Attribute::Underlined),
+    (Effect::CurlyUnderline, Attribute::Undercurled),
+    (Effect::DottedUnderline, Attribute::Underdotted),
+    (Effect::DashedUnderline, Attribute::Underdashed),
+    (Effect::Blink, Attribute::SlowBlink),
+    (Effect::RapidBlink, Attribute::RapidBlink),
+    (Effect::Reverse, Attribute::Reverse),
+    (Effect::Conceal, Attribute::Hidden),
+    (Effect::Strikethrough, Attribute::CrossedOut),
+    (Effect::Fraktur, Attribute::Fraktur),
+    (Effect::DoubleUnderline, Attribute::DoubleUnderlined),
+    (Effect::Framed, Attribute::Framed),
+    (Effect::Encircled, Attribute::Encircled),
+    (Effect::Overline, Attribute::OverLined),
+];
+
+/// Converts an effect into its corresponding attribute.
+///
+/// The error is the effect itself, when it has no corresponding attribute.
+impl TryFrom<Effect> for Attribute {
+    type Error = Effect;
+
+    fn try_from(effect: Effect) -> Result<Self, Self::Error> {
+        EFFECTS
+            .iter()
+            .find(|(e, _)| *e == effect)
+            .map(|&(_, attribute)| attribute)
+            .ok_or(effect)
+    }
+}
+
+impl From<Style> for ContentStyle {
+    fn from(style: Style) -> Self {
+        let attributes = style
+            .get_effects()
+            .filter_map(|effect| Attribute::try_from(effect).ok())
+            .fold(Attributes::none(), Attributes::with);
+
+        ContentStyle {
+            foreground_color: style.fg.map(Into::into),
+            background_color: style.bg.map(Into::into),
+            underline_color: style.underline_color.map(Into::into),
+            attributes,
+        }
+    }
+}
+
+impl From<ContentStyle> for Style {
+    fn from(style: ContentStyle) -> Self {
+        let mut result = EFFECTS
+            .iter()
+            .filter(|&&(_, attribute)| style.attributes.has(attribute))
+            .fold(Style::new(), |result, &(effect, _)| {
+                result.set_effect(effect, true)
+            });
+
+        result.fg = style.foreground_color.map(Into::into);
+        result.bg = style.background_color.map(Into::into);
+        result.underline_color = style.underline_color.map(Into::into);
+        result
+    }
+}
+
+impl From<Color> for crossterm::style::Color {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Simple(simple) => simple.into(),
+            Color::Indexed(indexed) => indexed.into(),
+            Color::RGB(rgb) => rgb.into(),
+            Color::Default => crossterm::style::Color::Reset,
+        }
+    }
+}
+
+impl From<crossterm::style::Color> for Color {
+    fn from(color: crossterm::style::Color) -> Self {
+        use crossterm::style::Color as C;
+
+        let (basic_color, bright) = match color {
+            C::Reset => return Color::Default,
+            C::AnsiValue(index) => return Color::Indexed(IndexedColor::new(index)),
+            C::Rgb { r, g, b } => return Color::RGB(RGBColor::new(r, g, b)),
+            C::Black => (BasicColor::Black, false),
+            C::DarkRed => (BasicColor::Red, false),
+            C::DarkGreen => (BasicColor::Green, false),
+            C::DarkYellow => (BasicColor::Yellow, false),
+            C::DarkBlue => (BasicColor::Blue, false),
+            C::DarkMagenta => (BasicColor::Magenta, false),
+            C::DarkCyan => (BasicColor::Cyan, false),
+            C::Grey => (BasicColor::White, false),
+            C::DarkGrey => (BasicColor::Black, true),
+            C::Red => (BasicColor::Red, true),
+            C::Green => (BasicColor::Green, true),
+            C::Yellow => (BasicColor::Yellow, true),
+            C::Blue => (BasicColor::Blue, true),
+            C::Magenta => (BasicColor::Magenta, true),
+            C::Cyan => (BasicColor::Cyan, true),
+            C::White => (BasicColor::White, true),
+        };
+        let simple = if bright {
+            SimpleColor::new_bright(basic_color)
+        } else {
+            SimpleColor::new(basic_color)
+        };
+        Color::Simple(simple)
+    }
+}
+
+impl From<BasicColor> for crossterm::style::Color {
+    fn from(color: BasicColor) -> Self {
+        color.to_simple_color().into()
+    }
+}
+
+impl From<SimpleColor> for crossterm::style::Color {
+    fn from(color: SimpleColor) -> Self {
+        use crossterm::style::Color as C;
+
+        match (color.get_basic_color(), color.is_bright()) {
+            (BasicColor::Black, false) => C::Black,
+            (BasicColor::Red, false) => C::DarkRed,
+            (BasicColor::Green, false) => C::DarkGreen,
+            (BasicColor::Yellow, false) => C::DarkYellow,
+            (BasicColor::Blue, false) => C::DarkBlue,
+            (BasicColor::Magenta, false) => C::DarkMagenta,
+            (BasicColor::Cyan, false) => C::DarkCyan,
+            (BasicColor::White, false) => C::Grey,
+            (BasicColor::Black, true) => C::DarkGrey,
+            (BasicColor::Red, true) => C::Red,
+            (BasicColor::Green, true) => C::Green,
+            (BasicColor::Yellow, true) => C::Yellow,
+            (BasicColor::Blue, true) => C::Blue,
+            (BasicColor::Magenta, true) => C::Magenta,
+            (BasicColor::Cyan, true) => C::Cyan,
+            (BasicColor::White, true) => C::White,
+        }
+    }
+}
+
+impl From<IndexedColor> for crossterm::style::Color {
+    fn from(color: IndexedColor) -> Self {
+        crossterm::style::Color::AnsiValue(color.get_index())
+    }
+}
+
+impl From<RGBColor> for crossterm::style::Color {
+    fn from(color: RGBColor) -> Self {
+        crossterm::style::Color::Rgb {
+            r: color.r,
+            g: color.g,
+            b: color.b,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToStyleSet as _;
+
+    use super::*;
+
+    #[test]
+    fn style() {
+        let style = Style::new()
+            .bold()
+            .dotted_underline()
+            .overline()
+            .fg(BasicColor::Red)
+            .bg(Color::Default)
+            .underline_color(IndexedColor::new(42));
+        let expected = ContentStyle {
+            foreground_color: Some(crossterm::style::Color::DarkRed),
+            background_color: Some(crossterm::style::Color::Reset),
+            underline_color: Some(crossterm::style::Color::AnsiValue(42)),
+            attributes: Attributes::from(
+                [
+                    Attribute::Bold,
+                    Attribute::Underdotted,
+                    Attribute::OverLined,
+                ]
+                .as_slice(),
+            ),
+        };
+
+        assert_eq!(ContentStyle::from(style), expected);
+        assert_eq!(Style::from(expected), style);
+    }
+
+    #[test]
+    fn all_effects() {
+        for effect in
+            Effect::iter().filter(|&e| !matches!(e, Effect::Superscript | Effect::Subscript))
+        {
+            let style = Style::new().effect(effect);
+            assert_eq!(Style::from(ContentStyle::from(style)), style);
+        }
+        assert_eq!(
+            Attribute::try_from(Effect::Superscript),
+            Err(Effect::Superscript)
+        );
+    }
+
+    #[test]
+    fn lossy() {
+        let style = ContentStyle {
+            attributes: Attributes::from([Attribute::Bold, Attribute::NoBold].as_slice()),
+            ..ContentStyle::new()
+        };
+        assert_eq!(Style::from(style), Style::new().bold());
+        assert_eq!(
+            ContentStyle::from(Style::new().superscript()),
+            ContentStyle::new()
+        );
+    }
+
+    #[test]
+    fn colors() {
+        for basic_color in [BasicColor::Black, BasicColor::Green, BasicColor::White] {
+            for simple_color in [basic_color.to_simple_color(), basic_color.bright()] {
+                let color = Color::Simple(simple_color);
+                assert_eq!(Color::from(crossterm::style::Color::from(color)), color);
+            }
+        }
+        assert_eq!(
+            crossterm::style::Color::from(BasicColor::White),
+            crossterm::style::Color::Grey
+        );
+        assert_eq!(
+            crossterm::style::Color::from(BasicColor::White.bright()),
+            crossterm::style::Color::White
+        );
+        assert_eq!(
+            Color::from(crossterm::style::Color::from(RGBColor::new(1, 2, 3))),
+            Color::rgb(1, 2, 3)
+        );
+    }
+}
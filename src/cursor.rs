@@ -0,0 +1,256 @@
+//! Cursor control sequences.
+//!
+//! The values in this module render sequences that move, save, restore, hide or show the cursor when formatted with
+//! [`Display`]:
+//!
+//! ```
+//! use fluent_ansi::cursor;
+//!
+//! let output = format!("{}{}Done", cursor::Up(2), cursor::Column(1));
+//! assert_eq!(output, "\x1b[2A\x1b[1GDone");
+//! ```
+//!
+//! Rows and columns are 1-based, like in the escape sequences themselves. Relative moves by zero cells render nothing,
+//! since terminals treat a zero count as one.
+//!
+//! [`Save`] and [`Restore`] allow writing somewhere else and coming back, like when updating a progress line:
+//!
+//! ```
+//! use fluent_ansi::{cursor, prelude::*};
+//!
+//! let output = format!(
+//!     "{}{}{}{}{}",
+//!     cursor::Save,
+//!     cursor::MoveTo::new(1, 70),
+//!     Color::GREEN.applied_to("42%"),
+//!     cursor::Restore,
+//!     cursor::Show,
+//! );
+//! assert_eq!(output, "\x1b7\x1b[1;70H\x1b[32m42%\x1b[0m\x1b8\x1b[?25h");
+//! ```
+
+use core::fmt::{Display, Formatter, Result};
+
+use crate::{ParseResponseError, response::parse_csi_response, sequence::fixed_sequence};
+
+macro_rules! relative_move {
+    ($(#[$attr:meta])* $name:ident, $final:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name(pub u16);
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                if self.0 == 0 {
+                    Ok(())
+                } else {
+                    write!(f, concat!("\x1b[{}", $final), self.0)
+                }
+            }
+        }
+    };
+}
+
+relative_move!(
+    /// Moves the cursor up by the given number of rows (CUU).
+    Up,
+    "A"
+);
+relative_move!(
+    /// Moves the cursor down by the given number of rows (CUD).
+    Down,
+    "B"
+);
+relative_move!(
+    /// Moves the cursor right by the given number of columns (CUF).
+    Right,
+    "C"
+);
+relative_move!(
+    /// Moves the cursor left by the given number of columns (CUB).
+    Left,
+    "D"
+);
+relative_move!(
+    /// Moves the cursor to the beginning of the line that is the given number of rows down (CNL).
+    NextLine,
+    "E"
+);
+relative_move!(
+    /// Moves the cursor to the beginning of the line that is the given number of rows up (CPL).
+    PreviousLine,
+    "F"
+);
+
+/// Moves the cursor to the given column of the current row (CHA).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Column(pub u16);
+
+impl Display for Column {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "\x1b[{}G", self.0)
+    }
+}
+
+/// Moves the cursor to the given row and column (CUP).
+///
+/// ```
+/// use fluent_ansi::cursor::MoveTo;
+///
+/// assert_eq!(format!("{}", MoveTo::new(5, 10)), "\x1b[5;10H");
+/// assert_eq!(format!("{}", MoveTo::HOME), "\x1b[H");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MoveTo {
+    /// The row, starting at 1.
+    pub row: u16,
+    /// The column, starting at 1.
+    pub column: u16,
+}
+
+impl MoveTo {
+    /// The top-left position.
+    pub const HOME: MoveTo = MoveTo::new(1, 1);
+
+    /// Creates a value that moves the cursor to the given row and column.
+    #[must_use]
+    pub const fn new(row: u16, column: u16) -> Self {
+        MoveTo { row, column }
+    }
+}
+
+impl Display for MoveTo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if *self == MoveTo::HOME {
+            f.write_str("\x1b[H")
+        } else {
+            write!(f, "\x1b[{};{}H", self.row, self.column)
+        }
+    }
+}
+
+fixed_sequence!(
+    /// Saves the cursor position, along with the current style (DECSC).
+    Save,
+    "\x1b7"
+);
+fixed_sequence!(
+    /// Restores the cursor position and style saved by [`Save`] (DECRC).
+    Restore,
+    "\x1b8"
+);
+fixed_sequence!(
+    /// Hides the cursor (DECTCEM reset).
+    Hide,
+    "\x1b[?25l"
+);
+fixed_sequence!(
+    /// Shows the cursor (DECTCEM set).
+    Show,
+    "\x1b[?25h"
+);
+
+fixed_sequence!(
+    /// Queries the cursor position (DSR 6).
+    ///
+    /// The terminal responds with a cursor position report, which can be parsed with [`CursorPositionReport::parse()`].
+    QueryCursorPosition,
+    "\x1b[6n"
+);
+
+/// The terminal's response to a [`QueryCursorPosition`] (CPR).
+///
+/// ```
+/// use fluent_ansi::cursor::CursorPositionReport;
+///
+/// // Read from the terminal after writing the query
+/// let response = "\x1b[12;40R";
+/// let report = CursorPositionReport::parse(response).unwrap();
+/// assert_eq!(report, CursorPositionReport { row: 12, column: 40 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CursorPositionReport {
+    /// The row, starting at 1.
+    pub row: u16,
+    /// The column, starting at 1.
+    pub column: u16,
+}
+
+impl CursorPositionReport {
+    /// Parses a response like `ESC [ 12 ; 40 R`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response is not a valid cursor position report.
+    pub fn parse(response: &str) -> core::result::Result<Self, ParseResponseError> {
+        let [row, column] = parse_csi_response(response, 'R')?;
+        Ok(CursorPositionReport { row, column })
+    }
+}
+
+impl From<CursorPositionReport> for MoveTo {
+    fn from(report: CursorPositionReport) -> Self {
+        MoveTo::new(report.row, report.column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_display;
+
+    use super::*;
+
+    #[test]
+    fn relative_moves() {
+        assert_display!(Up(1), "\x1b[1A");
+        assert_display!(Down(2), "\x1b[2B");
+        assert_display!(Right(3), "\x1b[3C");
+        assert_display!(Left(400), "\x1b[400D");
+        assert_display!(NextLine(1), "\x1b[1E");
+        assert_display!(PreviousLine(5), "\x1b[5F");
+        assert_display!(Up(0), "");
+        assert_display!(Left(0), "");
+    }
+
+    #[test]
+    fn column() {
+        assert_display!(Column(1), "\x1b[1G");
+        assert_display!(Column(80), "\x1b[80G");
+    }
+
+    #[test]
+    fn move_to() {
+        assert_display!(MoveTo::new(3, 7), "\x1b[3;7H");
+        assert_display!(MoveTo::new(1, 1), "\x1b[H");
+        assert_display!(MoveTo::HOME, "\x1b[H");
+    }
+
+    #[test]
+    fn save_and_restore() {
+        assert_display!(Save, "\x1b7");
+        assert_display!(Restore, "\x1b8");
+    }
+
+    #[test]
+    fn cursor_position() {
+        assert_display!(QueryCursorPosition, "\x1b[6n");
+        assert_eq!(
+            CursorPositionReport::parse("\x1b[1;1R"),
+            Ok(CursorPositionReport { row: 1, column: 1 })
+        );
+        assert_eq!(
+            CursorPositionReport::parse("\x1b[1;1"),
+            Err(ParseResponseError)
+        );
+        assert_eq!(
+            MoveTo::from(CursorPositionReport { row: 3, column: 4 }),
+            MoveTo::new(3, 4)
+        );
+    }
+
+    #[test]
+    fn visibility() {
+        assert_display!(Hide, "\x1b[?25l");
+        assert_display!(Show, "\x1b[?25h");
+    }
+}
@@ -0,0 +1,154 @@
+use core::fmt::{Display, Formatter, Result};
+
+use crate::{AppliedTo, Style, StyleAttribute, StyleElement, StyleSet as _, ToStyle, ToStyleSet};
+
+/// A style element holding raw SGR parameters, for terminal-specific extensions not modeled by this crate.
+///
+/// The parameters are emitted after all the other parameters of a style. A style holds at most one custom code;
+/// adding another one replaces it.
+///
+/// ```
+/// use fluent_ansi::{prelude::*, CustomCode};
+///
+/// const IDEOGRAM_UNDERLINE: CustomCode = CustomCode::new("60");
+///
+/// let style = Color::RED.bold().add(IDEOGRAM_UNDERLINE);
+/// assert_eq!(format!("{style}"), "\x1b[1;31;60m");
+/// ```
+///
+/// The custom code is only emitted in escape sequences; it is ignored by the conversions to other representations,
+/// like [`Style::to_spec()`] and [`Styled::to_html()`](crate::Styled::to_html). Serializing a style with a custom
+/// code, with the `serde` feature, fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomCode(&'static str);
+
+impl CustomCode {
+    /// Creates a custom code from the given SGR parameters, like `"60"` or `"4:6;58:5:1"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters are empty or contain characters other than ASCII digits, `:` and `;`.
+    #[must_use]
+    pub const fn new(params: &'static str) -> Self {
+        let bytes = params.as_bytes();
+        assert!(
+            !bytes.is_empty(),
+            "custom code parameters must not be empty"
+        );
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(
+                matches!(bytes[i], b'0'..=b'9' | b':' | b';'),
+                "custom code parameters must contain only digits, `:` and `;`"
+            );
+            i += 1;
+        }
+        CustomCode(params)
+    }
+
+    /// Gets the SGR parameters.
+    #[must_use]
+    pub const fn params(self) -> &'static str {
+        self.0
+    }
+}
+
+impl StyleElement for CustomCode {
+    fn add_to_style(self, style: Style) -> Style {
+        style.set(Custom, Some(self))
+    }
+}
+
+impl ToStyleSet for CustomCode {
+    type StyleSet = Style;
+
+    fn to_style_set(self) -> Self::StyleSet {
+        self.to_style()
+    }
+}
+
+impl ToStyle for CustomCode {
+    fn to_style(self) -> Style {
+        self.into()
+    }
+}
+
+impl AppliedTo for CustomCode {}
+
+impl Display for CustomCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.to_style().fmt(f)
+    }
+}
+
+/// The custom code attribute.
+///
+/// Usable in the [`StyleSet::set`](crate::StyleSet::set) and [`StyleSet::get`](crate::StyleSet::get) methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Custom;
+
+impl StyleAttribute for Custom {
+    type Value = Option<CustomCode>;
+
+    fn set_in_style(self, style: Style, value: Self::Value) -> Style {
+        Style {
+            custom_code: value,
+            ..style
+        }
+    }
+
+    fn get_from_style(self, style: &Style) -> Self::Value {
+        style.custom_code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_display, color::BasicColor, test_to_style_set_methods};
+
+    use super::*;
+
+    const CODE: CustomCode = CustomCode::new("73");
+
+    test_to_style_set_methods!(
+        CustomCode::new("73"),
+        Style::new().add(CustomCode::new("73"))
+    );
+
+    #[test]
+    fn display() {
+        assert_display!(CODE, "\x1b[73m");
+        assert_display!(
+            Style::new().add(CustomCode::new("4:6;58:5:1")).bold(),
+            "\x1b[1;4:6;58:5:1m"
+        );
+        assert_display!(
+            Style::new().add(CODE).fg(BasicColor::Red).underline(),
+            "\x1b[4;31;73m"
+        );
+    }
+
+    #[test]
+    fn attribute() {
+        let style = Style::new().add(CODE);
+        assert_eq!(style.get(Custom), Some(CODE));
+        assert_eq!(
+            style.add(CustomCode::new("74")).get(Custom),
+            Some(CustomCode::new("74"))
+        );
+        assert_eq!(style.unset(Custom), Style::new());
+        assert_eq!(CODE.params(), "73");
+    }
+
+    #[test]
+    #[should_panic(expected = "only digits")]
+    fn invalid() {
+        let _ = CustomCode::new("1m");
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be empty")]
+    fn empty() {
+        let _ = CustomCode::new("");
+    }
+}
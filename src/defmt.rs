@@ -0,0 +1,195 @@
+//! Implementations of [`Format`], enabled by the `defmt` feature.
+//!
+//! Values are formatted as readable descriptions, with the same words as in the specification strings (see
+//! [`Style::from_spec()`]):
+//! - [`Style`] as its effects and colors, followed by its custom code prefixed with `code:`, like `bold red on blue`
+//!   or `italic code:60`, or `plain` if it has none;
+//! - colors as their specification strings, like `red`, `123` or `#00ff88`;
+//! - [`Effect`] as its name, like `curly-underline`;
+//! - [`UnderlineStyle`] as `solid`, `curly`, `dotted`, `dashed` or `double`;
+//! - [`TargetedColor`] as its color, prefixed with `on` for the background or `under` for the underline;
+//! - [`ColorTarget`] as `foreground`, `background` or `underline`;
+//! - [`Styled<C>`] as its content followed by its style in parentheses, like `Error (bold red)`.
+
+use core::fmt::Display;
+
+use defmt::{Format, Formatter, write};
+
+use crate::{
+    ColorTarget, Effect, Reset, Style, StyleSet as _, Styled, TargetedColor, UnderlineStyle,
+    color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor},
+    style::{basic_color_name, effect_name},
+};
+
+impl Format for Style {
+    fn format(&self, f: Formatter<'_>) {
+        if self.is_plain() {
+            write!(f, "plain");
+            return;
+        }
+        let mut separator = "";
+        for word in words(*self) {
+            match word {
+                Word::Effect(effect) => write!(f, "{=str}{}", separator, effect),
+                Word::Color(prefix, color) => write!(f, "{=str}{=str}{}", separator, prefix, color),
+                Word::CustomCode(params) => write!(f, "{=str}code:{=str}", separator, params),
+            }
+            separator = " ";
+        }
+    }
+}
+
+/// A word of the description of a [`Style`].
+#[derive(Debug, PartialEq)]
+enum Word {
+    Effect(Effect),
+    Color(&'static str, Color),
+    CustomCode(&'static str),
+}
+
+fn words(style: Style) -> impl Iterator<Item = Word> {
+    let colors = [
+        (ColorTarget::Foreground, ""),
+        (ColorTarget::Background, "on "),
+        (ColorTarget::Underline, "under "),
+    ]
+    .into_iter()
+    .filter_map(move |(target, prefix)| Some(Word::Color(prefix, style.get_color(target)?)));
+
+    style.get_effects().map(Word::Effect).chain(colors).chain(
+        style
+            .custom_code
+            .map(|custom_code| Word::CustomCode(custom_code.params())),
+    )
+}
+
+impl Format for Color {
+    fn format(&self, f: Formatter<'_>) {
+        match *self {
+            Color::Simple(simple) => simple.format(f),
+            Color::Indexed(indexed) => indexed.format(f),
+            Color::RGB(rgb) => rgb.format(f),
+            Color::Default => write!(f, "default"),
+        }
+    }
+}
+
+impl Format for BasicColor {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=str}", basic_color_name(*self));
+    }
+}
+
+impl Format for SimpleColor {
+    fn format(&self, f: Formatter<'_>) {
+        let prefix = if self.is_bright() { "bright-" } else { "" };
+        write!(f, "{=str}{}", prefix, self.get_basic_color());
+    }
+}
+
+impl Format for IndexedColor {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=u8}", self.get_index());
+    }
+}
+
+impl Format for RGBColor {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "#{=u8:02x}{=u8:02x}{=u8:02x}", self.r, self.g, self.b);
+    }
+}
+
+impl Format for Effect {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=str}", effect_name(*self));
+    }
+}
+
+impl Format for UnderlineStyle {
+    fn format(&self, f: Formatter<'_>) {
+        let name = match self {
+            UnderlineStyle::Solid => "solid",
+            UnderlineStyle::Curly => "curly",
+            UnderlineStyle::Dotted => "dotted",
+            UnderlineStyle::Dashed => "dashed",
+            UnderlineStyle::Double => "double",
+        };
+        write!(f, "{=str}", name);
+    }
+}
+
+impl Format for TargetedColor {
+    fn format(&self, f: Formatter<'_>) {
+        let prefix = match self.get_target() {
+            ColorTarget::Foreground => "",
+            ColorTarget::Background => "on ",
+            ColorTarget::Underline => "under ",
+        };
+        write!(f, "{=str}{}", prefix, self.get_color());
+    }
+}
+
+impl Format for ColorTarget {
+    fn format(&self, f: Formatter<'_>) {
+        let name = match self {
+            ColorTarget::Foreground => "foreground",
+            ColorTarget::Background => "background",
+            ColorTarget::Underline => "underline",
+        };
+        write!(f, "{=str}", name);
+    }
+}
+
+impl Format for Reset {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "reset");
+    }
+}
+
+impl<C: Display + Format> Format for Styled<C> {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{} ({})", self.get_content(), self.get_style());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CustomCode, ToStyleSet as _};
+
+    use super::*;
+
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn implemented() {
+        assert_format::<Style>();
+        assert_format::<Color>();
+        assert_format::<BasicColor>();
+        assert_format::<SimpleColor>();
+        assert_format::<IndexedColor>();
+        assert_format::<RGBColor>();
+        assert_format::<Effect>();
+        assert_format::<UnderlineStyle>();
+        assert_format::<TargetedColor>();
+        assert_format::<ColorTarget>();
+        assert_format::<Reset>();
+        assert_format::<Styled<&str>>();
+    }
+
+    #[test]
+    fn style_words() {
+        let style = Style::new()
+            .bold()
+            .bg(BasicColor::Blue)
+            .fg(BasicColor::Red)
+            .add(CustomCode::new("60"));
+        assert!(words(style).eq([
+            Word::Effect(Effect::Bold),
+            Word::Color("", BasicColor::Red.into()),
+            Word::Color("on ", BasicColor::Blue.into()),
+            Word::CustomCode("60"),
+        ]));
+        assert!(words(Style::new().add(CustomCode::new("60;61"))).eq([Word::CustomCode("60;61")]));
+        assert_eq!(words(Style::new()).next(), None);
+    }
+}
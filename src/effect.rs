@@ -1,16 +1,15 @@
-use core::fmt::{Display, Formatter, Result};
+use core::{
+    fmt::{Display, Formatter, Result},
+    iter::FusedIterator,
+};
 
 use enum_iterator::Sequence;
 
-use crate::{
-    AppliedTo, CodeWriter, Style, StyleAttribute, StyleElement, StyleSet, ToStyle, ToStyleSet,
-};
+use crate::{AppliedTo, Code, Style, StyleAttribute, StyleElement, StyleSet, ToStyle, ToStyleSet};
 pub use underline::*;
 
 mod underline;
 
-pub(crate) type AllEffects = enum_iterator::All<Effect>;
-
 /// An enumeration of all supported text styling effects.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
 pub enum Effect {
@@ -28,46 +27,144 @@ pub enum Effect {
     DottedUnderline,
     /// Dashed underline styling.
     DashedUnderline,
-    /// Blink styling.
+    /// Slow blink styling.
+    ///
+    /// It is mutually exclusive with [`RapidBlink`](Effect::RapidBlink): setting one of them unsets the other.
     Blink,
+    /// Rapid blink styling.
+    ///
+    /// It is mutually exclusive with [`Blink`](Effect::Blink): setting one of them unsets the other.
+    RapidBlink,
     /// Reverse video styling.
     Reverse,
     /// Conceal (hidden) styling.
     Conceal,
     /// Strikethrough styling.
     Strikethrough,
+    /// Fraktur (blackletter) font styling.
+    Fraktur,
     /// Double underline styling.
     DoubleUnderline,
+    /// Framed styling.
+    Framed,
+    /// Encircled styling.
+    Encircled,
     /// Overline styling.
     Overline,
+    /// Superscript styling.
+    ///
+    /// It is mutually exclusive with [`Subscript`](Effect::Subscript): setting one of them unsets the other.
+    Superscript,
+    /// Subscript styling.
+    ///
+    /// It is mutually exclusive with [`Superscript`](Effect::Superscript): setting one of them unsets the other.
+    Subscript,
 }
 
 impl Effect {
+    /// Returns an iterator over all the effects, in the order they are declared.
+    ///
+    /// ```
+    /// use fluent_ansi::Effect;
+    ///
+    /// assert_eq!(Effect::iter().next(), Some(Effect::Bold));
+    /// assert_eq!(Effect::iter().count(), 19);
+    /// ```
+    #[must_use]
+    pub fn iter() -> EffectIter {
+        EffectIter(enum_iterator::all())
+    }
+
+    /// Returns the SGR parameter that sets this effect.
+    ///
+    /// Most effects are set by a single parameter, but the curly, dotted and dashed underlines are set by the `4`
+    /// parameter with a sub-parameter:
+    ///
+    /// ```
+    /// use fluent_ansi::{Code, Effect};
+    ///
+    /// assert_eq!(Effect::Bold.code(), Code::Single(1));
+    /// assert_eq!(Effect::CurlyUnderline.code(), Code::Sub(4, 3));
+    /// ```
+    ///
+    /// The reverse conversion is provided by the `TryFrom<Code>` and `TryFrom<u8>` implementations.
+    #[must_use]
+    pub const fn code(self) -> Code {
+        match self {
+            Effect::Bold => Code::Single(1),
+            Effect::Faint => Code::Single(2),
+            Effect::Italic => Code::Single(3),
+            Effect::Underline => Code::Single(4),
+            Effect::CurlyUnderline => Code::Sub(4, 3),
+            Effect::DottedUnderline => Code::Sub(4, 4),
+            Effect::DashedUnderline => Code::Sub(4, 5),
+            Effect::Blink => Code::Single(5),
+            Effect::RapidBlink => Code::Single(6),
+            Effect::Reverse => Code::Single(7),
+            Effect::Conceal => Code::Single(8),
+            Effect::Strikethrough => Code::Single(9),
+            Effect::Fraktur => Code::Single(20),
+            Effect::DoubleUnderline => Code::Single(21),
+            Effect::Framed => Code::Single(51),
+            Effect::Encircled => Code::Single(52),
+            Effect::Overline => Code::Single(53),
+            Effect::Superscript => Code::Single(73),
+            Effect::Subscript => Code::Single(74),
+        }
+    }
+
+    /// Returns the SGR parameter that unsets this effect.
+    ///
+    /// Some parameters unset several effects, like `22`, which unsets both [`Bold`](Effect::Bold) and
+    /// [`Faint`](Effect::Faint).
     #[must_use]
-    pub(crate) fn all() -> AllEffects {
-        enum_iterator::all()
-    }
-
-    pub(crate) fn write_codes(self, code_writer: &mut CodeWriter) -> Result {
-        let codes = match self {
-            Effect::Bold => "1",
-            Effect::Faint => "2",
-            Effect::Italic => "3",
-            Effect::Underline => "4",
-            Effect::CurlyUnderline => "4:3",
-            Effect::DottedUnderline => "4:4",
-            Effect::DashedUnderline => "4:5",
-            Effect::Blink => "5",
-            Effect::Reverse => "7",
-            Effect::Conceal => "8",
-            Effect::Strikethrough => "9",
-            Effect::DoubleUnderline => "21",
-            Effect::Overline => "53",
-        };
-        code_writer.write_code(codes)
+    pub const fn reset_code(self) -> u8 {
+        match self {
+            Effect::Bold | Effect::Faint => 22,
+            Effect::Italic | Effect::Fraktur => 23,
+            Effect::Underline
+            | Effect::CurlyUnderline
+            | Effect::DottedUnderline
+            | Effect::DashedUnderline
+            | Effect::DoubleUnderline => 24,
+            Effect::Blink | Effect::RapidBlink => 25,
+            Effect::Reverse => 27,
+            Effect::Conceal => 28,
+            Effect::Strikethrough => 29,
+            Effect::Framed | Effect::Encircled => 54,
+            Effect::Overline => 55,
+            Effect::Superscript | Effect::Subscript => 75,
+        }
+    }
+
+    /// Returns the effect that is unset when this effect is set, if any.
+    pub(crate) const fn exclusive_with(self) -> Option<Effect> {
+        match self {
+            Effect::Blink => Some(Effect::RapidBlink),
+            Effect::RapidBlink => Some(Effect::Blink),
+            Effect::Superscript => Some(Effect::Subscript),
+            Effect::Subscript => Some(Effect::Superscript),
+            _ => None,
+        }
+    }
+}
+
+/// An iterator over all the [`Effect`]s.
+///
+/// It is returned by [`Effect::iter()`].
+#[derive(Debug, Clone)]
+pub struct EffectIter(enum_iterator::All<Effect>);
+
+impl Iterator for EffectIter {
+    type Item = Effect;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
     }
 }
 
+impl FusedIterator for EffectIter {}
+
 impl StyleElement for Effect {
     fn add_to_style(self, style: Style) -> Style {
         style.set_effect(self, true)
@@ -106,12 +203,62 @@ impl ToStyle for Effect {
 
 impl AppliedTo for Effect {}
 
+impl PartialEq<Style> for Effect {
+    fn eq(&self, other: &Style) -> bool {
+        self.to_style() == *other
+    }
+}
+
 impl Display for Effect {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         self.to_style().fmt(f)
     }
 }
 
+/// Converts an SGR parameter into the effect it sets.
+///
+/// Besides the forms returned by [`Effect::code()`], the `4:1` and `4:2` forms of the solid and double underlines
+/// are accepted. The error is the code itself, when it sets no effect.
+///
+/// ```
+/// use fluent_ansi::{Code, Effect};
+///
+/// assert_eq!(Effect::try_from(Code::Sub(4, 4)), Ok(Effect::DottedUnderline));
+/// assert_eq!(Effect::try_from(Code::Sub(4, 2)), Ok(Effect::DoubleUnderline));
+/// assert_eq!(Effect::try_from(Code::Single(31)), Err(Code::Single(31)));
+/// ```
+impl TryFrom<Code> for Effect {
+    type Error = Code;
+
+    fn try_from(code: Code) -> core::result::Result<Self, Self::Error> {
+        match code {
+            Code::Sub(4, 1) => Ok(Effect::Underline),
+            Code::Sub(4, 2) => Ok(Effect::DoubleUnderline),
+            _ => Effect::iter()
+                .find(|effect| effect.code() == code)
+                .ok_or(code),
+        }
+    }
+}
+
+/// Converts a single SGR parameter into the effect it sets.
+///
+/// The error is the parameter itself, when it sets no effect. See also the `TryFrom<Code>` implementation.
+///
+/// ```
+/// use fluent_ansi::Effect;
+///
+/// assert_eq!(Effect::try_from(9), Ok(Effect::Strikethrough));
+/// assert_eq!(Effect::try_from(22), Err(22));
+/// ```
+impl TryFrom<u8> for Effect {
+    type Error = u8;
+
+    fn try_from(code: u8) -> core::result::Result<Self, Self::Error> {
+        Effect::try_from(Code::Single(code)).map_err(|_| code)
+    }
+}
+
 impl From<UnderlineStyle> for Effect {
     fn from(value: UnderlineStyle) -> Self {
         match value {
@@ -133,6 +280,15 @@ mod tests {
     test_to_style_set_methods!(bold; Effect::Bold, Style::new().bold());
     test_to_style_set_methods!(italic; Effect::Italic, Style::new().italic());
 
+    #[test]
+    fn eq_style() {
+        assert_eq!(Effect::Bold, Style::new().bold());
+        assert_ne!(Effect::Bold, Style::new());
+        assert_ne!(Effect::Bold, Style::new().bold().italic());
+        assert_eq!(Style::new().bold(), Effect::Bold);
+        assert_ne!(Style::new().italic(), Effect::Bold);
+    }
+
     #[test]
     fn applied_to() {
         let stld = Effect::Bold.applied_to("CONTENT");
@@ -146,20 +302,59 @@ mod tests {
         assert_eq!(Effect::Bold.to_style(), Style::new().bold());
     }
 
+    #[test]
+    fn codes() {
+        for effect in Effect::iter() {
+            assert_eq!(Effect::try_from(effect.code()), Ok(effect), "{effect:?}");
+            if let Code::Single(code) = effect.code() {
+                assert_eq!(Effect::try_from(code), Ok(effect), "{effect:?}");
+            }
+        }
+
+        assert_eq!(Effect::Subscript.code(), Code::Single(74));
+        assert_eq!(Effect::DashedUnderline.code(), Code::Sub(4, 5));
+        assert_eq!(Effect::try_from(Code::Sub(4, 1)), Ok(Effect::Underline));
+        assert_eq!(Effect::try_from(Code::Sub(4, 0)), Err(Code::Sub(4, 0)));
+        assert_eq!(Effect::try_from(Code::Sub(3, 3)), Err(Code::Sub(3, 3)));
+        assert_eq!(
+            Effect::try_from(Code::Indexed(38, 1)),
+            Err(Code::Indexed(38, 1))
+        );
+        assert_eq!(Effect::try_from(0), Err(0));
+        assert_eq!(Effect::try_from(10), Err(10));
+        assert_eq!(Effect::Faint.reset_code(), 22);
+        assert_eq!(Effect::Encircled.reset_code(), 54);
+    }
+
     #[test]
     fn display() {
         assert_display!(Effect::Bold, "\x1b[1m");
         assert_display!(Effect::Faint, "\x1b[2m");
         assert_display!(Effect::Italic, "\x1b[3m");
         assert_display!(Effect::Underline, "\x1b[4m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(Effect::CurlyUnderline, "\x1b[4:3m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(Effect::CurlyUnderline, "\x1b[4m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(Effect::DottedUnderline, "\x1b[4:4m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(Effect::DottedUnderline, "\x1b[4m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(Effect::DashedUnderline, "\x1b[4:5m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(Effect::DashedUnderline, "\x1b[4m");
         assert_display!(Effect::Blink, "\x1b[5m");
+        assert_display!(Effect::RapidBlink, "\x1b[6m");
         assert_display!(Effect::Reverse, "\x1b[7m");
         assert_display!(Effect::Conceal, "\x1b[8m");
         assert_display!(Effect::Strikethrough, "\x1b[9m");
+        assert_display!(Effect::Fraktur, "\x1b[20m");
         assert_display!(Effect::DoubleUnderline, "\x1b[21m");
+        assert_display!(Effect::Framed, "\x1b[51m");
+        assert_display!(Effect::Encircled, "\x1b[52m");
         assert_display!(Effect::Overline, "\x1b[53m");
+        assert_display!(Effect::Superscript, "\x1b[73m");
+        assert_display!(Effect::Subscript, "\x1b[74m");
     }
 }
@@ -1,4 +1,7 @@
-use core::fmt::{Display, Formatter, Result};
+use core::{
+    fmt::{Display, Formatter, Result},
+    iter::FusedIterator,
+};
 
 use enum_iterator::Sequence;
 
@@ -6,8 +9,6 @@ use crate::{
     AppliedTo, Effect, Style, StyleAttribute, StyleElement, StyleSet, ToStyle, ToStyleSet,
 };
 
-pub(crate) type AllUnderlineStyles = enum_iterator::All<UnderlineStyle>;
-
 /// An enumeration of all supported underline styles.
 ///
 /// The values correspond to a subset of [`Effect`].
@@ -27,9 +28,10 @@ pub enum UnderlineStyle {
 }
 
 impl UnderlineStyle {
+    /// Returns an iterator over all the underline styles, in the order they are declared.
     #[must_use]
-    pub(crate) fn all() -> AllUnderlineStyles {
-        enum_iterator::all()
+    pub fn iter() -> UnderlineStyleIter {
+        UnderlineStyleIter(enum_iterator::all())
     }
 
     #[must_use]
@@ -38,8 +40,30 @@ impl UnderlineStyle {
     }
 }
 
+/// An iterator over all the [`UnderlineStyle`]s.
+///
+/// It is returned by [`UnderlineStyle::iter()`].
+#[derive(Debug, Clone)]
+pub struct UnderlineStyleIter(enum_iterator::All<UnderlineStyle>);
+
+impl Iterator for UnderlineStyleIter {
+    type Item = UnderlineStyle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl FusedIterator for UnderlineStyleIter {}
+
 impl AppliedTo for UnderlineStyle {}
 
+impl PartialEq<Style> for UnderlineStyle {
+    fn eq(&self, other: &Style) -> bool {
+        self.to_style() == *other
+    }
+}
+
 impl Display for UnderlineStyle {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         self.to_effect().fmt(f)
@@ -96,7 +120,7 @@ impl StyleAttribute for Underline {
     }
 
     fn get_from_style(self, style: &Style) -> Self::Value {
-        UnderlineStyle::all()
+        UnderlineStyle::iter()
             .find(|&underline_style| style.encoded_effects.get(underline_style.to_effect()))
     }
 }
@@ -110,6 +134,30 @@ mod tests {
     test_to_style_set_methods!(solid; UnderlineStyle::Solid, Style::new().underline());
     test_to_style_set_methods!(curly; UnderlineStyle::Curly, Style::new().curly_underline());
 
+    #[test]
+    fn iter() {
+        assert!(UnderlineStyle::iter().eq([
+            UnderlineStyle::Solid,
+            UnderlineStyle::Curly,
+            UnderlineStyle::Dotted,
+            UnderlineStyle::Dashed,
+            UnderlineStyle::Double,
+        ]));
+        assert!(
+            UnderlineStyle::iter()
+                .map(UnderlineStyle::to_effect)
+                .all(|effect| Effect::iter().any(|e| e == effect))
+        );
+    }
+
+    #[test]
+    fn eq_style() {
+        assert_eq!(UnderlineStyle::Curly, Style::new().curly_underline());
+        assert_ne!(UnderlineStyle::Curly, Style::new().underline());
+        assert_eq!(Style::new().curly_underline(), UnderlineStyle::Curly);
+        assert_ne!(Style::new().curly_underline().bold(), UnderlineStyle::Curly);
+    }
+
     #[test]
     fn applied_to() {
         let stld = UnderlineStyle::Curly.applied_to("CONTENT");
@@ -151,9 +199,18 @@ mod tests {
     #[test]
     fn display() {
         assert_display!(UnderlineStyle::Solid, "\x1b[4m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(UnderlineStyle::Curly, "\x1b[4:3m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(UnderlineStyle::Curly, "\x1b[4m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(UnderlineStyle::Dotted, "\x1b[4:4m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(UnderlineStyle::Dotted, "\x1b[4m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(UnderlineStyle::Dashed, "\x1b[4:5m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(UnderlineStyle::Dashed, "\x1b[4m");
         assert_display!(UnderlineStyle::Double, "\x1b[21m");
     }
 }
@@ -0,0 +1,222 @@
+use crate::{Effect, GetEffects, Style, style::EncodedEffects};
+
+/// A set of [`Effect`]s.
+///
+/// It supports the usual set operations without looping over the effects, and converts from and to [`Style`]:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, Effects, Style};
+///
+/// let emphasis = Effects::from_iter([Effect::Bold, Effect::Italic]);
+/// let style = Color::RED.bold().underline();
+///
+/// let common = emphasis.intersection(Effects::from(style));
+/// assert!(common.iter().eq([Effect::Bold]));
+///
+/// let style = Style::from(emphasis.union(Effects::from(Effect::Overline)));
+/// assert_eq!(style, Style::new().bold().italic().overline());
+/// ```
+///
+/// Unlike a [`Style`], the set doesn't enforce the effects that are mutually exclusive, like
+/// [`Blink`](Effect::Blink) and [`RapidBlink`](Effect::RapidBlink), or the underline effects. They can all be in the
+/// set at the same time, and only one of them is kept when the set is converted to a style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Effects(EncodedEffects);
+
+impl Effects {
+    /// Creates an empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(EncodedEffects::new())
+    }
+
+    /// Returns a copy of this set with the given effect added.
+    #[must_use]
+    pub const fn insert(self, effect: Effect) -> Self {
+        Self(self.0.set_bit(effect))
+    }
+
+    /// Returns a copy of this set with the given effect removed.
+    #[must_use]
+    pub const fn remove(self, effect: Effect) -> Self {
+        Self(self.0.clear_bit(effect))
+    }
+
+    /// Returns whether the set contains the given effect.
+    #[must_use]
+    pub const fn contains(self, effect: Effect) -> bool {
+        self.0.get(effect)
+    }
+
+    /// Returns the set of the effects that are in this set or in the other one.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0.union(other.0))
+    }
+
+    /// Returns the set of the effects that are both in this set and in the other one.
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0.intersection(other.0))
+    }
+
+    /// Returns the set of the effects that are in this set, but not in the other one.
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0.difference(other.0))
+    }
+
+    /// Returns whether the set is empty.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0.count() == 0
+    }
+
+    /// Returns the number of effects in the set.
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.0.count()
+    }
+
+    /// Returns an iterator over the effects in the set, in the order they are declared in [`Effect`].
+    #[must_use]
+    pub fn iter(self) -> GetEffects {
+        self.0.get_effects()
+    }
+}
+
+impl From<Effect> for Effects {
+    fn from(effect: Effect) -> Self {
+        Self::new().insert(effect)
+    }
+}
+
+/// Gets the effects of a style.
+impl From<Style> for Effects {
+    fn from(style: Style) -> Self {
+        Self(style.encoded_effects)
+    }
+}
+
+/// Creates a style with the effects in the set, and no colors.
+///
+/// The effects are set in the order they are declared in [`Effect`], so, among the effects in the set that are
+/// mutually exclusive, the one declared last wins:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, Effects, Style};
+///
+/// let effects = Effects::from_iter([Effect::CurlyUnderline, Effect::Underline, Effect::Blink, Effect::RapidBlink]);
+/// assert_eq!(Style::from(effects), Style::new().curly_underline().rapid_blink());
+/// ```
+impl From<Effects> for Style {
+    fn from(effects: Effects) -> Self {
+        let encoded_effects = effects
+            .iter()
+            .fold(EncodedEffects::new(), EncodedEffects::add);
+        Style {
+            encoded_effects,
+            ..Style::new()
+        }
+    }
+}
+
+impl FromIterator<Effect> for Effects {
+    fn from_iter<I: IntoIterator<Item = Effect>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), Self::insert)
+    }
+}
+
+impl Extend<Effect> for Effects {
+    fn extend<I: IntoIterator<Item = Effect>>(&mut self, iter: I) {
+        *self = iter.into_iter().fold(*self, Self::insert);
+    }
+}
+
+impl IntoIterator for Effects {
+    type Item = Effect;
+    type IntoIter = GetEffects;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{StyleSet as _, ToStyleSet as _, UnderlineStyle, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn insert_and_remove() {
+        let effects = Effects::new();
+        assert!(effects.is_empty());
+        assert_eq!(effects, Effects::default());
+
+        let effects = effects.insert(Effect::Blink).insert(Effect::RapidBlink);
+        assert!(effects.contains(Effect::Blink));
+        assert!(effects.contains(Effect::RapidBlink));
+        assert!(!effects.contains(Effect::Bold));
+        assert_eq!(effects.len(), 2);
+
+        let effects = effects.remove(Effect::Blink).remove(Effect::Bold);
+        assert!(effects.iter().eq([Effect::RapidBlink]));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = Effects::from_iter([Effect::Bold, Effect::Italic, Effect::Underline]);
+        let b = Effects::from_iter([Effect::Italic, Effect::CurlyUnderline]);
+
+        assert_eq!(
+            a.union(b),
+            Effects::from_iter([
+                Effect::Bold,
+                Effect::Italic,
+                Effect::Underline,
+                Effect::CurlyUnderline,
+            ])
+        );
+        assert!(a.intersection(b).iter().eq([Effect::Italic]));
+        assert!(
+            a.difference(b)
+                .into_iter()
+                .eq([Effect::Bold, Effect::Underline])
+        );
+        assert!(b.difference(b).is_empty());
+    }
+
+    #[test]
+    fn extend() {
+        let mut effects = Effects::from(Effect::Bold);
+        effects.extend([Effect::Faint, Effect::Bold]);
+        assert!(effects.iter().eq([Effect::Bold, Effect::Faint]));
+    }
+
+    #[test]
+    fn style_conversions() {
+        let style = Style::new().bold().curly_underline().fg(Color::RED);
+        let effects = Effects::from(style);
+        assert!(effects.iter().eq([Effect::Bold, Effect::CurlyUnderline]));
+        assert_eq!(Style::from(effects), style.effects_only());
+        assert_eq!(Style::from(Effects::new()), Style::new());
+
+        let style = Style::from(Effects::from_iter([Effect::Blink, Effect::RapidBlink]));
+        assert!(!style.get_effect(Effect::Blink));
+        assert!(style.get_effect(Effect::RapidBlink));
+
+        let style = Style::from(Effects::from_iter([
+            Effect::Underline,
+            Effect::CurlyUnderline,
+            Effect::Superscript,
+            Effect::Subscript,
+        ]));
+        assert_eq!(style, Style::new().curly_underline().subscript());
+        assert_eq!(style.get_underline_style(), Some(UnderlineStyle::Curly));
+        assert_eq!(
+            style.set_effect(Effect::CurlyUnderline, false),
+            Style::new().subscript()
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Erase sequences.
+//!
+//! The values in this module render CSI sequences that erase parts of the line or of the display when formatted with
+//! [`Display`]. The cursor is not moved. Combined with the [`cursor`](crate::cursor) sequences, they allow updating a
+//! status line in place:
+//!
+//! ```
+//! use fluent_ansi::{cursor, erase::EraseInLine, prelude::*};
+//!
+//! let output = format!("{}{}{}", cursor::Column(1), EraseInLine::All, Color::GREEN.applied_to("Done"));
+//! assert_eq!(output, "\x1b[1G\x1b[2K\x1b[32mDone\x1b[0m");
+//! ```
+
+use core::fmt::{Display, Formatter, Result};
+
+/// Erases part of the current line (EL).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EraseInLine {
+    /// Erases from the cursor to the end of the line.
+    ToEnd,
+    /// Erases from the start of the line to the cursor.
+    ToStart,
+    /// Erases the whole line.
+    All,
+}
+
+impl Display for EraseInLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            EraseInLine::ToEnd => "\x1b[K",
+            EraseInLine::ToStart => "\x1b[1K",
+            EraseInLine::All => "\x1b[2K",
+        })
+    }
+}
+
+/// Erases part of the display (ED).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EraseInDisplay {
+    /// Erases from the cursor to the end of the display.
+    ToEnd,
+    /// Erases from the start of the display to the cursor.
+    ToStart,
+    /// Erases the whole display.
+    All,
+    /// Erases the scrollback buffer, keeping the visible display (`3J`, an xterm extension).
+    Scrollback,
+}
+
+impl Display for EraseInDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            EraseInDisplay::ToEnd => "\x1b[J",
+            EraseInDisplay::ToStart => "\x1b[1J",
+            EraseInDisplay::All => "\x1b[2J",
+            EraseInDisplay::Scrollback => "\x1b[3J",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_display;
+
+    use super::*;
+
+    #[test]
+    fn erase_in_line() {
+        assert_display!(EraseInLine::ToEnd, "\x1b[K");
+        assert_display!(EraseInLine::ToStart, "\x1b[1K");
+        assert_display!(EraseInLine::All, "\x1b[2K");
+    }
+
+    #[test]
+    fn erase_in_display() {
+        assert_display!(EraseInDisplay::ToEnd, "\x1b[J");
+        assert_display!(EraseInDisplay::ToStart, "\x1b[1J");
+        assert_display!(EraseInDisplay::All, "\x1b[2J");
+        assert_display!(EraseInDisplay::Scrollback, "\x1b[3J");
+    }
+}
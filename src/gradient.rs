@@ -0,0 +1,618 @@
+use core::fmt::{Display, Formatter, Result, Write};
+
+use crate::{
+    ColorTarget, Style,
+    color::{ColorKind as _, RGBColor},
+    width::{EscapeState, visible_chars},
+};
+
+/// A value that renders its content with a foreground color that goes from a start color to an end color, one
+/// character at a time:
+///
+/// ```
+/// use fluent_ansi::{color::RGBColor, Gradient};
+///
+/// let gradient = Gradient::new("abc", RGBColor::new(255, 0, 0), RGBColor::new(0, 0, 255));
+/// # #[cfg(feature = "truecolor")]
+/// assert_eq!(
+///     format!("{gradient}"),
+///     "\x1b[38;2;255;0;0ma\x1b[38;2;127;0;128mb\x1b[38;2;0;0;255mc\x1b[0m"
+/// );
+/// ```
+///
+/// The first character gets the start color, and the last character gets the end color. Escape sequences and
+/// control characters rendered by the content are passed through and don't take a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gradient<C: Display> {
+    content: C,
+    start: RGBColor,
+    end: RGBColor,
+}
+
+impl<C: Display> Gradient<C> {
+    /// Creates a new `Gradient` value that renders the content from the start color to the end color.
+    #[must_use]
+    pub const fn new(content: C, start: RGBColor, end: RGBColor) -> Self {
+        Self {
+            content,
+            start,
+            end,
+        }
+    }
+
+    /// Gets the content.
+    #[must_use]
+    pub const fn get_content(&self) -> &C {
+        &self.content
+    }
+
+    /// Gets the start color.
+    #[must_use]
+    pub const fn get_start(&self) -> RGBColor {
+        self.start
+    }
+
+    /// Gets the end color.
+    #[must_use]
+    pub const fn get_end(&self) -> RGBColor {
+        self.end
+    }
+}
+
+impl<C: Display> Display for Gradient<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let count = visible_chars(&self.content);
+        write_per_char(f, &self.content, |index| {
+            self.start.mix(self.end, fraction(index, count))
+        })
+    }
+}
+
+/// A value that renders its content with the colors of the rainbow, one character at a time.
+///
+/// The foreground color goes once around the hue circle over the content, starting at red:
+///
+/// ```
+/// use fluent_ansi::Rainbow;
+///
+/// # #[cfg(feature = "truecolor")]
+/// assert_eq!(
+///     format!("{}", Rainbow::new("abc")),
+///     "\x1b[38;2;255;0;0ma\x1b[38;2;0;255;0mb\x1b[38;2;0;0;255mc\x1b[0m"
+/// );
+/// ```
+///
+/// Escape sequences and control characters rendered by the content are passed through and don't take a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rainbow<C: Display> {
+    content: C,
+}
+
+impl<C: Display> Rainbow<C> {
+    /// Creates a new `Rainbow` value that renders the content.
+    #[must_use]
+    pub const fn new(content: C) -> Self {
+        Self { content }
+    }
+
+    /// Gets the content.
+    #[must_use]
+    pub const fn get_content(&self) -> &C {
+        &self.content
+    }
+}
+
+impl<C: Display> Display for Rainbow<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let count = visible_chars(&self.content).max(1);
+        write_per_char(f, &self.content, |index| {
+            hue_color(index * HUE_STEPS / count)
+        })
+    }
+}
+
+/// The number of steps around the hue circle, going through the 255 values of each of the 6 sectors.
+const HUE_STEPS: usize = 6 * 255;
+
+/// Returns the fully saturated color of the given hue, in steps of [`HUE_STEPS`].
+fn hue_color(hue: usize) -> RGBColor {
+    let x = u8::try_from(hue % 255).unwrap_or(u8::MAX);
+    match hue / 255 % 6 {
+        0 => RGBColor::new(255, x, 0),
+        1 => RGBColor::new(255 - x, 255, 0),
+        2 => RGBColor::new(0, 255, x),
+        3 => RGBColor::new(0, 255 - x, 255),
+        4 => RGBColor::new(x, 0, 255),
+        _ => RGBColor::new(255, 0, 255 - x),
+    }
+}
+
+/// Builds a [`ColorGradient`] that goes through any number of color stops, with easing:
+///
+/// ```
+/// use fluent_ansi::{color::RGBColor, ColorTarget, Easing, GradientBuilder};
+///
+/// const STOPS: [RGBColor; 3] = [
+///     RGBColor::new(255, 0, 0),
+///     RGBColor::new(0, 255, 0),
+///     RGBColor::new(0, 0, 255),
+/// ];
+/// let gradient = GradientBuilder::new(&STOPS).easing(Easing::EaseIn).build();
+/// # #[cfg(feature = "truecolor")]
+/// assert_eq!(
+///     format!("{}", gradient.applied_to("abc")),
+///     "\x1b[38;2;255;0;0ma\x1b[38;2;127;128;0mb\x1b[38;2;0;0;255mc\x1b[0m"
+/// );
+///
+/// let banner = GradientBuilder::new(&STOPS).target(ColorTarget::Background).per_line().build();
+/// # #[cfg(feature = "truecolor")]
+/// assert_eq!(
+///     format!("{}", banner.applied_to("ab\ncd")),
+///     "\x1b[48;2;255;0;0mab\x1b[0m\n\x1b[48;2;0;0;255mcd\x1b[0m"
+/// );
+/// ```
+///
+/// The stops are evenly spaced. By default, the gradient is linear, applies to the foreground and goes across the
+/// characters of the content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GradientBuilder<'a> {
+    gradient: ColorGradient<'a>,
+}
+
+impl<'a> GradientBuilder<'a> {
+    /// Creates a new `GradientBuilder` value with the given color stops.
+    #[must_use]
+    pub const fn new(stops: &'a [RGBColor]) -> Self {
+        Self {
+            gradient: ColorGradient {
+                stops,
+                easing: Easing::Linear,
+                target: ColorTarget::Foreground,
+                per_line: false,
+            },
+        }
+    }
+
+    /// Sets the easing that controls how fast the colors change along the gradient.
+    #[must_use]
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.gradient.easing = easing;
+        self
+    }
+
+    /// Sets the target that the colors are applied to.
+    #[must_use]
+    pub const fn target(mut self, target: ColorTarget) -> Self {
+        self.gradient.target = target;
+        self
+    }
+
+    /// Makes the gradient go across the lines of the content, with a single color for each line.
+    #[must_use]
+    pub const fn per_line(mut self) -> Self {
+        self.gradient.per_line = true;
+        self
+    }
+
+    /// Builds the gradient.
+    #[must_use]
+    pub const fn build(self) -> ColorGradient<'a> {
+        self.gradient
+    }
+}
+
+/// A gradient that goes through a sequence of color stops.
+///
+/// It is built with a [`GradientBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColorGradient<'a> {
+    stops: &'a [RGBColor],
+    easing: Easing,
+    target: ColorTarget,
+    per_line: bool,
+}
+
+impl<'a> ColorGradient<'a> {
+    /// Gets the color stops.
+    #[must_use]
+    pub const fn get_stops(&self) -> &'a [RGBColor] {
+        self.stops
+    }
+
+    /// Gets the easing.
+    #[must_use]
+    pub const fn get_easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Gets the target that the colors are applied to.
+    #[must_use]
+    pub const fn get_target(&self) -> ColorTarget {
+        self.target
+    }
+
+    /// Returns whether the gradient goes across the lines of the content, instead of its characters.
+    #[must_use]
+    pub const fn is_per_line(&self) -> bool {
+        self.per_line
+    }
+
+    /// Returns the color at a position of the gradient, given the number of positions.
+    ///
+    /// The first position gets the first stop, and the last position gets the last stop. This allows the gradient to
+    /// be applied to things other than a single value, like lines that are written one at a time:
+    ///
+    /// ```
+    /// use fluent_ansi::{color::RGBColor, GradientBuilder};
+    ///
+    /// let stops = [RGBColor::new(0, 0, 0), RGBColor::new(200, 200, 200)];
+    /// let gradient = GradientBuilder::new(&stops).build();
+    /// assert_eq!(gradient.color_at(1, 5), Some(RGBColor::new(50, 50, 50)));
+    /// ```
+    ///
+    /// Returns `None` if there are no stops.
+    #[must_use]
+    pub fn color_at(&self, position: usize, count: usize) -> Option<RGBColor> {
+        let (&first, rest) = self.stops.split_first()?;
+        if rest.is_empty() {
+            return Some(first);
+        }
+        let segments = u64::try_from(rest.len()).unwrap_or(u64::MAX);
+        let scaled = self.easing.apply(fraction(position, count)) * segments;
+        let segment = (scaled / SCALE).min(segments - 1);
+        let index = usize::try_from(segment).unwrap_or(0);
+        Some(self.stops[index].mix(self.stops[index + 1], scaled - segment * SCALE))
+    }
+
+    /// Returns a value that renders the content with this gradient.
+    #[must_use]
+    pub const fn applied_to<C: Display>(self, content: C) -> GradientText<'a, C> {
+        GradientText {
+            gradient: self,
+            content,
+        }
+    }
+}
+
+/// A value that renders its content with a [`ColorGradient`].
+///
+/// It is returned by [`ColorGradient::applied_to()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GradientText<'a, C: Display> {
+    gradient: ColorGradient<'a>,
+    content: C,
+}
+
+impl<'a, C: Display> GradientText<'a, C> {
+    /// Gets the gradient.
+    #[must_use]
+    pub const fn get_gradient(&self) -> ColorGradient<'a> {
+        self.gradient
+    }
+
+    /// Gets the content.
+    #[must_use]
+    pub const fn get_content(&self) -> &C {
+        &self.content
+    }
+}
+
+impl<C: Display> Display for GradientText<'_, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let gradient = self.gradient;
+        if gradient.stops.is_empty() {
+            return write!(f, "{}", self.content);
+        }
+        let count = if gradient.per_line {
+            line_count(&self.content)
+        } else {
+            visible_chars(&self.content)
+        };
+        let mut writer = GradientWriter {
+            f,
+            state: EscapeState::Text,
+            per_line: gradient.per_line,
+            target: gradient.target,
+            index: 0,
+            colored: false,
+            color_at: |index| {
+                gradient
+                    .color_at(index, count)
+                    .unwrap_or(RGBColor::new(0, 0, 0))
+            },
+        };
+        writer.write_content(&self.content)
+    }
+}
+
+/// How fast the colors change along a gradient.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// The colors change at a constant pace.
+    #[default]
+    Linear,
+    /// The colors change slowly at the start, and faster towards the end.
+    EaseIn,
+    /// The colors change fast at the start, and slower towards the end.
+    EaseOut,
+    /// The colors change slowly at the start and at the end, and faster in the middle.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the easing to a fraction in fixed point, from `0` to [`SCALE`].
+    fn apply(self, t: u64) -> u64 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t / SCALE,
+            Easing::EaseOut => SCALE - (SCALE - t) * (SCALE - t) / SCALE,
+            Easing::EaseInOut => t * t * (3 * SCALE - 2 * t) / (SCALE * SCALE),
+        }
+    }
+}
+
+/// The fixed-point representation of the fraction `1`.
+const SCALE: u64 = RGBColor::MIX_SCALE;
+
+/// Returns the fraction, in fixed point, of a position among `count` positions, where the first one is at `0` and
+/// the last one is at `SCALE`.
+fn fraction(position: usize, count: usize) -> u64 {
+    let last = u64::try_from(count.saturating_sub(1).max(1)).unwrap_or(u64::MAX);
+    let position = u64::try_from(position).unwrap_or(u64::MAX).min(last);
+    position * SCALE / last
+}
+
+/// Returns the number of lines of the rendered value.
+fn line_count(value: &impl Display) -> usize {
+    struct LineCounter(usize);
+
+    impl Write for LineCounter {
+        fn write_str(&mut self, s: &str) -> Result {
+            self.0 += s.matches('\n').count();
+            Ok(())
+        }
+    }
+
+    let mut counter = LineCounter(1);
+    // Writing to the counter never fails, but the value's `Display` implementation may
+    let _ = write!(counter, "{value}");
+    counter.0
+}
+
+/// Writes the content, setting the foreground color returned by `color_at()` before each visible character, and
+/// resetting the styling at the end.
+fn write_per_char(
+    f: &mut Formatter<'_>,
+    content: &impl Display,
+    color_at: impl FnMut(usize) -> RGBColor,
+) -> Result {
+    let mut writer = GradientWriter {
+        f,
+        state: EscapeState::Text,
+        per_line: false,
+        target: ColorTarget::Foreground,
+        index: 0,
+        colored: false,
+        color_at,
+    };
+    writer.write_content(content)
+}
+
+/// A writer that sets the color returned by `color_at()` before each visible character, or before the first visible
+/// character of each line, passing everything through.
+struct GradientWriter<'a, 'b, F> {
+    f: &'a mut Formatter<'b>,
+    state: EscapeState,
+    per_line: bool,
+    target: ColorTarget,
+    /// The index of the current character or line.
+    index: usize,
+    /// Whether a color was set and not reset yet.
+    colored: bool,
+    color_at: F,
+}
+
+impl<F: FnMut(usize) -> RGBColor> GradientWriter<'_, '_, F> {
+    fn write_content(&mut self, content: &impl Display) -> Result {
+        write!(self, "{content}")?;
+        self.reset()
+    }
+
+    fn reset(&mut self) -> Result {
+        if self.colored {
+            self.colored = false;
+            write!(self.f, "{}", Style::new())?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: FnMut(usize) -> RGBColor> Write for GradientWriter<'_, '_, F> {
+    fn write_str(&mut self, s: &str) -> Result {
+        for c in s.chars() {
+            if self.state.advance(c) {
+                if !self.per_line || !self.colored {
+                    let color = (self.color_at)(self.index);
+                    write!(self.f, "{}", color.for_target(self.target))?;
+                    self.colored = true;
+                }
+                if !self.per_line {
+                    self.index += 1;
+                }
+            } else if c == '\n' && self.per_line {
+                self.reset()?;
+                self.index += 1;
+            }
+            self.f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn gradient() {
+        let start = RGBColor::new(0, 100, 200);
+        let end = RGBColor::new(200, 100, 0);
+        #[cfg(feature = "truecolor")]
+        assert_eq!(
+            Gradient::new("abcde", start, end).to_string(),
+            "\x1b[38;2;0;100;200ma\x1b[38;2;50;100;150mb\x1b[38;2;100;100;100mc\x1b[38;2;150;100;50md\x1b[38;2;200;100;0me\x1b[0m"
+        );
+        #[cfg(not(feature = "truecolor"))]
+        assert_eq!(
+            Gradient::new("abcde", start, end).to_string(),
+            "\x1b[36ma\x1b[90mb\x1b[90mc\x1b[90md\x1b[31me\x1b[0m"
+        );
+        #[cfg(feature = "truecolor")]
+        assert_eq!(
+            Gradient::new("a", start, end).to_string(),
+            "\x1b[38;2;0;100;200ma\x1b[0m"
+        );
+        #[cfg(not(feature = "truecolor"))]
+        assert_eq!(
+            Gradient::new("a", start, end).to_string(),
+            "\x1b[36ma\x1b[0m"
+        );
+        assert_eq!(Gradient::new("", start, end).to_string(), "");
+
+        let gradient = Gradient::new(42, start, end);
+        assert_eq!(gradient.get_content(), &42);
+        assert_eq!(gradient.get_start(), start);
+        assert_eq!(gradient.get_end(), end);
+    }
+
+    #[test]
+    fn rainbow() {
+        #[cfg(feature = "truecolor")]
+        assert_eq!(
+            Rainbow::new("abcdef").to_string(),
+            "\x1b[38;2;255;0;0ma\x1b[38;2;255;255;0mb\x1b[38;2;0;255;0mc\x1b[38;2;0;255;255md\x1b[38;2;0;0;255me\x1b[38;2;255;0;255mf\x1b[0m"
+        );
+        #[cfg(not(feature = "truecolor"))]
+        assert_eq!(
+            Rainbow::new("abcdef").to_string(),
+            "\x1b[91ma\x1b[93mb\x1b[92mc\x1b[96md\x1b[34me\x1b[95mf\x1b[0m"
+        );
+        assert_eq!(Rainbow::new("").to_string(), "");
+        assert_eq!(Rainbow::new(42).get_content(), &42);
+    }
+
+    #[test]
+    fn escape_sequences() {
+        let content = Color::RED.applied_to("a\nb");
+        #[cfg(feature = "truecolor")]
+        assert_eq!(
+            Rainbow::new(content).to_string(),
+            "\x1b[31m\x1b[38;2;255;0;0ma\n\x1b[38;2;0;255;255mb\x1b[0m\x1b[0m"
+        );
+        #[cfg(not(feature = "truecolor"))]
+        assert_eq!(
+            Rainbow::new(content).to_string(),
+            "\x1b[31m\x1b[91ma\n\x1b[96mb\x1b[0m\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn gradient_builder() {
+        let stops = [
+            RGBColor::new(0, 0, 0),
+            RGBColor::new(100, 100, 100),
+            RGBColor::new(100, 0, 200),
+        ];
+        let gradient = GradientBuilder::new(&stops).build();
+        assert_eq!(gradient.get_stops(), stops);
+        assert_eq!(gradient.get_easing(), Easing::Linear);
+        assert_eq!(gradient.get_target(), ColorTarget::Foreground);
+        assert!(!gradient.is_per_line());
+        assert_eq!(gradient.color_at(0, 5), Some(RGBColor::new(0, 0, 0)));
+        assert_eq!(gradient.color_at(1, 5), Some(RGBColor::new(50, 50, 50)));
+        assert_eq!(gradient.color_at(2, 5), Some(RGBColor::new(100, 100, 100)));
+        assert_eq!(gradient.color_at(3, 5), Some(RGBColor::new(100, 50, 150)));
+        assert_eq!(gradient.color_at(4, 5), Some(RGBColor::new(100, 0, 200)));
+        assert_eq!(gradient.color_at(9, 5), Some(RGBColor::new(100, 0, 200)));
+        assert_eq!(gradient.color_at(0, 1), Some(RGBColor::new(0, 0, 0)));
+
+        let gradient = GradientBuilder::new(&stops)
+            .easing(Easing::EaseOut)
+            .target(ColorTarget::Background)
+            .per_line()
+            .build();
+        assert_eq!(gradient.get_easing(), Easing::EaseOut);
+        assert_eq!(gradient.get_target(), ColorTarget::Background);
+        assert!(gradient.is_per_line());
+    }
+
+    #[test]
+    fn easing() {
+        let stops = [RGBColor::new(0, 0, 0), RGBColor::new(200, 200, 200)];
+        let color_at = |easing| {
+            GradientBuilder::new(&stops)
+                .easing(easing)
+                .build()
+                .color_at(1, 5)
+        };
+        assert_eq!(color_at(Easing::Linear), Some(RGBColor::new(50, 50, 50)));
+        assert_eq!(color_at(Easing::EaseIn), Some(RGBColor::new(13, 13, 13)));
+        assert_eq!(color_at(Easing::EaseOut), Some(RGBColor::new(88, 88, 88)));
+        assert_eq!(color_at(Easing::EaseInOut), Some(RGBColor::new(31, 31, 31)));
+    }
+
+    #[test]
+    fn stop_counts() {
+        assert_eq!(GradientBuilder::new(&[]).build().color_at(0, 1), None);
+        assert_eq!(
+            GradientBuilder::new(&[])
+                .build()
+                .applied_to("ab")
+                .to_string(),
+            "ab"
+        );
+
+        let stops = [RGBColor::new(1, 2, 3)];
+        let gradient = GradientBuilder::new(&stops).build();
+        assert_eq!(gradient.color_at(3, 5), Some(stops[0]));
+        #[cfg(feature = "truecolor")]
+        assert_eq!(
+            gradient.applied_to("ab").to_string(),
+            "\x1b[38;2;1;2;3ma\x1b[38;2;1;2;3mb\x1b[0m"
+        );
+        #[cfg(not(feature = "truecolor"))]
+        assert_eq!(
+            gradient.applied_to("ab").to_string(),
+            "\x1b[30ma\x1b[30mb\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn per_line() {
+        let stops = [RGBColor::new(0, 0, 0), RGBColor::new(200, 0, 0)];
+        let gradient = GradientBuilder::new(&stops).per_line().build();
+        #[cfg(feature = "truecolor")]
+        assert_eq!(
+            gradient.applied_to("ab\n\ncd\n").to_string(),
+            "\x1b[38;2;0;0;0mab\x1b[0m\n\n\x1b[38;2;133;0;0mcd\x1b[0m\n"
+        );
+        #[cfg(not(feature = "truecolor"))]
+        assert_eq!(
+            gradient.applied_to("ab\n\ncd\n").to_string(),
+            "\x1b[30mab\x1b[0m\n\n\x1b[31mcd\x1b[0m\n"
+        );
+        #[cfg(feature = "truecolor")]
+        assert_eq!(
+            gradient.applied_to("ab").to_string(),
+            "\x1b[38;2;0;0;0mab\x1b[0m"
+        );
+        #[cfg(not(feature = "truecolor"))]
+        assert_eq!(gradient.applied_to("ab").to_string(), "\x1b[30mab\x1b[0m");
+
+        let text = gradient.applied_to(42);
+        assert_eq!(text.get_gradient(), gradient);
+        assert_eq!(text.get_content(), &42);
+    }
+}
@@ -0,0 +1,100 @@
+use core::fmt::Display;
+
+use heapless::String;
+
+use crate::{BufferOverflowError, Style, Styled, render_to};
+
+impl Style {
+    /// Renders the escape sequence of this style into a `heapless::String` that fits any style without a custom code.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let style = Style::new().bold().fg(Color::RED);
+    /// assert_eq!(style.to_heapless_string().unwrap(), "\x1b[1;31m");
+    /// ```
+    ///
+    /// Requires the `heapless` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferOverflowError`] if the style has a custom code that makes it exceed
+    /// [`Style::MAX_RENDERED_LEN`].
+    pub fn to_heapless_string(
+        self,
+    ) -> Result<String<{ Style::MAX_RENDERED_LEN }>, BufferOverflowError> {
+        render_to(self).map_err(|_| BufferOverflowError)
+    }
+}
+
+impl<C: Display> Styled<C> {
+    /// Renders this value into a `heapless::String` with the given capacity.
+    ///
+    /// The escape sequences take up to [`Style::MAX_RENDERED_LEN`] bytes before the content, plus 4 bytes after it,
+    /// for styles without a custom code:
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let styled = Color::GREEN.applied_to(42);
+    /// let s = styled.to_heapless_string::<{ Style::MAX_RENDERED_LEN + 2 + 4 }>().unwrap();
+    /// assert_eq!(s, "\x1b[32m42\x1b[0m");
+    /// ```
+    ///
+    /// Requires the `heapless` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferOverflowError`] if the rendered value doesn't fit in the capacity.
+    pub fn to_heapless_string<const N: usize>(&self) -> Result<String<N>, BufferOverflowError> {
+        render_to(self).map_err(|_| BufferOverflowError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, CustomCode, ToStyleSet as _, color::RGBColor};
+
+    use super::*;
+
+    #[test]
+    fn style() {
+        let white = RGBColor::new(255, 255, 255);
+        let style = Style::new()
+            .bold()
+            .curly_underline()
+            .fg(white)
+            .bg(white)
+            .underline_color(white);
+        assert_eq!(
+            style.to_heapless_string().unwrap().as_str(),
+            style.to_string()
+        );
+
+        let style = Style::new().add(CustomCode::new(
+            "1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1;1",
+        ));
+        assert_eq!(style.to_heapless_string(), Err(BufferOverflowError));
+    }
+
+    #[test]
+    fn styled() {
+        let styled = RGBColor::new(1, 2, 3).applied_to("abc");
+        #[cfg(feature = "truecolor")]
+        {
+            assert_eq!(
+                styled.to_heapless_string::<20>().unwrap().as_str(),
+                "\x1b[38;2;1;2;3mabc\x1b[0m"
+            );
+            assert_eq!(styled.to_heapless_string::<19>(), Err(BufferOverflowError));
+        }
+        #[cfg(not(feature = "truecolor"))]
+        {
+            assert_eq!(
+                styled.to_heapless_string::<12>().unwrap().as_str(),
+                "\x1b[30mabc\x1b[0m"
+            );
+            assert_eq!(styled.to_heapless_string::<11>(), Err(BufferOverflowError));
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use core::{
+    fmt::{Display, Formatter, Result},
+    ops::Range,
+};
+
+use crate::{Style, Styled};
+
+/// Highlights the occurrences of a pattern in a text, applying a style to them and leaving the rest of the text
+/// untouched:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, highlight, ToStyle as _};
+///
+/// let line = "let x = x + 1;";
+/// assert_eq!(
+///     format!("{}", highlight(line, "x", Color::RED.bold().to_style())),
+///     "let \x1b[1;31mx\x1b[0m = \x1b[1;31mx\x1b[0m + 1;"
+/// );
+/// ```
+///
+/// The occurrences are found from left to right, and don't overlap. An empty pattern matches nothing.
+#[must_use]
+pub fn highlight<'a>(text: &'a str, pattern: &'a str, style: Style) -> Highlight<'a> {
+    Highlight {
+        text,
+        matches: Matches::Pattern(pattern),
+        style,
+    }
+}
+
+/// Highlights the given byte ranges of a text, applying a style to them and leaving the rest of the text untouched.
+///
+/// This is useful when the matches are found by other means, like a regular expression:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, highlight_ranges, ToStyle as _};
+///
+/// let line = "error: file not found";
+/// assert_eq!(
+///     format!("{}", highlight_ranges(line, &[0..5, 12..15], Effect::Bold.to_style())),
+///     "\x1b[1merror\x1b[0m: file \x1b[1mnot\x1b[0m found"
+/// );
+/// ```
+///
+/// The ranges are expected to be sorted. The parts of a range that overlap a previous one, or that lie beyond the
+/// end of the text, are ignored, as are the ranges whose bounds are not at character boundaries.
+#[must_use]
+pub fn highlight_ranges<'a>(
+    text: &'a str,
+    ranges: &'a [Range<usize>],
+    style: Style,
+) -> Highlight<'a> {
+    Highlight {
+        text,
+        matches: Matches::Ranges(ranges),
+        style,
+    }
+}
+
+/// A text with highlighted parts.
+///
+/// It is returned by [`highlight()`] and [`highlight_ranges()`], and renders the highlighted text when formatted
+/// with [`Display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Highlight<'a> {
+    text: &'a str,
+    matches: Matches<'a>,
+    style: Style,
+}
+
+impl Highlight<'_> {
+    /// Gets the style applied to the highlighted parts.
+    #[must_use]
+    pub const fn get_style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns a new value with the given style applied to the highlighted parts.
+    #[must_use]
+    pub const fn with_style(self, style: Style) -> Self {
+        Self { style, ..self }
+    }
+
+    fn write_parts(
+        &self,
+        f: &mut Formatter<'_>,
+        ranges: impl Iterator<Item = Range<usize>>,
+    ) -> Result {
+        let mut position = 0;
+        for range in ranges {
+            let start = range.start.max(position);
+            let end = range.end.min(self.text.len());
+            if start >= end {
+                continue;
+            }
+            let (Some(before), Some(part)) =
+                (self.text.get(position..start), self.text.get(start..end))
+            else {
+                continue;
+            };
+            write!(f, "{before}{}", Styled::new(part).with_style(self.style))?;
+            position = end;
+        }
+        f.write_str(&self.text[position..])
+    }
+}
+
+impl Display for Highlight<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.matches {
+            Matches::Pattern("") => f.write_str(self.text),
+            Matches::Pattern(pattern) => self.write_parts(
+                f,
+                self.text
+                    .match_indices(pattern)
+                    .map(|(index, matched)| index..index + matched.len()),
+            ),
+            Matches::Ranges(ranges) => self.write_parts(f, ranges.iter().cloned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Matches<'a> {
+    Pattern(&'a str),
+    Ranges(&'a [Range<usize>]),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Effect, ToStyle as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn pattern() {
+        let style = Effect::Bold.to_style();
+        assert_eq!(
+            highlight("abcabc", "bc", style).to_string(),
+            "a\x1b[1mbc\x1b[0ma\x1b[1mbc\x1b[0m"
+        );
+        assert_eq!(
+            highlight("aaa", "aa", style).to_string(),
+            "\x1b[1maa\x1b[0ma"
+        );
+        assert_eq!(highlight("abc", "x", style).to_string(), "abc");
+        assert_eq!(highlight("abc", "", style).to_string(), "abc");
+        assert_eq!(highlight("", "a", style).to_string(), "");
+        assert_eq!(highlight("abc", "b", Style::new()).to_string(), "abc");
+    }
+
+    #[test]
+    fn ranges() {
+        let style = Effect::Bold.to_style();
+        let text = "abcdef";
+        assert_eq!(
+            highlight_ranges(text, &[0..1, 3..5], style).to_string(),
+            "\x1b[1ma\x1b[0mbc\x1b[1mde\x1b[0mf"
+        );
+        assert_eq!(
+            highlight_ranges(text, &[1..3, 2..4], style).to_string(),
+            "a\x1b[1mbc\x1b[0m\x1b[1md\x1b[0mef"
+        );
+        assert_eq!(
+            highlight_ranges(text, &[4..10, 20..30], style).to_string(),
+            "abcd\x1b[1mef\x1b[0m"
+        );
+        assert_eq!(highlight_ranges(text, &[], style).to_string(), "abcdef");
+        assert_eq!(
+            highlight_ranges(text, &[2..2, 4..4], style).to_string(),
+            "abcdef"
+        );
+        assert_eq!(
+            highlight_ranges("aé", &[1..2, 3..3], style).to_string(),
+            "aé"
+        );
+    }
+
+    #[test]
+    fn style() {
+        let value = highlight("ab", "b", Style::new()).with_style(Color::RED.to_style());
+        assert_eq!(value.get_style(), Color::RED.to_style());
+        assert_eq!(value.to_string(), "a\x1b[31mb\x1b[0m");
+    }
+}
@@ -0,0 +1,399 @@
+use core::fmt::{Display, Formatter, Result, Write};
+
+use crate::{
+    Effect, Style, StyleSet as _, Styled, UnderlineStyle,
+    color::{Color, RGBColor},
+    parse::{Event, Parser},
+};
+
+/// A value that renders HTML.
+///
+/// It is returned by [`Styled::to_html()`] and [`ansi_to_html()`], and renders the HTML when formatted with
+/// [`Display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Html<T>(T);
+
+impl<C: Display> Styled<C> {
+    /// Returns a value that renders the content as HTML, enclosed in a `<span>` element whose `style` attribute
+    /// contains the CSS equivalent to the style.
+    ///
+    /// The content is HTML-escaped. Colors are converted to RGB values using xterm's default palette. If the style
+    /// is empty, only the content is rendered.
+    ///
+    /// ```
+    /// use fluent_ansi::prelude::*;
+    ///
+    /// let styled = Color::RED.bold().underline().applied_to("<Error>");
+    /// assert_eq!(
+    ///     styled.to_html().to_string(),
+    ///     r#"<span style="color:#cd0000;font-weight:bold;text-decoration-line:underline">&lt;Error&gt;</span>"#
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn to_html(&self) -> Html<&Styled<C>> {
+        Html(self)
+    }
+}
+
+impl<C: Display> Display for Html<&Styled<C>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let style = self.0.get_style();
+        if style != Style::new() {
+            write_span_start(f, style)?;
+        }
+        write!(HtmlEscaper(f), "{}", self.0.get_content())?;
+        if style != Style::new() {
+            f.write_str("</span>")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts text containing ANSI escape sequences to HTML.
+///
+/// Each run of text is HTML-escaped and enclosed in a `<span>` element with the CSS equivalent to the style in
+/// effect, as in [`Styled::to_html()`]. Runs of text with no style are not enclosed.
+///
+/// The text is parsed by a [lossy](Parser::lossy) [`Parser`], so the escape sequences other than SGR ones, like the
+/// ones that erase the line or the hyperlinks usually found in captured output, are skipped.
+///
+/// ```
+/// use fluent_ansi::ansi_to_html;
+///
+/// let html = ansi_to_html("\x1b[1mbold\x1b[0m, plain & \x1b[31mred\x1b[K");
+/// assert_eq!(
+///     html.to_string(),
+///     r#"<span style="font-weight:bold">bold</span>, plain &amp; <span style="color:#cd0000">red</span>"#
+/// );
+/// ```
+#[must_use]
+pub const fn ansi_to_html(text: &str) -> Html<&str> {
+    Html(text)
+}
+
+impl Display for Html<&str> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut style = Style::new();
+        let mut span_open = false;
+
+        for event in Parser::new(self.0).lossy(true).flatten() {
+            match event {
+                Event::Text(text) => {
+                    if !span_open && style != Style::new() {
+                        write_span_start(f, style)?;
+                        span_open = true;
+                    }
+                    HtmlEscaper(f).write_str(text)?;
+                }
+                Event::Style(new_style) => {
+                    if new_style != style && span_open {
+                        f.write_str("</span>")?;
+                        span_open = false;
+                    }
+                    style = new_style;
+                }
+                Event::Unknown(_) => {}
+            }
+        }
+
+        if span_open {
+            f.write_str("</span>")?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn write_span_start(f: &mut impl Write, style: Style) -> Result {
+    f.write_str("<span style=\"")?;
+    write_css(f, style)?;
+    f.write_str("\">")
+}
+
+/// Writes the CSS declarations equivalent to the style.
+pub(crate) fn write_css(f: &mut impl Write, style: Style) -> Result {
+    let mut declarations = Declarations { f, first: true };
+
+    let (fg, bg) = if style.get_effect(Effect::Reverse) {
+        (
+            Some(CssColor::or_system(style.bg, "Canvas")),
+            Some(CssColor::or_system(style.fg, "CanvasText")),
+        )
+    } else {
+        (CssColor::from(style.fg), CssColor::from(style.bg))
+    };
+    let fg = if style.get_effect(Effect::Conceal) {
+        Some(CssColor::System("transparent"))
+    } else {
+        fg
+    };
+    if let Some(fg) = fg {
+        declarations.write("color", fg)?;
+    }
+    if let Some(bg) = bg {
+        declarations.write("background-color", bg)?;
+    }
+
+    if style.get_effect(Effect::Bold) {
+        declarations.write("font-weight", "bold")?;
+    }
+    if style.get_effect(Effect::Faint) {
+        declarations.write("opacity", "0.5")?;
+    }
+    if style.get_effect(Effect::Italic) {
+        declarations.write("font-style", "italic")?;
+    }
+    if style.get_effect(Effect::Superscript) {
+        declarations.write("vertical-align", "super")?;
+    } else if style.get_effect(Effect::Subscript) {
+        declarations.write("vertical-align", "sub")?;
+    }
+    if style.get_effect(Effect::Framed) || style.get_effect(Effect::Encircled) {
+        declarations.write("border", "1px solid")?;
+    }
+    if style.get_effect(Effect::Encircled) {
+        declarations.write("border-radius", "50%")?;
+    }
+
+    let underline_style = style.get_underline_style();
+    let lines = [
+        (underline_style.is_some(), "underline"),
+        (style.get_effect(Effect::Strikethrough), "line-through"),
+        (style.get_effect(Effect::Overline), "overline"),
+        (
+            style.get_effect(Effect::Blink) || style.get_effect(Effect::RapidBlink),
+            "blink",
+        ),
+    ];
+    if lines.iter().any(|&(enabled, _)| enabled) {
+        declarations.write("text-decoration-line", TextDecorationLine(lines))?;
+    }
+    let decoration_style = match underline_style {
+        Some(UnderlineStyle::Curly) => Some("wavy"),
+        Some(UnderlineStyle::Dotted) => Some("dotted"),
+        Some(UnderlineStyle::Dashed) => Some("dashed"),
+        Some(UnderlineStyle::Double) => Some("double"),
+        Some(UnderlineStyle::Solid) | None => None,
+    };
+    if let Some(decoration_style) = decoration_style {
+        declarations.write("text-decoration-style", decoration_style)?;
+    }
+    if let Some(color) = CssColor::from(style.underline_color) {
+        declarations.write("text-decoration-color", color)?;
+    }
+
+    Ok(())
+}
+
+struct Declarations<'a, W: Write> {
+    f: &'a mut W,
+    first: bool,
+}
+
+impl<W: Write> Declarations<'_, W> {
+    fn write(&mut self, property: &str, value: impl Display) -> Result {
+        if !self.first {
+            self.f.write_char(';')?;
+        }
+        self.first = false;
+        write!(self.f, "{property}:{value}")
+    }
+}
+
+#[derive(Clone, Copy)]
+enum CssColor {
+    Rgb(RGBColor),
+    System(&'static str),
+}
+
+impl CssColor {
+    fn from(color: Option<Color>) -> Option<Self> {
+        color.and_then(Color::to_xterm_rgb).map(CssColor::Rgb)
+    }
+
+    fn or_system(color: Option<Color>, system_color: &'static str) -> Self {
+        CssColor::from(color).unwrap_or(CssColor::System(system_color))
+    }
+}
+
+impl Display for CssColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CssColor::Rgb(RGBColor { r, g, b }) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+            CssColor::System(name) => f.write_str(name),
+        }
+    }
+}
+
+struct TextDecorationLine([(bool, &'static str); 4]);
+
+impl Display for TextDecorationLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut separator = "";
+        for &(_, line) in self.0.iter().filter(|(enabled, _)| *enabled) {
+            write!(f, "{separator}{line}")?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+/// A writer that escapes the HTML special characters before forwarding the text to the inner writer.
+pub(crate) struct HtmlEscaper<'a, W: Write>(pub(crate) &'a mut W);
+
+impl<W: Write> Write for HtmlEscaper<'_, W> {
+    fn write_str(&mut self, s: &str) -> Result {
+        let mut rest = s;
+        while let Some(index) = rest.find(['<', '>', '&', '"', '\'']) {
+            let (text, special) = rest.split_at(index);
+            self.0.write_str(text)?;
+            let escaped = match special.as_bytes()[0] {
+                b'<' => "&lt;",
+                b'>' => "&gt;",
+                b'&' => "&amp;",
+                b'"' => "&quot;",
+                _ => "&#39;",
+            };
+            self.0.write_str(escaped)?;
+            rest = &special[1..];
+        }
+        self.0.write_str(rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AppliedTo as _, ToStyleSet as _, assert_display,
+        color::{BasicColor, IndexedColor},
+    };
+
+    use super::*;
+
+    #[test]
+    fn ansi_text() {
+        assert_display!(ansi_to_html(""), "");
+        assert_display!(ansi_to_html("a < b"), "a &lt; b");
+        assert_display!(
+            ansi_to_html("\x1b[1mA\x1b[1mB\x1b[3mC\x1b[0mD"),
+            r#"<span style="font-weight:bold">AB</span><span style="font-weight:bold;font-style:italic">C</span>D"#
+        );
+        assert_display!(
+            ansi_to_html("\x1b[31m\x1b[32mA\x1b[39m\x1b[0m"),
+            r#"<span style="color:#00cd00">A</span>"#
+        );
+        assert_display!(
+            ansi_to_html("\x1b[4mA"),
+            r#"<span style="text-decoration-line:underline">A</span>"#
+        );
+    }
+
+    #[test]
+    fn ansi_text_with_other_sequences() {
+        assert_display!(
+            ansi_to_html("\x1b[2J\x1b[31mA\x1b[K\x1b[0m\x1b[K"),
+            r#"<span style="color:#cd0000">A</span>"#
+        );
+        assert_display!(
+            ansi_to_html("see \x1b]8;;https://example.com\x1b\\\x1b[4mdocs\x1b[0m\x1b]8;;\x1b\\."),
+            r#"see <span style="text-decoration-line:underline">docs</span>."#
+        );
+        assert_display!(
+            ansi_to_html("\x1b[1;99mA\x1b[3mB"),
+            r#"A<span style="font-style:italic">B</span>"#
+        );
+    }
+
+    #[test]
+    fn plain() {
+        assert_display!(Styled::new("a < b").to_html(), "a &lt; b");
+    }
+
+    #[test]
+    fn escaping() {
+        assert_display!(
+            Styled::new(r#"<a href="x">Tom & 'Jerry'</a>"#).to_html(),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; &#39;Jerry&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn effects() {
+        assert_display!(
+            Style::new()
+                .bold()
+                .faint()
+                .italic()
+                .applied_to("X")
+                .to_html(),
+            r#"<span style="font-weight:bold;opacity:0.5;font-style:italic">X</span>"#
+        );
+        assert_display!(
+            Style::new()
+                .curly_underline()
+                .strikethrough()
+                .overline()
+                .blink()
+                .underline_color(BasicColor::Red)
+                .applied_to("X")
+                .to_html(),
+            r#"<span style="text-decoration-line:underline line-through overline blink;text-decoration-style:wavy;text-decoration-color:#cd0000">X</span>"#
+        );
+        assert_display!(
+            Style::new()
+                .superscript()
+                .encircled()
+                .applied_to("X")
+                .to_html(),
+            r#"<span style="vertical-align:super;border:1px solid;border-radius:50%">X</span>"#
+        );
+    }
+
+    #[test]
+    fn colors() {
+        assert_display!(
+            Style::new()
+                .fg(BasicColor::Green.bright())
+                .bg(IndexedColor(33))
+                .applied_to("X")
+                .to_html(),
+            r#"<span style="color:#00ff00;background-color:#0087ff">X</span>"#
+        );
+        assert_display!(
+            Style::new()
+                .fg(Color::Default)
+                .bg(Color::rgb(1, 2, 3))
+                .applied_to("X")
+                .to_html(),
+            r#"<span style="background-color:#010203">X</span>"#
+        );
+    }
+
+    #[test]
+    fn reverse() {
+        assert_display!(
+            Style::new()
+                .reverse()
+                .fg(BasicColor::Red)
+                .applied_to("X")
+                .to_html(),
+            r#"<span style="color:Canvas;background-color:#cd0000">X</span>"#
+        );
+        assert_display!(
+            Style::new().reverse().applied_to("X").to_html(),
+            r#"<span style="color:Canvas;background-color:CanvasText">X</span>"#
+        );
+    }
+
+    #[test]
+    fn conceal() {
+        assert_display!(
+            Style::new()
+                .conceal()
+                .fg(BasicColor::Red)
+                .bg(BasicColor::Blue)
+                .applied_to("X")
+                .to_html(),
+            r#"<span style="color:transparent;background-color:#0000ee">X</span>"#
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use core::fmt::{Display, Formatter, Result};
+
+use crate::{Style, Styled};
+
+/// Joins the items of an iterator with a separator, applying a style to each item.
+///
+/// The items are rendered one by one when the returned value is formatted, without collecting them into a string:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, styled_join, ToStyle as _};
+///
+/// let paths = ["src/lib.rs", "src/main.rs"];
+/// assert_eq!(
+///     format!("{}", styled_join(&paths, ", ", Color::CYAN.to_style())),
+///     "\x1b[36msrc/lib.rs\x1b[0m, \x1b[36msrc/main.rs\x1b[0m"
+/// );
+/// ```
+///
+/// The separator can be styled as well, with [`StyledJoin::with_separator_style()`]. Since the value may be
+/// formatted more than once, the iterator must be [`Clone`], which is the case for references to collections.
+#[must_use]
+pub fn styled_join<I, S>(items: I, separator: S, style: Style) -> StyledJoin<I, S>
+where
+    I: IntoIterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    StyledJoin {
+        items,
+        separator,
+        item_style: style,
+        separator_style: Style::new(),
+    }
+}
+
+/// The items of an iterator joined with a separator.
+///
+/// It is returned by [`styled_join()`], and renders the styled items and separators when formatted with [`Display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyledJoin<I, S> {
+    items: I,
+    separator: S,
+    item_style: Style,
+    separator_style: Style,
+}
+
+impl<I, S> StyledJoin<I, S> {
+    /// Gets the style applied to the items.
+    #[must_use]
+    pub const fn get_item_style(&self) -> Style {
+        self.item_style
+    }
+
+    /// Returns a new value with the given style applied to the items.
+    #[must_use]
+    pub fn with_item_style(self, style: Style) -> Self {
+        Self {
+            item_style: style,
+            ..self
+        }
+    }
+
+    /// Gets the style applied to the separators.
+    #[must_use]
+    pub const fn get_separator_style(&self) -> Style {
+        self.separator_style
+    }
+
+    /// Returns a new value with the given style applied to the separators.
+    #[must_use]
+    pub fn with_separator_style(self, style: Style) -> Self {
+        Self {
+            separator_style: style,
+            ..self
+        }
+    }
+}
+
+impl<I, S> Display for StyledJoin<I, S>
+where
+    I: IntoIterator + Clone,
+    I::Item: Display,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (index, item) in self.items.clone().into_iter().enumerate() {
+            if index > 0 {
+                write!(
+                    f,
+                    "{}",
+                    Styled::new(&self.separator).with_style(self.separator_style)
+                )?;
+            }
+            write!(f, "{}", Styled::new(item).with_style(self.item_style))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Effect, ToStyle as _, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn join() {
+        let items = [1, 2, 3];
+        let style = Effect::Bold.to_style();
+        assert_eq!(
+            styled_join(&items, ", ", style).to_string(),
+            "\x1b[1m1\x1b[0m, \x1b[1m2\x1b[0m, \x1b[1m3\x1b[0m"
+        );
+        assert_eq!(styled_join(&items, '-', Style::new()).to_string(), "1-2-3");
+        assert_eq!(styled_join([1], ", ", style).to_string(), "\x1b[1m1\x1b[0m");
+        assert_eq!(styled_join(&[] as &[i32], ", ", style).to_string(), "");
+        assert_eq!(styled_join(1..=3, "", Style::new()).to_string(), "123");
+    }
+
+    #[test]
+    fn styles() {
+        let join =
+            styled_join(["a", "b"], "|", Style::new()).with_separator_style(Color::RED.to_style());
+        assert_eq!(join.get_item_style(), Style::new());
+        assert_eq!(join.get_separator_style(), Color::RED.to_style());
+        assert_eq!(join.to_string(), "a\x1b[31m|\x1b[0mb");
+
+        let join = join.with_item_style(Style::new().bold());
+        assert_eq!(join.get_item_style(), Style::new().bold());
+        assert_eq!(
+            join.to_string(),
+            "\x1b[1ma\x1b[0m\x1b[31m|\x1b[0m\x1b[1mb\x1b[0m"
+        );
+    }
+}
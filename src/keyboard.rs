@@ -0,0 +1,185 @@
+//! Kitty keyboard protocol sequences.
+//!
+//! The [kitty keyboard protocol](https://sw.kovidgoyal.net/kitty/keyboard-protocol/) lets programs request progressive
+//! enhancements to how keys are reported. The terminal keeps a stack of enhancement flags: a program pushes the flags it
+//! needs when it starts, and pops them before it exits.
+//!
+//! ```
+//! use fluent_ansi::keyboard::{KeyboardFlags, PopKeyboardFlags, PushKeyboardFlags};
+//!
+//! let flags = KeyboardFlags::DISAMBIGUATE_ESCAPE_CODES | KeyboardFlags::REPORT_EVENT_TYPES;
+//! assert_eq!(format!("{}", PushKeyboardFlags(flags)), "\x1b[>3u");
+//! assert_eq!(format!("{}", PopKeyboardFlags(1)), "\x1b[<1u");
+//! ```
+
+use core::{
+    fmt::{Display, Formatter, Result},
+    ops::{BitOr, BitOrAssign},
+};
+
+use crate::sequence::fixed_sequence;
+
+/// A set of keyboard enhancement flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct KeyboardFlags(u8);
+
+impl KeyboardFlags {
+    /// Reports ambiguous keys, like `Esc` and `Alt` combinations, with unambiguous escape codes.
+    pub const DISAMBIGUATE_ESCAPE_CODES: KeyboardFlags = KeyboardFlags(1);
+    /// Reports key repeat and release events, besides press events.
+    pub const REPORT_EVENT_TYPES: KeyboardFlags = KeyboardFlags(2);
+    /// Reports the shifted and base layout keys, besides the key itself.
+    pub const REPORT_ALTERNATE_KEYS: KeyboardFlags = KeyboardFlags(4);
+    /// Reports all keys as escape codes, including the ones that produce text.
+    pub const REPORT_ALL_KEYS_AS_ESCAPE_CODES: KeyboardFlags = KeyboardFlags(8);
+    /// Reports the text associated with the keys.
+    pub const REPORT_ASSOCIATED_TEXT: KeyboardFlags = KeyboardFlags(16);
+
+    /// Creates an empty set of flags.
+    #[must_use]
+    pub const fn empty() -> Self {
+        KeyboardFlags(0)
+    }
+
+    /// Creates a set of flags from its bits, ignoring the unknown ones.
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> Self {
+        KeyboardFlags(bits & 0b1_1111)
+    }
+
+    /// Returns the bits of the flags.
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether all the given flags are set.
+    #[must_use]
+    pub const fn contains(self, other: KeyboardFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the union of both sets of flags.
+    #[must_use]
+    pub const fn union(self, other: KeyboardFlags) -> Self {
+        KeyboardFlags(self.0 | other.0)
+    }
+}
+
+impl BitOr for KeyboardFlags {
+    type Output = KeyboardFlags;
+
+    fn bitor(self, other: KeyboardFlags) -> KeyboardFlags {
+        self.union(other)
+    }
+}
+
+impl BitOrAssign for KeyboardFlags {
+    fn bitor_assign(&mut self, other: KeyboardFlags) {
+        *self = self.union(other);
+    }
+}
+
+/// Pushes the given flags onto the terminal's stack of keyboard enhancement flags, making them the current flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PushKeyboardFlags(pub KeyboardFlags);
+
+impl Display for PushKeyboardFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "\x1b[>{}u", self.0.bits())
+    }
+}
+
+/// Pops the given number of entries from the terminal's stack of keyboard enhancement flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PopKeyboardFlags(pub u16);
+
+impl Display for PopKeyboardFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "\x1b[<{}u", self.0)
+    }
+}
+
+/// How [`SetKeyboardFlags`] changes the current keyboard enhancement flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SetMode {
+    /// Replaces the current flags.
+    Replace,
+    /// Sets the given flags, keeping the others.
+    Add,
+    /// Unsets the given flags, keeping the others.
+    Remove,
+}
+
+/// Changes the current keyboard enhancement flags, without pushing a new entry onto the stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SetKeyboardFlags {
+    /// The flags to change.
+    pub flags: KeyboardFlags,
+    /// How the flags are changed.
+    pub mode: SetMode,
+}
+
+impl Display for SetKeyboardFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mode = match self.mode {
+            SetMode::Replace => 1,
+            SetMode::Add => 2,
+            SetMode::Remove => 3,
+        };
+        write!(f, "\x1b[={};{mode}u", self.flags.bits())
+    }
+}
+
+fixed_sequence!(
+    /// Queries the current keyboard enhancement flags.
+    ///
+    /// Supporting terminals respond with `CSI ? flags u`.
+    QueryKeyboardFlags,
+    "\x1b[?u"
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_display;
+
+    use super::*;
+
+    #[test]
+    fn flags() {
+        let mut flags = KeyboardFlags::empty();
+        assert_eq!(flags.bits(), 0);
+
+        flags |= KeyboardFlags::REPORT_ALTERNATE_KEYS;
+        flags |= KeyboardFlags::REPORT_ASSOCIATED_TEXT;
+        assert_eq!(flags.bits(), 20);
+        assert!(flags.contains(KeyboardFlags::REPORT_ALTERNATE_KEYS));
+        assert!(!flags.contains(KeyboardFlags::REPORT_EVENT_TYPES));
+
+        assert_eq!(KeyboardFlags::from_bits(0xff).bits(), 31);
+    }
+
+    #[test]
+    fn sequences() {
+        assert_display!(
+            PushKeyboardFlags(KeyboardFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES),
+            "\x1b[>8u"
+        );
+        assert_display!(PopKeyboardFlags(2), "\x1b[<2u");
+        assert_display!(QueryKeyboardFlags, "\x1b[?u");
+        assert_display!(
+            SetKeyboardFlags {
+                flags: KeyboardFlags::DISAMBIGUATE_ESCAPE_CODES,
+                mode: SetMode::Add,
+            },
+            "\x1b[=1;2u"
+        );
+        assert_display!(
+            SetKeyboardFlags {
+                flags: KeyboardFlags::empty(),
+                mode: SetMode::Replace,
+            },
+            "\x1b[=0;1u"
+        );
+    }
+}
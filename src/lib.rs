@@ -44,7 +44,8 @@
 //! escape sequence corresponding to the styling, and is succeeded by the escape sequence that resets the styling.
 //!
 //! A [`Styled<C>`] instance is obtained with the [`applied_to()`](AppliedTo::applied_to) method in any styling type,
-//! or with [`Styled<C>::new()`] to create an instance without any styling.
+//! or with [`Styled<C>::new()`] to create an instance without any styling. Unsized content, like a `&dyn Display`
+//! value, can be styled with [`display()`](AppliedTo::display), which returns a [`StyledRef`].
 //!
 //! ```
 //! use fluent_ansi::{prelude::*, Styled};
@@ -55,6 +56,11 @@
 //! assert_eq!(format!("{}", Styled::new("Some content").bold().fg(Color::RED)), "\x1b[1;31mSome content\x1b[0m");
 //! ```
 //!
+//! Formatted content can be styled without allocating with the [`styled_args!`] macro, which applies a style to the
+//! result of [`format_args!`]. Whole lines with several differently styled segments can be formatted with the
+//! [`ansi_format!`] macro, or by adding [`Styled<C>`] values and other values with the `+` operator, which results
+//! in a [`Chain`] value.
+//!
 //! # Style elements
 //!
 //! Effects and colors are the style elements. They are combined into [`Style`] values, even if not explicitly.
@@ -119,6 +125,7 @@
 //! assert_eq!(format!("{}", red_background.applied_to("Some content")), "\x1b[41mSome content\x1b[0m");
 //!
 //! let red_underline: TargetedColor = Color::RED.for_underline();
+//! # #[cfg(feature = "underline-color")]
 //! assert_eq!(format!("{}", red_underline.applied_to("Some content")), "\x1b[58;5;1mSome content\x1b[0m");
 //! ```
 //!
@@ -164,6 +171,7 @@
 //!     .add(Color::RED.for_fg())
 //!     .add(Color::indexed(42).for_underline());
 //! let rendered = format!("{}", style.applied_to("Some content"));
+//! # #[cfg(feature = "underline-color")]
 //! assert_eq!(rendered, "\x1b[1;31;58;5;42mSome content\x1b[0m");
 //! ```
 //!
@@ -195,6 +203,17 @@
 //!
 //! *Note* \[2]: there is in fact a single [`add()`](ToStyleSet::add) method that takes an <code>impl [StyleElement]</code> argument.
 //!
+//! Since trait methods can't be called in `const` contexts, [`Style`] also provides the `const` methods
+//! [`with_effect()`](Style::with_effect), [`with_fg()`](Style::with_fg), [`with_bg()`](Style::with_bg) and
+//! [`with_underline_color()`](Style::with_underline_color). The [`style!`] macro builds on them:
+//!
+//! ```
+//! use fluent_ansi::{prelude::*, style, Style};
+//!
+//! const HEADER: Style = style!(bold, underline, fg = Color::CYAN);
+//! assert_eq!(HEADER, Color::CYAN.bold().underline());
+//! ```
+//!
 //!
 //! ### Methods provided by the [`StyleSet`] trait
 //!
@@ -249,6 +268,32 @@
 //! *Note*: there is in fact a single [`get()`](StyleSet::get) method that is based on the [`StyleAttribute`] trait.
 //!
 //!
+//! # Style specifications
+//!
+//! A [`Style`] can be parsed from a human-readable specification string, which is convenient
+//! for styles that come from configuration files:
+//!
+//! ```
+//! use fluent_ansi::{prelude::*, Style};
+//!
+//! let style = Style::from_spec("bold underline yellow on blue").unwrap();
+//! assert_eq!(style, Style::new().bold().underline().fg(Color::YELLOW).bg(Color::BLUE));
+//! ```
+//!
+//! See [`Style::from_spec()`] for the specification grammar. The inverse operation is provided by
+//! [`Style::to_spec()`]:
+//!
+//! ```
+//! use fluent_ansi::prelude::*;
+//!
+//! let style = Color::RED.bold().bg(Color::BLUE);
+//! assert_eq!(format!("{}", style.to_spec()), "bold red on blue");
+//! ```
+//!
+//! A readable description, like `bold + fg:red + bg:blue`, is rendered by formatting a style with the alternate flag,
+//! like in `format!("{style:#}")`.
+//!
+//!
 //! # The [`Reset`] singleton
 //!
 //! [`Reset`] is a singleton value that represents the "reset" ANSI code. It can be used to manually control
@@ -262,22 +307,235 @@
 //!
 //! assert_eq!(output, "\x1b[1;31mSome content\x1b[0m");
 //! ```
+//!
+//!
+//! # Render options
+//!
+//! Some terminals and tools only accept specific forms of escape sequences. [`Style::render()`] and
+//! [`Styled::render()`] render with the given [`RenderOptions`] instead of the default forms:
+//!
+//! ```
+//! use fluent_ansi::{prelude::*, RenderOptions};
+//!
+//! let options = RenderOptions::new().colon_colors(true);
+//! let styled = Color::rgb(0, 128, 255).applied_to("Some content");
+//! # #[cfg(feature = "truecolor")]
+//! assert_eq!(
+//!     format!("{}", styled.render(options)),
+//!     "\x1b[38:2::0:128:255mSome content\x1b[0m"
+//! );
+//! ```
+//!
+//!
+//! # HTML rendering
+//!
+//! A [`Styled<C>`] value can also be rendered as HTML with [`Styled::to_html()`], which encloses the
+//! escaped content in a `<span>` element with the equivalent CSS:
+//!
+//! ```
+//! use fluent_ansi::prelude::*;
+//!
+//! let styled = Color::BLUE.italic().applied_to("Some content");
+//! assert_eq!(
+//!     styled.to_html().to_string(),
+//!     r#"<span style="color:#0000ee;font-style:italic">Some content</span>"#
+//! );
+//! ```
+//!
+//! Whole strings containing escape sequences can be converted with [`ansi_to_html()`], which is built on the
+//! [`parse`] module.
+//!
+//! To render only the content, with no escape sequences, as when writing to a log file, use [`Styled::plain()`].
+//! Conversely, untrusted content can be wrapped in [`Sanitized`] before being styled, so the escape sequences it may
+//! contain are rendered as visible text instead of being interpreted by the terminal.
+//!
+//!
+//! # Other escape sequences
+//!
+//! Besides styling, the crate provides values for other escape sequences, which are also rendered with
+//! [`Display`](core::fmt::Display):
+//! - [`cursor`]: cursor movement, saving and restoring, visibility, and position queries.
+//! - [`erase`]: erasing parts of the line or of the display.
+//! - [`keyboard`]: the kitty keyboard protocol.
+//! - [`mode`]: terminal modes, like the alternate screen buffer, synchronized output, and mouse and
+//!   focus reporting.
+//! - [`osc`]: Operating System Commands, like setting the window title, redefining palette colors and querying the
+//!   default colors.
+//! - [`scroll`]: scroll regions and scrolling.
+//! - [`window`]: window queries, like the text area size.
+//!
+//! The responses to the queries can be parsed with the corresponding report types, which fail with
+//! [`ParseResponseError`].
+//!
+//!
+//! # Cargo features
+//!
+//! The default features only enable the emission of the escape sequences that are not supported by every terminal.
+//! Disabling them downgrades what is rendered to basic SGR parameters, for targets where only 16 colors are wanted.
+//! The styles still hold the same values, and the APIs and the [`Code`] variants are the same: only the emitted
+//! escape sequences change.
+//!
+//! - `alloc`: provides [`AnsiString`], an owned string made of styled segments, [`StyledCow`], a styled
+//!   string whose content is either borrowed or owned, and [`Styled::to_styled_string()`] and conversions from
+//!   [`Style`] and [`Styled<C>`] values to `String`, which render them.
+//! - `anstyle`: implements conversions from and to the [`anstyle`](https://docs.rs/anstyle) types.
+//! - `crossterm`: implements conversions from and to the [`crossterm`](https://docs.rs/crossterm) `ContentStyle`,
+//!   `Color` and `Attribute` types.
+//! - `defmt`: implements [`defmt::Format`](https://docs.rs/defmt/latest/defmt/trait.Format.html) for [`Style`], the
+//!   color types, [`Effect`], [`UnderlineStyle`], [`TargetedColor`], [`ColorTarget`], [`Reset`] and [`Styled<C>`].
+//!   Values are formatted as readable descriptions, like `bold red on blue` for styles.
+//! - `extended-underline` (default): emits the curly, dotted and dashed underline styles, with the `4:x` parameters.
+//!   Without it, they are downgraded to a solid underline when rendered. The double underline is always emitted.
+//! - `heapless`: provides [`Style::to_heapless_string()`] and [`Styled::to_heapless_string()`], which render into
+//!   [`heapless`](https://docs.rs/heapless) strings, for building styled output without allocation.
+//! - `log`: provides [`LogFormatter`], which formats [`log`](https://docs.rs/log) records with the styles from a
+//!   [`Theme`](theme::Theme), and maps the log levels to [`theme::Key`]s.
+//! - `macros`: provides the `styled!` macro, which formats a template with inline style tags that are checked at
+//!   compile time.
+//! - `owo-colors`: implements conversions from and to the [`owo-colors`](https://docs.rs/owo-colors) `Style`,
+//!   `DynColors` and `AnsiColors` types.
+//! - `proptest`: implements [`proptest`](https://docs.rs/proptest)'s `Arbitrary` for [`Style`], the color types,
+//!   [`Effect`], [`UnderlineStyle`], [`TargetedColor`] and [`ColorTarget`], for property tests. It enables the
+//!   `alloc` feature.
+//! - `quickcheck`: implements [`quickcheck`](https://docs.rs/quickcheck)'s `Arbitrary` for the same types as the
+//!   `proptest` feature. It enables the `alloc` feature.
+//! - `ratatui`: implements conversions from and to the [`ratatui`](https://docs.rs/ratatui) `Style` and `Color`
+//!   types.
+//! - `serde`: implements `Serialize` and `Deserialize` for [`Style`], the color types, [`Effect`],
+//!   [`UnderlineStyle`], [`TargetedColor`], [`ColorTarget`] and [`Theme`](theme::Theme). Values are represented
+//!   as human-friendly strings, like `"bold red on blue"` for styles.
+//! - `std`: provides [`AnsiWriter`], which writes styled output to an [`std::io::Write`] writer, changing the
+//!   styling with the minimal escape sequences, and [`AutoStream`], which strips the escape sequences from the output
+//!   written to destinations that should not be styled, according to a [`ColorChoice`]. It enables the `alloc`
+//!   feature.
+//! - `termcolor`: implements conversions from and to the [`termcolor`](https://docs.rs/termcolor) `ColorSpec` and
+//!   `Color` types.
+//! - `testing`: provides the [`testing`] module, with a virtual terminal that interprets rendered output, so tests can
+//!   assert on the resulting styled characters. It enables the `alloc` feature.
+//! - `tracing-subscriber`: provides [`TracingFormatter`] and [`TracingFields`], which format
+//!   [`tracing-subscriber`](https://docs.rs/tracing-subscriber) events and fields with the styles from a
+//!   [`Theme`](theme::Theme). It enables the `std` feature.
+//! - `truecolor` (default): emits RGB colors. Without it, they are downgraded to the nearest simple colors in xterm's
+//!   palette when rendered.
+//! - `underline-color` (default): emits underline colors. Without it, they are left out when rendered.
+//! - `unicode-width`: measures the visible width of content with [`unicode-width`](https://docs.rs/unicode-width),
+//!   so wide characters, like CJK ideographs and emoji, take two columns, and combining characters take none, when
+//!   padding [`Styled<C>`] values and in [`wrap_visible()`] and [`slice_visible()`]. Without it, every character takes one column.
 
 pub use crate::{
-    applied_to::*, effect::*, reset::*, style::*, style_set::*, styled::*, targeted_color::*,
-    to_style::*, to_style_set::*,
+    applied_to::*, bytes::*, chain::*, code::*, compiled::*, custom_code::*, effect::*, effects::*,
+    gradient::*, highlight::*, html::*, join::*, line_resetter::*, plain::*, render::*, reset::*,
+    response::*, sanitized::*, slice::*, spans::*, style::*, style_builder::*, style_map::*,
+    style_set::*, style_source::*, styled::*, styled_debug::*, targeted_color::*, to_style::*,
+    to_style_set::*, wrap::*,
 };
 
+#[cfg(feature = "alloc")]
+pub use crate::ansi_string::*;
+#[cfg(feature = "std")]
+pub use crate::ansi_writer::*;
+#[cfg(feature = "std")]
+pub use crate::auto_stream::*;
+#[cfg(feature = "log")]
+pub use crate::log::*;
+#[cfg(feature = "alloc")]
+pub use crate::styled_cow::*;
+#[cfg(feature = "tracing-subscriber")]
+pub use crate::tracing_subscriber::*;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::{ConstColor, color_literal};
+    #[cfg(feature = "macros")]
+    pub use fluent_ansi_macros::styled;
+}
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+mod ansi_string;
+#[cfg(feature = "std")]
+mod ansi_writer;
+#[cfg(feature = "anstyle")]
+mod anstyle;
 mod applied_to;
+#[cfg(feature = "std")]
+mod auto_stream;
+mod bytes;
+mod chain;
+mod code;
 pub mod color;
+mod compiled;
+#[cfg(feature = "crossterm")]
+mod crossterm;
+pub mod cursor;
+mod custom_code;
+#[cfg(feature = "defmt")]
+mod defmt;
 mod effect;
+mod effects;
+pub mod erase;
+mod gradient;
+#[cfg(feature = "heapless")]
+mod heapless;
+mod highlight;
+mod html;
+mod join;
+pub mod keyboard;
+mod line_resetter;
+#[cfg(feature = "log")]
+mod log;
+mod macros;
+pub mod mode;
+pub mod osc;
+#[cfg(feature = "owo-colors")]
+mod owo_colors;
+pub mod parse;
+mod plain;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "ratatui")]
+mod ratatui;
+mod render;
 mod reset;
+mod response;
+mod sanitized;
+pub mod scroll;
+mod sequence;
+#[cfg(feature = "serde")]
+mod serde;
+mod sgr;
+mod slice;
+mod spans;
+#[cfg(feature = "alloc")]
+mod string;
 mod style;
+mod style_builder;
+mod style_map;
 mod style_set;
+mod style_source;
 mod styled;
+#[cfg(feature = "alloc")]
+mod styled_cow;
+mod styled_debug;
 mod targeted_color;
+#[cfg(feature = "termcolor")]
+mod termcolor;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod theme;
 mod to_style;
 mod to_style_set;
+#[cfg(feature = "tracing-subscriber")]
+mod tracing_subscriber;
+mod width;
+pub mod window;
+mod wrap;
 
 /// Re-exports the minimal set of items to style some content.
 ///
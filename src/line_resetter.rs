@@ -0,0 +1,174 @@
+use core::fmt::{Result, Write};
+
+use crate::{Style, sgr, width::EscapeState};
+
+/// A writer adapter that resets the styling before every line break, and applies it again after it.
+///
+/// The active style is tracked from the SGR sequences written through it, so that each line of multi-line styled
+/// output is styled on its own. This keeps the output well-formed for consumers that process each line
+/// independently, like log viewers:
+///
+/// ```
+/// use core::fmt::Write as _;
+/// use fluent_ansi::{prelude::*, LineResetter};
+///
+/// let mut writer = LineResetter::new(String::new());
+/// write!(writer, "{}", Color::RED.applied_to("one\ntwo")).unwrap();
+/// assert_eq!(writer.into_inner(), "\x1b[31mone\x1b[0m\n\x1b[31mtwo\x1b[0m");
+/// ```
+///
+/// Nothing is inserted around the line breaks written while no style is active. SGR sequences that are not
+/// supported, like the ones with invalid parameters, leave the active style unchanged.
+#[derive(Debug, Clone)]
+pub struct LineResetter<W: Write> {
+    inner: W,
+    style: Style,
+    state: EscapeState,
+    params: [u8; Style::MAX_RENDERED_LEN],
+    params_len: usize,
+}
+
+impl<W: Write> LineResetter<W> {
+    /// Creates a new `LineResetter` that writes to the given writer.
+    #[must_use]
+    pub const fn new(inner: W) -> Self {
+        Self {
+            inner,
+            style: Style::new(),
+            state: EscapeState::Text,
+            params: [0; Style::MAX_RENDERED_LEN],
+            params_len: 0,
+        }
+    }
+
+    /// Gets the active style.
+    #[must_use]
+    pub const fn get_style(&self) -> Style {
+        self.style
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Keeps track of the SGR sequences, to update the active style when they end.
+    fn advance(&mut self, c: char) {
+        let previous = self.state;
+        self.state.advance(c);
+        match (previous, self.state) {
+            (EscapeState::Escape, EscapeState::Csi) => self.params_len = 0,
+            (EscapeState::Csi, EscapeState::Csi) => {
+                // Parameters that don't fit are not SGR parameters this crate can apply
+                let len = c.len_utf8();
+                if let Some(buf) = self.params.get_mut(self.params_len..self.params_len + len) {
+                    c.encode_utf8(buf);
+                }
+                self.params_len += len;
+            }
+            (EscapeState::Csi, EscapeState::Text) if c == 'm' => {
+                if let Some(params) = self.params.get(..self.params_len)
+                    && let Ok(params) = core::str::from_utf8(params)
+                    && let Ok(style) = sgr::apply_params(self.style, params)
+                {
+                    self.style = style;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<W: Write> Write for LineResetter<W> {
+    fn write_str(&mut self, s: &str) -> Result {
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            if c == '\n' && self.state == EscapeState::Text && !self.style.is_plain() {
+                self.inner.write_str(&s[start..i])?;
+                write!(self.inner, "{}\n{}", Style::new(), self.style)?;
+                start = i + 1;
+            } else {
+                self.advance(c);
+            }
+        }
+        self.inner.write_str(&s[start..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, CustomCode, Styled, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    fn reset_lines(content: impl core::fmt::Display) -> String {
+        let mut writer = LineResetter::new(String::new());
+        write!(writer, "{content}").unwrap();
+        writer.into_inner()
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(reset_lines("a\nb\n"), "a\nb\n");
+        assert_eq!(reset_lines(Styled::new("a\nb")), "a\nb");
+    }
+
+    #[test]
+    fn styled() {
+        assert_eq!(
+            reset_lines(Color::RED.bold().applied_to("a\n\nb")),
+            "\x1b[1;31ma\x1b[0m\n\x1b[1;31m\x1b[0m\n\x1b[1;31mb\x1b[0m"
+        );
+        assert_eq!(
+            reset_lines(format_args!(
+                "{}\n{}",
+                Color::RED.applied_to("a"),
+                Color::BLUE.applied_to("b\nc")
+            )),
+            "\x1b[31ma\x1b[0m\n\x1b[34mb\x1b[0m\n\x1b[34mc\x1b[0m"
+        );
+        assert_eq!(
+            reset_lines("\x1b[1ma\x1b[3mb\nc\x1b[22md\ne\x1b[0m"),
+            "\x1b[1ma\x1b[3mb\x1b[0m\n\x1b[1;3mc\x1b[22md\x1b[0m\n\x1b[3me\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn split_writes() {
+        let mut writer = LineResetter::new(String::new());
+        writer.write_str("\x1b[3").unwrap();
+        writer.write_str("1ma\n").unwrap();
+        assert_eq!(writer.get_style(), Style::new().fg(Color::RED));
+        writer.write_str("b").unwrap();
+        assert_eq!(writer.get_ref(), "\x1b[31ma\x1b[0m\n\x1b[31mb");
+
+        writer.get_mut().clear();
+        writer.write_str("\x1b[0m").unwrap();
+        assert_eq!(writer.get_style(), Style::new());
+    }
+
+    #[test]
+    fn unsupported_sequences() {
+        assert_eq!(
+            reset_lines("\x1b[1m\x1b[999ma\x1b]0;x\ny\x07\nb\x1b[0m"),
+            "\x1b[1m\x1b[999ma\x1b]0;x\ny\x07\x1b[0m\n\x1b[1mb\x1b[0m"
+        );
+        assert_eq!(
+            reset_lines(CustomCode::new("60").applied_to("a\nb")),
+            "\x1b[60ma\nb\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use core::fmt::{Arguments, Display, Formatter, Result};
+
+use log::{Level, Record};
+
+use crate::{
+    AppliedTo as _, Style, Styled,
+    theme::{Key, Theme},
+};
+
+/// Maps a log level to the theme key with the same meaning.
+impl From<Level> for Key {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Error => Key::Error,
+            Level::Warn => Key::Warning,
+            Level::Info => Key::Info,
+            Level::Debug => Key::Debug,
+            Level::Trace => Key::Trace,
+        }
+    }
+}
+
+/// Formats [`log`](https://docs.rs/log) records with the styles from a [`Theme`].
+///
+/// The level is styled with the theme style for its [`Key`], the target with the [`Key::Muted`] style, and the
+/// message of errors and warnings with the same style as the level. [`Theme::env_logger()`] makes the output look
+/// like [`env_logger`](https://docs.rs/env_logger)'s. It can be used to implement a `log::Log`:
+///
+/// ```
+/// use fluent_ansi::{LogFormatter, theme::Theme};
+/// use log::{Log, Metadata, Record};
+///
+/// struct Logger(LogFormatter);
+///
+/// impl Log for Logger {
+///     fn enabled(&self, _: &Metadata) -> bool {
+///         true
+///     }
+///
+///     fn log(&self, record: &Record) {
+///         eprintln!("{}", self.0.format(record));
+///     }
+///
+///     fn flush(&self) {}
+/// }
+///
+/// let formatter = LogFormatter::new(Theme::default());
+/// let record = Record::builder().level(log::Level::Warn).target("app").args(format_args!("disk full")).build();
+/// assert_eq!(
+///     formatter.format(&record).to_string(),
+///     "\x1b[1;33mWARN\x1b[0m  \x1b[2mapp\x1b[0m: \x1b[1;33mdisk full\x1b[0m"
+/// );
+/// ```
+///
+/// Requires the `log` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LogFormatter {
+    theme: Theme,
+}
+
+impl LogFormatter {
+    /// Creates a new `LogFormatter` value with the given theme.
+    #[must_use]
+    pub const fn new(theme: Theme) -> Self {
+        Self { theme }
+    }
+
+    /// Gets the theme.
+    #[must_use]
+    pub const fn get_theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Returns the name of a level, like `"WARN"`, styled with the theme style for its [`Key`].
+    #[must_use]
+    pub fn level(&self, level: Level) -> Styled<&'static str> {
+        self.style(level).applied_to(level.as_str())
+    }
+
+    /// Returns a target styled with the [`Key::Muted`] theme style.
+    #[must_use]
+    pub fn target<'a>(&self, target: &'a str) -> Styled<&'a str> {
+        self.theme.style(Key::Muted).applied_to(target)
+    }
+
+    /// Returns the message of a record, styled with the level style for errors and warnings, and not styled
+    /// otherwise.
+    #[must_use]
+    pub fn message<'a>(&self, record: &'a Record<'_>) -> Styled<&'a Arguments<'a>> {
+        let style = if record.level() <= Level::Warn {
+            self.style(record.level())
+        } else {
+            Style::new()
+        };
+        style.applied_to(record.args())
+    }
+
+    /// Returns a value that renders a whole record, as the level padded to 5 characters, the target, and the message,
+    /// like `WARN  app: disk full`.
+    #[must_use]
+    pub fn format<'a>(&self, record: &'a Record<'_>) -> FormattedRecord<'a> {
+        FormattedRecord {
+            formatter: *self,
+            record,
+        }
+    }
+
+    fn style(&self, level: Level) -> Style {
+        self.theme.style(Key::from(level))
+    }
+}
+
+/// A log record formatted with a theme.
+///
+/// It is returned by [`LogFormatter::format()`].
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedRecord<'a> {
+    formatter: LogFormatter,
+    record: &'a Record<'a>,
+}
+
+impl Display for FormattedRecord<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{:<5} {}: {}",
+            self.formatter.level(self.record.level()),
+            self.formatter.target(self.record.target()),
+            self.formatter.message(self.record),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn level_keys() {
+        assert_eq!(Key::from(Level::Error), Key::Error);
+        assert_eq!(Key::from(Level::Warn), Key::Warning);
+        assert_eq!(Key::from(Level::Info), Key::Info);
+        assert_eq!(Key::from(Level::Debug), Key::Debug);
+        assert_eq!(Key::from(Level::Trace), Key::Trace);
+    }
+
+    #[test]
+    fn format() {
+        let theme = Theme::new()
+            .with(Key::Info, Color::GREEN)
+            .with(Key::Error, Color::RED.bold());
+        let formatter = LogFormatter::new(theme);
+        assert_eq!(formatter.get_theme(), &theme);
+
+        let args = format_args!("started in {}ms", 42);
+        let record = Record::builder()
+            .level(Level::Info)
+            .target("app::server")
+            .args(args)
+            .build();
+        assert_eq!(
+            formatter.format(&record).to_string(),
+            "\x1b[32mINFO\x1b[0m  app::server: started in 42ms"
+        );
+
+        let args = format_args!("failed");
+        let record = Record::builder()
+            .level(Level::Error)
+            .target("app")
+            .args(args)
+            .build();
+        assert_eq!(
+            formatter.format(&record).to_string(),
+            "\x1b[1;31mERROR\x1b[0m app: \x1b[1;31mfailed\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn parts() {
+        let formatter = LogFormatter::default();
+        assert_eq!(
+            formatter.level(Level::Debug).to_string(),
+            "\x1b[34mDEBUG\x1b[0m"
+        );
+        assert_eq!(formatter.target("db").to_string(), "\x1b[2mdb\x1b[0m");
+    }
+}
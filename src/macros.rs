@@ -0,0 +1,428 @@
+use crate::color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor, parse_color};
+
+/// Applies a style to formatted content, without allocating.
+///
+/// The first argument is any value that implements [`ToStyle`](crate::ToStyle), and the remaining arguments are
+/// passed to [`format_args!`]. The result is a [`Styled<fmt::Arguments>`](crate::Styled) value:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, styled_args};
+///
+/// let (done, total) = (3, 10);
+/// let line = format!("{}", styled_args!(Color::GREEN.bold(), "{done} of {total}"));
+/// assert_eq!(line, "\x1b[1;32m3 of 10\x1b[0m");
+/// ```
+///
+/// As with [`format_args!`], the formatted arguments borrow temporary values, so the result must be used in the same
+/// statement. To keep it in a variable, bind the [`format_args!`] result first:
+///
+/// ```
+/// use fluent_ansi::prelude::*;
+///
+/// let (done, total) = (3, 10);
+/// let args = format_args!("{} of {}", done, total);
+/// let styled = Color::GREEN.bold().applied_to(args);
+/// assert_eq!(format!("{styled:>9}"), "  \x1b[1;32m3 of 10\x1b[0m");
+/// ```
+#[macro_export]
+macro_rules! styled_args {
+    ($style:expr, $($args:tt)+) => {
+        $crate::AppliedTo::applied_to(
+            $crate::ToStyle::to_style($style),
+            ::core::format_args!($($args)+),
+        )
+    };
+}
+
+/// Formats a sequence of styled and unstyled segments, without allocating.
+///
+/// Each segment is either a format string, or the arguments for [`format_args!`] in parentheses, optionally preceded
+/// by a style in brackets. The style is any value that implements [`ToStyle`](crate::ToStyle), and it is applied only
+/// to its segment. Segments are separated by commas. The result is a [`fmt::Arguments`](core::fmt::Arguments) value
+/// that renders all the segments, one after the other:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, ansi_format};
+///
+/// let (name, code) = ("build", 2);
+/// let line = format!(
+///     "{}",
+///     ansi_format!([Color::RED.bold()] "ERROR", ": {name} ", [Effect::Italic] ("failed with {}", code))
+/// );
+/// assert_eq!(line, "\x1b[1;31mERROR\x1b[0m: build \x1b[3mfailed with 2\x1b[0m");
+/// ```
+///
+/// As with [`format_args!`], the result borrows temporary values, so it must be used in the same statement.
+#[macro_export]
+macro_rules! ansi_format {
+    () => {
+        ::core::format_args!("")
+    };
+    ([$style:expr] $fmt:literal $(, $($rest:tt)*)?) => {
+        ::core::format_args!(
+            "{}{}",
+            $crate::styled_args!($style, $fmt),
+            $crate::ansi_format!($($($rest)*)?),
+        )
+    };
+    ([$style:expr] ($($args:tt)+) $(, $($rest:tt)*)?) => {
+        ::core::format_args!(
+            "{}{}",
+            $crate::styled_args!($style, $($args)+),
+            $crate::ansi_format!($($($rest)*)?),
+        )
+    };
+    ($fmt:literal $(, $($rest:tt)*)?) => {
+        ::core::format_args!(
+            "{}{}",
+            ::core::format_args!($fmt),
+            $crate::ansi_format!($($($rest)*)?),
+        )
+    };
+    (($($args:tt)+) $(, $($rest:tt)*)?) => {
+        ::core::format_args!(
+            "{}{}",
+            ::core::format_args!($($args)+),
+            $crate::ansi_format!($($($rest)*)?),
+        )
+    };
+}
+
+/// Creates a [`Style`](crate::Style) value from a list of effects and colors, in `const` contexts too.
+///
+/// Each item is either an effect name, like `bold` or `curly_underline`, or a color assignment to `fg`, `bg` or
+/// `underline_color`. A color is either any color value, like `Color::RED` or `Color::rgb(0, 128, 255)`, or a string
+/// literal in one of the forms accepted by the [`FromStr`](core::str::FromStr) implementation for
+/// [`Color`](crate::color::Color), like `"#222"` or `"dodger-blue"`:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, style, Style};
+///
+/// const ERROR: Style = style!(bold, italic, fg = Color::RED, bg = "#222");
+///
+/// assert_eq!(ERROR, Color::RED.bold().italic().bg(Color::rgb(0x22, 0x22, 0x22)));
+/// ```
+///
+/// The items are applied in order, so later items override earlier ones, as with the fluent methods. An invalid
+/// color literal fails the compilation in `const` contexts, and panics otherwise. An unknown effect always fails the
+/// compilation:
+///
+/// ```compile_fail
+/// # use fluent_ansi::style;
+/// let style = style!(bolt);
+/// ```
+#[macro_export]
+macro_rules! style {
+    (@apply $style:expr;) => {
+        $style
+    };
+    (@apply $style:expr; fg = $color:literal $(, $($rest:tt)*)?) => {
+        $crate::style!(@apply $style.with_fg($crate::__private::color_literal($color)); $($($rest)*)?)
+    };
+    (@apply $style:expr; fg = $color:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@apply $style.with_fg($crate::__private::ConstColor($color).to_color()); $($($rest)*)?)
+    };
+    (@apply $style:expr; bg = $color:literal $(, $($rest:tt)*)?) => {
+        $crate::style!(@apply $style.with_bg($crate::__private::color_literal($color)); $($($rest)*)?)
+    };
+    (@apply $style:expr; bg = $color:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@apply $style.with_bg($crate::__private::ConstColor($color).to_color()); $($($rest)*)?)
+    };
+    (@apply $style:expr; underline_color = $color:literal $(, $($rest:tt)*)?) => {
+        $crate::style!(
+            @apply $style.with_underline_color($crate::__private::color_literal($color)); $($($rest)*)?
+        )
+    };
+    (@apply $style:expr; underline_color = $color:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(
+            @apply $style.with_underline_color($crate::__private::ConstColor($color).to_color());
+            $($($rest)*)?
+        )
+    };
+    (@apply $style:expr; $effect:ident $(, $($rest:tt)*)?) => {
+        $crate::style!(@apply $style.with_effect($crate::style!(@effect $effect)); $($($rest)*)?)
+    };
+    (@apply $style:expr; $($rest:tt)*) => {
+        compile_error!(concat!("invalid style item: `", stringify!($($rest)*), "`"))
+    };
+    (@effect bold) => { $crate::Effect::Bold };
+    (@effect faint) => { $crate::Effect::Faint };
+    (@effect italic) => { $crate::Effect::Italic };
+    (@effect underline) => { $crate::Effect::Underline };
+    (@effect curly_underline) => { $crate::Effect::CurlyUnderline };
+    (@effect dotted_underline) => { $crate::Effect::DottedUnderline };
+    (@effect dashed_underline) => { $crate::Effect::DashedUnderline };
+    (@effect blink) => { $crate::Effect::Blink };
+    (@effect rapid_blink) => { $crate::Effect::RapidBlink };
+    (@effect reverse) => { $crate::Effect::Reverse };
+    (@effect conceal) => { $crate::Effect::Conceal };
+    (@effect strikethrough) => { $crate::Effect::Strikethrough };
+    (@effect fraktur) => { $crate::Effect::Fraktur };
+    (@effect double_underline) => { $crate::Effect::DoubleUnderline };
+    (@effect framed) => { $crate::Effect::Framed };
+    (@effect encircled) => { $crate::Effect::Encircled };
+    (@effect overline) => { $crate::Effect::Overline };
+    (@effect superscript) => { $crate::Effect::Superscript };
+    (@effect subscript) => { $crate::Effect::Subscript };
+    (@effect $other:ident) => {
+        compile_error!(concat!("unknown effect `", stringify!($other), "`"))
+    };
+    (@ $($rest:tt)*) => {
+        compile_error!("invalid use of an internal rule of `style!`")
+    };
+    ($($items:tt)*) => {
+        $crate::style!(@apply $crate::Style::new(); $($items)*)
+    };
+}
+
+/// Formats a template with inline style tags, without allocating.
+///
+/// The template is a string literal in which styled text is enclosed in tags, like `<bold>` and `</bold>`, and the
+/// remaining arguments are formatting arguments, as in [`format_args!`]. The result is a
+/// [`fmt::Arguments`](core::fmt::Arguments) value:
+///
+/// ```
+/// use fluent_ansi::styled;
+///
+/// let (code, msg) = (42, "not found");
+/// let line = format!("{}", styled!("<b><red>E{code}</red></b>: {}", msg));
+/// assert_eq!(line, "\x1b[1;31mE42\x1b[0m: not found");
+/// ```
+///
+/// The content of a tag is a [style specification](crate::Style::from_spec), like `<bold yellow on #222>`, where
+/// `b`, `i`, `u` and `s` are also accepted as aliases for `bold`, `italic`, `underline` and `strikethrough`. A closing
+/// tag must repeat the content of the opening tag, or be empty, like `</>`. A literal `<` is written as `<<`.
+///
+/// The template is checked at compile time: unknown effects or colors and unbalanced tags fail the compilation.
+///
+/// ```compile_fail
+/// # use fluent_ansi::styled;
+/// println!("{}", styled!("<bold not-a-color>Error</>"));
+/// ```
+///
+/// ```compile_fail
+/// # use fluent_ansi::styled;
+/// println!("{}", styled!("<b>Error</i>"));
+/// ```
+///
+/// Nested tags are combined, so the text in the inner tags has the styles of all the enclosing tags, and no escape
+/// sequences are written for text outside the tags.
+///
+/// As with [`format_args!`], the result borrows temporary values, so it must be used in the same statement.
+///
+/// Requires the `macros` feature.
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! styled {
+    ($($args:tt)+) => {
+        $crate::__private::styled!($crate, $($args)+)
+    };
+}
+
+/// A wrapper that converts any color type to a [`Color`] in `const` contexts, for the [`style!`] macro.
+#[doc(hidden)]
+pub struct ConstColor<T>(pub T);
+
+impl ConstColor<Color> {
+    #[must_use]
+    pub const fn to_color(self) -> Color {
+        self.0
+    }
+}
+
+impl ConstColor<BasicColor> {
+    #[must_use]
+    pub const fn to_color(self) -> Color {
+        Color::Simple(SimpleColor::new(self.0))
+    }
+}
+
+impl ConstColor<SimpleColor> {
+    #[must_use]
+    pub const fn to_color(self) -> Color {
+        Color::Simple(self.0)
+    }
+}
+
+impl ConstColor<IndexedColor> {
+    #[must_use]
+    pub const fn to_color(self) -> Color {
+        Color::Indexed(self.0)
+    }
+}
+
+impl ConstColor<RGBColor> {
+    #[must_use]
+    pub const fn to_color(self) -> Color {
+        Color::RGB(self.0)
+    }
+}
+
+/// Parses a color literal, like `"#00ff88"`, for the [`style!`] macro.
+///
+/// # Panics
+///
+/// Panics if the literal is not a valid color.
+#[doc(hidden)]
+#[must_use]
+pub const fn color_literal(literal: &str) -> Color {
+    match parse_color(literal) {
+        Some(color) => color,
+        None => panic!("invalid color literal"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Style, Styled, ToStyleSet as _,
+        color::{Color, RGBColor, named},
+    };
+
+    #[test]
+    fn styled_args() {
+        let (a, b) = (1, "two");
+
+        assert_eq!(
+            styled_args!(Color::RED, "{a} and {b}").to_string(),
+            "\x1b[31m1 and two\x1b[0m"
+        );
+        assert_eq!(
+            styled_args!(Color::RED.bold(), "{} and {}", a, b).to_string(),
+            "\x1b[1;31m1 and two\x1b[0m"
+        );
+        assert_eq!(styled_args!(Style::new(), "{a:03}").to_string(), "001");
+    }
+
+    #[test]
+    fn ansi_format() {
+        let (a, b) = (1, "two");
+
+        assert_eq!(ansi_format!().to_string(), "");
+        assert_eq!(ansi_format!("plain {a}").to_string(), "plain 1");
+        assert_eq!(ansi_format!(("plain {}", b)).to_string(), "plain two");
+        assert_eq!(
+            ansi_format!([Color::RED] "red {a}").to_string(),
+            "\x1b[31mred 1\x1b[0m"
+        );
+        assert_eq!(
+            ansi_format!([Color::RED.bold()]("{} {}", a, b)).to_string(),
+            "\x1b[1;31m1 two\x1b[0m"
+        );
+        assert_eq!(
+            ansi_format!(
+                "[",
+                [Color::GREEN] "OK",
+                "] ",
+                [Style::new().italic()] ("{}", b),
+                ";",
+            )
+            .to_string(),
+            "[\x1b[32mOK\x1b[0m] \x1b[3mtwo\x1b[0m;"
+        );
+    }
+
+    #[test]
+    fn style() {
+        const STYLE: Style = style!(bold, italic, fg = Color::RED, bg = "#222");
+
+        assert_eq!(
+            STYLE,
+            Style::new()
+                .bold()
+                .italic()
+                .fg(Color::RED)
+                .bg(RGBColor::new(0x22, 0x22, 0x22))
+        );
+        assert_eq!(style!(), Style::new());
+        assert_eq!(style!(underline,), Style::new().underline());
+        assert_eq!(
+            style!(underline, curly_underline, blink, rapid_blink),
+            Style::new().curly_underline().rapid_blink()
+        );
+    }
+
+    #[test]
+    fn style_colors() {
+        assert_eq!(style!(fg = Color::RED), Style::new().fg(Color::RED));
+        assert_eq!(
+            style!(fg = Color::RED.bright()),
+            Style::new().fg(Color::RED.bright())
+        );
+        assert_eq!(
+            style!(bg = Color::indexed(42)),
+            Style::new().bg(Color::indexed(42))
+        );
+        assert_eq!(
+            style!(underline_color = Color::rgb(1, 2, 3)),
+            Style::new().underline_color(Color::rgb(1, 2, 3))
+        );
+        assert_eq!(style!(fg = Color::Default), Style::new().fg(Color::Default));
+        assert_eq!(
+            style!(underline_color = "#00FF88"),
+            Style::new().underline_color(Color::rgb(0, 255, 136))
+        );
+        assert_eq!(
+            style!(fg = Color::RED, fg = Color::BLUE),
+            Style::new().fg(Color::BLUE)
+        );
+    }
+
+    #[test]
+    fn style_color_literals() {
+        assert_eq!(
+            style!(
+                fg = "bright-red",
+                bg = "dodger-blue",
+                underline_color = "42"
+            ),
+            Style::new()
+                .fg(Color::RED.bright())
+                .bg(named::DODGER_BLUE)
+                .underline_color(Color::indexed(42))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid color literal")]
+    fn style_invalid_color_literal() {
+        let _ = style!(fg = "#12");
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn styled() {
+        let (a, b) = (1, "two");
+
+        assert_eq!(styled!("plain {a} {}", b).to_string(), "plain 1 two");
+        assert_eq!(
+            styled!("<bold>{a}</bold> {}", b).to_string(),
+            "\x1b[1m1\x1b[0m two"
+        );
+        #[cfg(feature = "truecolor")]
+        assert_eq!(
+            styled!("<b>x<red on #222>{a:>3}</>y</b>z",).to_string(),
+            "\x1b[1mx\x1b[0m\x1b[1;31;48;2;34;34;34m  1\x1b[0m\x1b[1my\x1b[0mz"
+        );
+        #[cfg(not(feature = "truecolor"))]
+        assert_eq!(
+            styled!("<b>x<red on #222>{a:>3}</>y</b>z",).to_string(),
+            "\x1b[1mx\x1b[0m\x1b[1;31;40m  1\x1b[0m\x1b[1my\x1b[0mz"
+        );
+        assert_eq!(
+            styled!(r"<i>\</i>{{<<}}").to_string(),
+            "\x1b[3m\\\x1b[0m{<}"
+        );
+    }
+
+    #[test]
+    fn arguments_content() {
+        let a = 1;
+        let args = format_args!("{a}!");
+        let stld = Styled::new(args).italic();
+
+        assert_eq!(stld.to_string(), "\x1b[3m1!\x1b[0m");
+        assert_eq!(format!("{stld:-<4}"), "\x1b[3m1!\x1b[0m--");
+        assert_eq!(stld.plain().to_string(), "1!");
+    }
+}
@@ -0,0 +1,156 @@
+//! Terminal mode sequences.
+//!
+//! The values in this module render CSI sequences that enable or disable terminal modes when formatted with
+//! [`Display`]. Modes stay enabled after the program exits, so they should be disabled before that.
+//!
+//! ```
+//! use fluent_ansi::{cursor, mode::{EnterAltScreen, LeaveAltScreen}};
+//!
+//! let enter = format!("{EnterAltScreen}{}", cursor::Hide);
+//! assert_eq!(enter, "\x1b[?1049h\x1b[?25l");
+//!
+//! let leave = format!("{}{LeaveAltScreen}", cursor::Show);
+//! assert_eq!(leave, "\x1b[?25h\x1b[?1049l");
+//! ```
+
+use core::fmt::{Display, Formatter, Result};
+
+use crate::sequence::fixed_sequence;
+
+fixed_sequence!(
+    /// Switches to the alternate screen buffer, saving the cursor position (`?1049` set).
+    ///
+    /// The alternate screen has no scrollback, and the content of the main screen is restored by [`LeaveAltScreen`].
+    EnterAltScreen,
+    "\x1b[?1049h"
+);
+fixed_sequence!(
+    /// Switches back to the main screen buffer, restoring the cursor position (`?1049` reset).
+    LeaveAltScreen,
+    "\x1b[?1049l"
+);
+fixed_sequence!(
+    /// Begins a synchronized update (`?2026` set).
+    ///
+    /// Supporting terminals hold the screen updates until [`EndSync`], so a frame composed of many writes is displayed
+    /// at once, without tearing. Other terminals ignore the sequence.
+    BeginSync,
+    "\x1b[?2026h"
+);
+fixed_sequence!(
+    /// Ends a synchronized update started by [`BeginSync`] (`?2026` reset).
+    EndSync,
+    "\x1b[?2026l"
+);
+
+fixed_sequence!(
+    /// Enables focus reporting (`?1004` set).
+    ///
+    /// The terminal then sends [`FOCUS_IN`] and [`FOCUS_OUT`] when its window gains or loses focus.
+    EnableFocusReporting,
+    "\x1b[?1004h"
+);
+fixed_sequence!(
+    /// Disables focus reporting (`?1004` reset).
+    DisableFocusReporting,
+    "\x1b[?1004l"
+);
+
+/// The sequence sent by the terminal when its window gains focus, if [focus reporting](EnableFocusReporting) is
+/// enabled.
+pub const FOCUS_IN: &str = "\x1b[I";
+
+/// The sequence sent by the terminal when its window loses focus, if [focus reporting](EnableFocusReporting) is
+/// enabled.
+pub const FOCUS_OUT: &str = "\x1b[O";
+
+/// A mouse reporting mode.
+///
+/// The tracking modes select which mouse events are reported, and [`SgrEncoding`](MouseMode::SgrEncoding) selects how
+/// they are reported. It is usually enabled along with a tracking mode:
+///
+/// ```
+/// use fluent_ansi::mode::{DisableMouse, EnableMouse, MouseMode};
+///
+/// let enable = format!("{}{}", EnableMouse(MouseMode::ButtonTracking), EnableMouse(MouseMode::SgrEncoding));
+/// assert_eq!(enable, "\x1b[?1000h\x1b[?1006h");
+///
+/// let disable = format!("{}{}", DisableMouse(MouseMode::ButtonTracking), DisableMouse(MouseMode::SgrEncoding));
+/// assert_eq!(disable, "\x1b[?1000l\x1b[?1006l");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseMode {
+    /// Reports button presses and releases (`?1000`).
+    ButtonTracking,
+    /// Reports button presses and releases, and motion while a button is pressed (`?1002`).
+    DragTracking,
+    /// Reports button presses and releases, and all motion (`?1003`).
+    AnyEventTracking,
+    /// Reports the events with the SGR encoding, which supports any screen size (`?1006`).
+    SgrEncoding,
+}
+
+impl MouseMode {
+    fn mode(self) -> u16 {
+        match self {
+            MouseMode::ButtonTracking => 1000,
+            MouseMode::DragTracking => 1002,
+            MouseMode::AnyEventTracking => 1003,
+            MouseMode::SgrEncoding => 1006,
+        }
+    }
+}
+
+/// Enables a mouse reporting mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnableMouse(pub MouseMode);
+
+impl Display for EnableMouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "\x1b[?{}h", self.0.mode())
+    }
+}
+
+/// Disables a mouse reporting mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisableMouse(pub MouseMode);
+
+impl Display for DisableMouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "\x1b[?{}l", self.0.mode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_display;
+
+    use super::*;
+
+    #[test]
+    fn alt_screen() {
+        assert_display!(EnterAltScreen, "\x1b[?1049h");
+        assert_display!(LeaveAltScreen, "\x1b[?1049l");
+    }
+
+    #[test]
+    fn sync() {
+        assert_display!(BeginSync, "\x1b[?2026h");
+        assert_display!(EndSync, "\x1b[?2026l");
+    }
+
+    #[test]
+    fn focus_reporting() {
+        assert_display!(EnableFocusReporting, "\x1b[?1004h");
+        assert_display!(DisableFocusReporting, "\x1b[?1004l");
+    }
+
+    #[test]
+    fn mouse() {
+        assert_display!(EnableMouse(MouseMode::ButtonTracking), "\x1b[?1000h");
+        assert_display!(EnableMouse(MouseMode::DragTracking), "\x1b[?1002h");
+        assert_display!(EnableMouse(MouseMode::AnyEventTracking), "\x1b[?1003h");
+        assert_display!(EnableMouse(MouseMode::SgrEncoding), "\x1b[?1006h");
+        assert_display!(DisableMouse(MouseMode::AnyEventTracking), "\x1b[?1003l");
+    }
+}
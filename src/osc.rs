@@ -0,0 +1,340 @@
+//! Operating System Command (OSC) sequences.
+//!
+//! The values in this module render OSC sequences when formatted with [`Display`], so they can be composed with
+//! styled output:
+//!
+//! ```
+//! use fluent_ansi::osc::WindowTitle;
+//!
+//! assert_eq!(format!("{}", WindowTitle("Build: 3/7")), "\x1b]2;Build: 3/7\x1b\\");
+//! ```
+//!
+//! The sequences are terminated with the String Terminator `ESC \`.
+//!
+//! The terminal's default colors can be queried with [`QueryDefaultColor`], and the terminal's response parsed with
+//! [`DefaultColorReport::parse()`]:
+//!
+//! ```
+//! use fluent_ansi::{color::RGBColor, osc::{DefaultColor, DefaultColorReport, QueryDefaultColor}};
+//!
+//! assert_eq!(format!("{}", QueryDefaultColor(DefaultColor::Background)), "\x1b]11;?\x1b\\");
+//!
+//! // Read from the terminal after writing the query
+//! let response = "\x1b]11;rgb:1e1e/1e1e/2e2e\x1b\\";
+//! let report = DefaultColorReport::parse(response).unwrap();
+//! assert_eq!(report.target, DefaultColor::Background);
+//! assert_eq!(report.color, RGBColor::new(0x1e, 0x1e, 0x2e));
+//! ```
+
+use core::fmt::{Display, Formatter, Result, Write};
+
+use crate::{
+    ParseResponseError,
+    color::{IndexedColor, RGBColor},
+};
+
+/// Sets the terminal window title (OSC 2).
+///
+/// Control characters in the title are not written, so the title can't end the sequence early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowTitle<T: Display>(pub T);
+
+impl<T: Display> Display for WindowTitle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_text_command(f, 2, &self.0)
+    }
+}
+
+/// Sets both the terminal icon name and window title (OSC 0).
+///
+/// Control characters in the title are not written, so the title can't end the sequence early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IconNameAndWindowTitle<T: Display>(pub T);
+
+impl<T: Display> Display for IconNameAndWindowTitle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_text_command(f, 0, &self.0)
+    }
+}
+
+/// Redefines an entry of the terminal's 256-color palette (OSC 4).
+///
+/// ```
+/// use fluent_ansi::{color::{IndexedColor, RGBColor}, osc::SetPaletteColor};
+///
+/// let set = SetPaletteColor::new(IndexedColor(1), RGBColor::new(0xcc, 0x24, 0x1d));
+/// assert_eq!(format!("{set}"), "\x1b]4;1;rgb:cc/24/1d\x1b\\");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SetPaletteColor {
+    /// The palette entry to redefine.
+    pub index: IndexedColor,
+    /// The new color of the entry.
+    pub color: RGBColor,
+}
+
+impl SetPaletteColor {
+    /// Creates a value that redefines the given palette entry to the given color.
+    #[must_use]
+    pub const fn new(index: IndexedColor, color: RGBColor) -> Self {
+        SetPaletteColor { index, color }
+    }
+}
+
+impl Display for SetPaletteColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let RGBColor { r, g, b } = self.color;
+        write!(
+            f,
+            "\x1b]4;{};rgb:{r:02x}/{g:02x}/{b:02x}{ST}",
+            self.index.get_index()
+        )
+    }
+}
+
+/// Resets an entry of the terminal's 256-color palette to its default color (OSC 104).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResetPaletteColor(pub IndexedColor);
+
+impl Display for ResetPaletteColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "\x1b]104;{}{ST}", self.0.get_index())
+    }
+}
+
+/// Resets all entries of the terminal's 256-color palette to their default colors (OSC 104).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResetPalette;
+
+impl Display for ResetPalette {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "\x1b]104{ST}")
+    }
+}
+
+/// A default color of the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DefaultColor {
+    /// The default foreground (text) color, queried with OSC 10.
+    Foreground,
+    /// The default background color, queried with OSC 11.
+    Background,
+}
+
+impl DefaultColor {
+    fn command(self) -> u8 {
+        match self {
+            DefaultColor::Foreground => 10,
+            DefaultColor::Background => 11,
+        }
+    }
+}
+
+/// Queries a default color of the terminal (OSC 10 or OSC 11).
+///
+/// Terminals that support the query respond with a sequence that can be parsed with [`DefaultColorReport::parse()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QueryDefaultColor(pub DefaultColor);
+
+impl Display for QueryDefaultColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "\x1b]{};?{ST}", self.0.command())
+    }
+}
+
+/// The terminal's response to a [`QueryDefaultColor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DefaultColorReport {
+    /// The default color that was queried.
+    pub target: DefaultColor,
+    /// The value of the color.
+    pub color: RGBColor,
+}
+
+impl DefaultColorReport {
+    /// Parses a response like `ESC ] 11 ; rgb:1e1e/1e1e/2e2e ESC \`.
+    ///
+    /// The response can be terminated either with `ESC \` or with `BEL`. The color is parsed with
+    /// [`parse_rgb_spec()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response is not a valid default color report.
+    pub fn parse(response: &str) -> core::result::Result<Self, ParseResponseError> {
+        let body = response.strip_prefix("\x1b]").ok_or(ParseResponseError)?;
+        let body = body
+            .strip_suffix(ST)
+            .or_else(|| body.strip_suffix('\x07'))
+            .ok_or(ParseResponseError)?;
+        let (command, spec) = body.split_once(';').ok_or(ParseResponseError)?;
+        let target = [DefaultColor::Foreground, DefaultColor::Background]
+            .into_iter()
+            .find(|target| command.parse() == Ok(target.command()))
+            .ok_or(ParseResponseError)?;
+        let color = parse_rgb_spec(spec)?;
+        Ok(DefaultColorReport { target, color })
+    }
+}
+
+/// Parses an X11 color specification in the form `rgb:R/G/B`, as used in terminal responses.
+///
+/// Each component has from 1 to 4 hexadecimal digits, and is scaled to the 8-bit range:
+///
+/// ```
+/// use fluent_ansi::{color::RGBColor, osc::parse_rgb_spec};
+///
+/// assert_eq!(parse_rgb_spec("rgb:ffff/8080/0000"), Ok(RGBColor::new(255, 128, 0)));
+/// assert_eq!(parse_rgb_spec("rgb:f/80/000"), Ok(RGBColor::new(255, 128, 0)));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the specification is not in the expected form.
+pub fn parse_rgb_spec(spec: &str) -> core::result::Result<RGBColor, ParseResponseError> {
+    let mut components = spec
+        .strip_prefix("rgb:")
+        .ok_or(ParseResponseError)?
+        .split('/')
+        .map(parse_rgb_component);
+    let mut next = || components.next().ok_or(ParseResponseError)?;
+    let color = RGBColor::new(next()?, next()?, next()?);
+    if components.next().is_some() {
+        return Err(ParseResponseError);
+    }
+    Ok(color)
+}
+
+fn parse_rgb_component(digits: &str) -> core::result::Result<u8, ParseResponseError> {
+    if digits.is_empty() || digits.len() > 4 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseResponseError);
+    }
+    let value = u32::from_str_radix(digits, 16).map_err(|_| ParseResponseError)?;
+    let max = (1 << (4 * digits.len())) - 1;
+    u8::try_from((value * 255 + max / 2) / max).map_err(|_| ParseResponseError)
+}
+
+fn write_text_command(f: &mut Formatter<'_>, command: u8, text: &impl Display) -> Result {
+    write!(f, "\x1b]{command};")?;
+    write!(ControlFilter(f), "{text}")?;
+    f.write_str(ST)
+}
+
+/// The String Terminator, which ends OSC sequences.
+const ST: &str = "\x1b\\";
+
+/// A writer that drops control characters.
+struct ControlFilter<'a, W: Write>(&'a mut W);
+
+impl<W: Write> Write for ControlFilter<'_, W> {
+    fn write_str(&mut self, s: &str) -> Result {
+        for part in s.split(char::is_control) {
+            self.0.write_str(part)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_display;
+
+    use super::*;
+
+    #[test]
+    fn window_title() {
+        assert_display!(WindowTitle("Title"), "\x1b]2;Title\x1b\\");
+        assert_display!(WindowTitle(42), "\x1b]2;42\x1b\\");
+        assert_display!(IconNameAndWindowTitle("Title"), "\x1b]0;Title\x1b\\");
+    }
+
+    #[test]
+    fn palette() {
+        assert_display!(
+            SetPaletteColor::new(IndexedColor(200), RGBColor::new(0, 128, 255)),
+            "\x1b]4;200;rgb:00/80/ff\x1b\\"
+        );
+        assert_display!(ResetPaletteColor(IndexedColor(7)), "\x1b]104;7\x1b\\");
+        assert_display!(ResetPalette, "\x1b]104\x1b\\");
+    }
+
+    #[test]
+    fn query_default_color() {
+        assert_display!(
+            QueryDefaultColor(DefaultColor::Foreground),
+            "\x1b]10;?\x1b\\"
+        );
+        assert_display!(
+            QueryDefaultColor(DefaultColor::Background),
+            "\x1b]11;?\x1b\\"
+        );
+    }
+
+    #[test]
+    fn default_color_report() {
+        assert_eq!(
+            DefaultColorReport::parse("\x1b]10;rgb:0000/8080/ffff\x07"),
+            Ok(DefaultColorReport {
+                target: DefaultColor::Foreground,
+                color: RGBColor::new(0, 128, 255),
+            })
+        );
+        assert_eq!(
+            DefaultColorReport::parse("\x1b]11;rgb:ff/ff/ff\x1b\\"),
+            Ok(DefaultColorReport {
+                target: DefaultColor::Background,
+                color: RGBColor::new(255, 255, 255),
+            })
+        );
+
+        for invalid in [
+            "\x1b]11;rgb:ff/ff/ff",
+            "\x1b]12;rgb:ff/ff/ff\x07",
+            "\x1b]11;?\x07",
+            "]11;rgb:ff/ff/ff\x07",
+        ] {
+            assert_eq!(
+                DefaultColorReport::parse(invalid),
+                Err(ParseResponseError),
+                "{invalid:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn rgb_spec() {
+        assert_eq!(
+            parse_rgb_spec("rgb:1e1e/1e1e/2e2e"),
+            Ok(RGBColor::new(30, 30, 46))
+        );
+        assert_eq!(
+            parse_rgb_spec("rgb:8/80/800"),
+            Ok(RGBColor::new(136, 128, 128))
+        );
+        assert_eq!(parse_rgb_spec("rgb:7fff/0/0"), Ok(RGBColor::new(127, 0, 0)));
+
+        for invalid in [
+            "rgb:ff/ff",
+            "rgb:ff/ff/ff/ff",
+            "rgb:ff//ff",
+            "rgb:fffff/ff/ff",
+            "rgb:+f/ff/ff",
+            "rgb:gg/ff/ff",
+            "rgba:ff/ff/ff",
+            "#ffffff",
+        ] {
+            assert_eq!(
+                parse_rgb_spec(invalid),
+                Err(ParseResponseError),
+                "{invalid:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn control_characters() {
+        assert_display!(
+            WindowTitle("A\x1b\\B\x07C\nD\u{9c}E"),
+            "\x1b]2;A\\BCDE\x1b\\"
+        );
+    }
+}
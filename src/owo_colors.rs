@@ -0,0 +1,282 @@
+//! Conversions from and to [`owo_colors`] types, enabled by the `owo-colors` feature.
+//!
+//! The conversions are lossy in some cases:
+//! - underline colors are dropped;
+//! - the underline styles are all converted to a solid underline;
+//! - the effects with no corresponding `owo-colors` effect, like [`Effect::Overline`], are dropped.
+
+use core::fmt::{self, Write as _};
+
+use owo_colors::{AnsiColors, DynColors};
+
+use crate::{
+    ColorTarget, Effect, Style, StyleSet as _,
+    color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor},
+    sgr,
+};
+
+const EFFECTS: [(Effect, owo_colors::Effect); 9] = [
+    (Effect::Bold, owo_colors::Effect::Bold),
+    (Effect::Faint, owo_colors::Effect::Dimmed),
+    (Effect::Italic, owo_colors::Effect::Italic),
+    (Effect::Underline, owo_colors::Effect::Underline),
+    (Effect::Blink, owo_colors::Effect::Blink),
+    (Effect::RapidBlink, owo_colors::Effect::BlinkFast),
+    (Effect::Reverse, owo_colors::Effect::Reversed),
+    (Effect::Conceal, owo_colors::Effect::Hidden),
+    (Effect::Strikethrough, owo_colors::Effect::Strikethrough),
+];
+
+impl From<Style> for owo_colors::Style {
+    fn from(style: Style) -> Self {
+        let mut result = EFFECTS
+            .iter()
+            .filter(|&&(effect, _)| style.get_effect(effect))
+            .fold(owo_colors::Style::new(), |result, &(_, effect)| {
+                result.effect(effect)
+            });
+        if style.get_underline_style().is_some() {
+            result = result.effect(owo_colors::Effect::Underline);
+        }
+        if let Some(fg) = style.fg {
+            result = result.color(DynColors::from(fg));
+        }
+        if let Some(bg) = style.bg {
+            result = result.on_color(DynColors::from(bg));
+        }
+        result
+    }
+}
+
+/// Converts an `owo-colors` style by parsing the escape sequence it renders.
+impl From<owo_colors::Style> for Style {
+    fn from(style: owo_colors::Style) -> Self {
+        let default_foreground = style == style.color(AnsiColors::Default);
+        let default_background = style == style.on_color(AnsiColors::Default);
+
+        let mut result = parse_prefix(style);
+        if default_foreground {
+            result = result.set_color(ColorTarget::Foreground, Some(Color::Default));
+        }
+        if default_background {
+            result = result.set_color(ColorTarget::Background, Some(Color::Default));
+        }
+        result
+    }
+}
+
+fn parse_prefix(style: owo_colors::Style) -> Style {
+    let mut buffer = PrefixBuffer {
+        bytes: [0; PrefixBuffer::CAPACITY],
+        len: 0,
+    };
+    write!(buffer, "{}", style.prefix_formatter()).expect("the prefix fits in the buffer");
+
+    let prefix = core::str::from_utf8(&buffer.bytes[..buffer.len]).expect("the prefix is ASCII");
+    match prefix
+        .strip_prefix("\x1b[")
+        .and_then(|prefix| prefix.strip_suffix('m'))
+    {
+        Some(params) => sgr::apply_params(Style::new(), params)
+            .expect("the prefix contains supported SGR parameters"),
+        None => Style::new(),
+    }
+}
+
+struct PrefixBuffer {
+    bytes: [u8; Self::CAPACITY],
+    len: usize,
+}
+
+impl PrefixBuffer {
+    // Enough for two RGB colors and all the effects.
+    const CAPACITY: usize = 64;
+}
+
+impl fmt::Write for PrefixBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl From<Color> for DynColors {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Simple(simple) => DynColors::Ansi(simple.into()),
+            Color::Indexed(indexed) => indexed.into(),
+            Color::RGB(rgb) => rgb.into(),
+            Color::Default => DynColors::Ansi(AnsiColors::Default),
+        }
+    }
+}
+
+/// Converts an `owo-colors` color. [CSS colors](owo_colors::CssColors) are converted to RGB colors.
+impl From<DynColors> for Color {
+    fn from(color: DynColors) -> Self {
+        match color {
+            DynColors::Ansi(ansi) => ansi.into(),
+            DynColors::Xterm(xterm) => Color::Indexed(IndexedColor::new(xterm.into())),
+            DynColors::Rgb(r, g, b) => Color::RGB(RGBColor::new(r, g, b)),
+            DynColors::Css(_) => parse_prefix(owo_colors::Style::new().color(color))
+                .fg
+                .expect("a CSS color is rendered as an RGB color"),
+        }
+    }
+}
+
+impl From<AnsiColors> for Color {
+    fn from(color: AnsiColors) -> Self {
+        let (basic_color, bright) = match color {
+            AnsiColors::Default => return Color::Default,
+            AnsiColors::Black => (BasicColor::Black, false),
+            AnsiColors::Red => (BasicColor::Red, false),
+            AnsiColors::Green => (BasicColor::Green, false),
+            AnsiColors::Yellow => (BasicColor::Yellow, false),
+            AnsiColors::Blue => (BasicColor::Blue, false),
+            AnsiColors::Magenta => (BasicColor::Magenta, false),
+            AnsiColors::Cyan => (BasicColor::Cyan, false),
+            AnsiColors::White => (BasicColor::White, false),
+            AnsiColors::BrightBlack => (BasicColor::Black, true),
+            AnsiColors::BrightRed => (BasicColor::Red, true),
+            AnsiColors::BrightGreen => (BasicColor::Green, true),
+            AnsiColors::BrightYellow => (BasicColor::Yellow, true),
+            AnsiColors::BrightBlue => (BasicColor::Blue, true),
+            AnsiColors::BrightMagenta => (BasicColor::Magenta, true),
+            AnsiColors::BrightCyan => (BasicColor::Cyan, true),
+            AnsiColors::BrightWhite => (BasicColor::White, true),
+        };
+        let simple = if bright {
+            SimpleColor::new_bright(basic_color)
+        } else {
+            SimpleColor::new(basic_color)
+        };
+        Color::Simple(simple)
+    }
+}
+
+impl From<BasicColor> for AnsiColors {
+    fn from(color: BasicColor) -> Self {
+        color.to_simple_color().into()
+    }
+}
+
+impl From<SimpleColor> for AnsiColors {
+    fn from(color: SimpleColor) -> Self {
+        match (color.get_basic_color(), color.is_bright()) {
+            (BasicColor::Black, false) => AnsiColors::Black,
+            (BasicColor::Red, false) => AnsiColors::Red,
+            (BasicColor::Green, false) => AnsiColors::Green,
+            (BasicColor::Yellow, false) => AnsiColors::Yellow,
+            (BasicColor::Blue, false) => AnsiColors::Blue,
+            (BasicColor::Magenta, false) => AnsiColors::Magenta,
+            (BasicColor::Cyan, false) => AnsiColors::Cyan,
+            (BasicColor::White, false) => AnsiColors::White,
+            (BasicColor::Black, true) => AnsiColors::BrightBlack,
+            (BasicColor::Red, true) => AnsiColors::BrightRed,
+            (BasicColor::Green, true) => AnsiColors::BrightGreen,
+            (BasicColor::Yellow, true) => AnsiColors::BrightYellow,
+            (BasicColor::Blue, true) => AnsiColors::BrightBlue,
+            (BasicColor::Magenta, true) => AnsiColors::BrightMagenta,
+            (BasicColor::Cyan, true) => AnsiColors::BrightCyan,
+            (BasicColor::White, true) => AnsiColors::BrightWhite,
+        }
+    }
+}
+
+impl From<IndexedColor> for DynColors {
+    fn from(color: IndexedColor) -> Self {
+        DynColors::Xterm(color.get_index().into())
+    }
+}
+
+impl From<RGBColor> for DynColors {
+    fn from(color: RGBColor) -> Self {
+        DynColors::Rgb(color.r, color.g, color.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use owo_colors::CssColors;
+
+    use crate::ToStyleSet as _;
+
+    use super::*;
+
+    #[test]
+    fn style() {
+        let style = Style::new()
+            .bold()
+            .underline()
+            .reverse()
+            .fg(BasicColor::Red.bright())
+            .bg(IndexedColor::new(42));
+        let expected = owo_colors::Style::new()
+            .bold()
+            .underline()
+            .reversed()
+            .bright_red()
+            .on_color(DynColors::Xterm(42.into()));
+
+        assert_eq!(owo_colors::Style::from(style), expected);
+        assert_eq!(Style::from(expected), style);
+    }
+
+    #[test]
+    fn all_effects() {
+        for &(effect, _) in &EFFECTS {
+            let style = Style::new().effect(effect);
+            assert_eq!(Style::from(owo_colors::Style::from(style)), style);
+        }
+    }
+
+    #[test]
+    fn default_colors() {
+        let style = Style::new().fg(Color::Default).bg(Color::Default);
+        assert_eq!(Style::from(owo_colors::Style::from(style)), style);
+
+        let style = Style::new().fg(Color::Default).bg(BasicColor::Blue);
+        assert_eq!(Style::from(owo_colors::Style::from(style)), style);
+    }
+
+    #[test]
+    fn lossy() {
+        assert_eq!(
+            owo_colors::Style::from(
+                Style::new()
+                    .curly_underline()
+                    .overline()
+                    .underline_color(BasicColor::Red)
+            ),
+            owo_colors::Style::new().underline()
+        );
+    }
+
+    #[test]
+    fn colors() {
+        for basic_color in [BasicColor::Black, BasicColor::Green, BasicColor::White] {
+            for simple_color in [basic_color.to_simple_color(), basic_color.bright()] {
+                let color = Color::Simple(simple_color);
+                assert_eq!(Color::from(DynColors::from(color)), color);
+            }
+        }
+        assert_eq!(
+            Color::from(DynColors::from(RGBColor::new(1, 2, 3))),
+            Color::rgb(1, 2, 3)
+        );
+        assert_eq!(
+            Color::from(DynColors::Css(CssColors::Tomato)),
+            Color::rgb(255, 99, 71)
+        );
+        assert_eq!(
+            Style::from(owo_colors::Style::new().color(CssColors::Tomato)),
+            Style::new().fg(Color::rgb(255, 99, 71))
+        );
+    }
+}
@@ -0,0 +1,998 @@
+//! Parsing of text containing ANSI escape sequences.
+//!
+//! A [`Parser`] splits a string into [`Event`]s: runs of plain text and style changes caused by SGR ("Select Graphic
+//! Rendition") escape sequences. The parser keeps track of the current style, so each [`Event::Style`] carries the
+//! complete style in effect after the sequence, not just the changes it contains.
+//!
+//! ```
+//! use fluent_ansi::{prelude::*, Style, parse::{Event, Parser}};
+//!
+//! let events: Vec<_> = Parser::new("plain \x1b[1;31mbold red\x1b[22m red\x1b[0m")
+//!     .collect::<Result<_, _>>()
+//!     .unwrap();
+//!
+//! assert_eq!(events, [
+//!     Event::Text("plain "),
+//!     Event::Style(Color::RED.bold()),
+//!     Event::Text("bold red"),
+//!     Event::Style(Style::new().fg(Color::RED)),
+//!     Event::Text(" red"),
+//!     Event::Style(Style::new()),
+//! ]);
+//! ```
+//!
+//! To process the text in a single pass, like to transform captured output, [`visit_ansi()`] calls back a [`Visitor`]
+//! for each run of text and each escape sequence.
+//!
+//! For streams, like the output of a process read from a PTY, a [`PushParser`] accepts the text in chunks of bytes,
+//! which may split escape sequences.
+
+use core::{
+    error::Error,
+    fmt::{Display, Formatter},
+    iter::FusedIterator,
+    ops::{ControlFlow, Range},
+};
+
+use crate::{ParseStyleError, ParseStyleErrorKind, Style, sgr, width::EscapeState};
+
+const ESC: char = '\x1b';
+
+/// An event produced by a [`Parser`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event<'a> {
+    /// A run of text without escape sequences.
+    Text(&'a str),
+    /// A style change. The value is the complete style in effect after the change.
+    Style(Style),
+    /// An escape sequence that is malformed, incomplete or unsupported, produced instead of an error in
+    /// [lossy](Parser::lossy) mode. The value is the raw text of the sequence.
+    Unknown(&'a str),
+}
+
+/// The error returned when a [`Parser`] or a [`PushParser`] finds an escape sequence that is malformed, incomplete or
+/// unsupported.
+///
+/// Besides the [kind](ParseError::kind) of the error, it tells the [span](ParseError::span) of the offending escape
+/// sequence and, for an invalid SGR parameter, the [error](ParseError::parameter_error) that points at the parameter:
+///
+/// ```
+/// use fluent_ansi::{ParseStyleErrorKind, parse::{ParseErrorKind, Parser}};
+///
+/// let text = "ok \x1b[1;38;5mbold";
+/// let error = Parser::new(text).find_map(Result::err).unwrap();
+/// assert_eq!(error.kind(), ParseErrorKind::InvalidParameter);
+/// assert_eq!(&text[error.span()], "\x1b[1;38;5m");
+///
+/// let parameter_error = error.parameter_error().unwrap();
+/// assert_eq!(parameter_error.kind(), ParseStyleErrorKind::IncompleteColor);
+/// assert_eq!(&text[parameter_error.span()], "38;5");
+/// assert_eq!(error.to_string(), "incomplete color at 7..11 in escape sequence at 3..12");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    start: usize,
+    end: usize,
+    parameter_error: Option<ParseStyleError>,
+}
+
+impl ParseError {
+    const fn new(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        Self {
+            kind,
+            start: span.start,
+            end: span.end,
+            parameter_error: None,
+        }
+    }
+
+    /// Creates an [`ParseErrorKind::InvalidParameter`] error, where the span of `error` is relative to the parameters
+    /// of the sequence, which start at `params_start`.
+    const fn invalid_parameter(
+        span: Range<usize>,
+        params_start: usize,
+        error: ParseStyleError,
+    ) -> Self {
+        Self {
+            parameter_error: Some(error.offset_by(params_start)),
+            ..Self::new(ParseErrorKind::InvalidParameter, span)
+        }
+    }
+
+    /// Gets the kind of the error.
+    #[must_use]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Gets the byte range of the offending escape sequence.
+    ///
+    /// For a [`Parser`], the range is in the parsed text. For a [`PushParser`], it is in the stream of bytes fed to
+    /// the parser, so it may extend over more than one chunk.
+    #[must_use]
+    pub const fn span(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Gets the error about the offending parameter of an SGR sequence, if that is the kind of the error.
+    ///
+    /// Its span is in the same coordinates as [`span()`](ParseError::span).
+    #[must_use]
+    pub const fn parameter_error(&self) -> Option<ParseStyleError> {
+        self.parameter_error
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.parameter_error {
+            Some(error) => write!(
+                f,
+                "{error} in escape sequence at {}..{}",
+                self.start, self.end
+            ),
+            None => write!(f, "{} at {}..{}", self.kind, self.start, self.end),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The kind of a [`ParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// The text ends before the escape sequence is complete.
+    Incomplete,
+    /// The escape sequence is not well-formed.
+    Malformed,
+    /// The escape sequence is well-formed, but it is not an SGR sequence.
+    Unsupported,
+    /// The SGR sequence has a parameter that is invalid or not supported.
+    InvalidParameter,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ParseErrorKind::Incomplete => "incomplete escape sequence",
+            ParseErrorKind::Malformed => "malformed escape sequence",
+            ParseErrorKind::Unsupported => "unsupported escape sequence",
+            ParseErrorKind::InvalidParameter => "invalid SGR parameter",
+        })
+    }
+}
+
+/// An iterator over the [`Event`]s of a string containing ANSI escape sequences.
+///
+/// Only SGR sequences are supported. Any other escape sequence, as well as an SGR sequence with an invalid or
+/// unsupported parameter, results in an error, after which the iteration ends.
+///
+/// In [lossy](Parser::lossy) mode, those sequences are skipped instead, and reported as [`Event::Unknown`]. This is
+/// useful for captured output, which may contain all kinds of sequences:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, Style, parse::{Event, Parser}};
+///
+/// let events: Vec<_> = Parser::new("\x1b[2J\x1b[1mtitle\x1b]0;x\x07")
+///     .lossy(true)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// assert_eq!(events, [
+///     Event::Unknown("\x1b[2J"),
+///     Event::Style(Style::new().bold()),
+///     Event::Text("title"),
+///     Event::Unknown("\x1b]0;x\x07"),
+/// ]);
+/// ```
+///
+/// A lossy parser never produces an error.
+#[derive(Debug, Clone)]
+pub struct Parser<'a> {
+    rest: &'a str,
+    offset: usize,
+    style: Style,
+    lossy: bool,
+    failed: bool,
+}
+
+impl<'a> Parser<'a> {
+    /// Creates a parser over the given text. The initial style is empty.
+    #[must_use]
+    pub const fn new(text: &'a str) -> Self {
+        Parser {
+            rest: text,
+            offset: 0,
+            style: Style::new(),
+            lossy: false,
+            failed: false,
+        }
+    }
+
+    /// Returns a new parser with the lossy mode enabled or disabled.
+    ///
+    /// In lossy mode, the escape sequences that would result in an error are reported as [`Event::Unknown`], and the
+    /// parsing continues after them.
+    #[must_use]
+    pub const fn lossy(self, enabled: bool) -> Self {
+        Self {
+            lossy: enabled,
+            ..self
+        }
+    }
+
+    /// Returns whether the lossy mode is enabled.
+    #[must_use]
+    pub const fn is_lossy(&self) -> bool {
+        self.lossy
+    }
+
+    /// Returns the style in effect at the current position.
+    #[must_use]
+    pub const fn style(&self) -> Style {
+        self.style
+    }
+}
+
+impl<'a> Iterator for Parser<'a> {
+    type Item = Result<Event<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.rest.is_empty() {
+            return None;
+        }
+
+        if !self.rest.starts_with(ESC) {
+            let len = self.rest.find(ESC).unwrap_or(self.rest.len());
+            let (text, rest) = self.rest.split_at(len);
+            self.rest = rest;
+            self.offset += len;
+            return Some(Ok(Event::Text(text)));
+        }
+
+        let start = self.offset;
+        let result = match split_sequence(self.rest) {
+            Some((Sequence::Sgr(params), rest)) => {
+                let span = start..start + self.rest.len() - rest.len();
+                match sgr::apply_params(self.style, params) {
+                    Ok(style) => {
+                        self.rest = rest;
+                        self.offset = span.end;
+                        self.style = style;
+                        Ok(Event::Style(style))
+                    }
+                    Err(error) => Err(ParseError::invalid_parameter(span, start + 2, error)),
+                }
+            }
+            Some((Sequence::Other, rest)) => Err(ParseError::new(
+                ParseErrorKind::Unsupported,
+                start..start + self.rest.len() - rest.len(),
+            )),
+            None => Err(sequence_error(self.rest, start)),
+        };
+        match result {
+            Err(error) if self.lossy => {
+                let (raw, rest) = self.rest.split_at(error.end - start);
+                self.rest = rest;
+                self.offset = error.end;
+                Some(Ok(Event::Unknown(raw)))
+            }
+            result => {
+                self.failed = result.is_err();
+                Some(result)
+            }
+        }
+    }
+}
+
+impl FusedIterator for Parser<'_> {}
+
+/// Gets the error for a text, found at `offset`, that doesn't start with a well-formed and complete escape sequence.
+fn sequence_error(text: &str, offset: usize) -> ParseError {
+    let mut state = EscapeState::Text;
+    for (index, c) in text.char_indices() {
+        // An ESC is not expected in the sequence, so it is assumed to start the next one
+        if c == ESC && matches!(state, EscapeState::Escape | EscapeState::Csi) {
+            return ParseError::new(ParseErrorKind::Malformed, offset..offset + index);
+        }
+        state.advance(c);
+        if state == EscapeState::Text {
+            let end = offset + index + c.len_utf8();
+            return ParseError::new(ParseErrorKind::Malformed, offset..end);
+        }
+    }
+    ParseError::new(ParseErrorKind::Incomplete, offset..offset + text.len())
+}
+
+/// A set of callbacks for the parts of a text containing ANSI escape sequences.
+///
+/// See [`visit_ansi()`]. All the methods do nothing by default, so a visitor implements only the ones it needs. Each
+/// of them can stop the visit by returning [`ControlFlow::Break`].
+pub trait Visitor {
+    /// Called for a run of text without escape sequences.
+    fn visit_text(&mut self, text: &str) -> ControlFlow<()> {
+        let _ = text;
+        ControlFlow::Continue(())
+    }
+
+    /// Called for an SGR sequence, with the complete style in effect after it, and the sequence itself.
+    fn visit_style(&mut self, style: Style, raw: &str) -> ControlFlow<()> {
+        let _ = (style, raw);
+        ControlFlow::Continue(())
+    }
+
+    /// Called for an OSC sequence, like a hyperlink or a window title, with its content between the `ESC ]`
+    /// introducer and the terminator, and the sequence itself.
+    fn visit_osc(&mut self, content: &str, raw: &str) -> ControlFlow<()> {
+        let _ = (content, raw);
+        ControlFlow::Continue(())
+    }
+
+    /// Called for any other escape sequence, for an SGR sequence with an invalid or unsupported parameter, and for an
+    /// `ESC` character that doesn't start a well-formed and complete sequence.
+    fn visit_unknown(&mut self, raw: &str) -> ControlFlow<()> {
+        let _ = raw;
+        ControlFlow::Continue(())
+    }
+}
+
+/// Walks a text containing ANSI escape sequences, calling back the visitor for each of its parts, in order.
+///
+/// The style passed to [`Visitor::visit_style()`] is tracked from the initial empty style, as in [`Parser`]. No
+/// intermediate collection is built, which makes it suitable for transforming or measuring captured output:
+///
+/// ```
+/// use core::ops::ControlFlow;
+/// use fluent_ansi::{prelude::*, Style, parse::{Visitor, visit_ansi}};
+///
+/// #[derive(Default)]
+/// struct BoldText(String);
+///
+/// impl Visitor for BoldText {
+///     fn visit_style(&mut self, style: Style, _raw: &str) -> ControlFlow<()> {
+///         self.0.push(if style.get_effect(Effect::Bold) { '[' } else { ']' });
+///         ControlFlow::Continue(())
+///     }
+///
+///     fn visit_text(&mut self, text: &str) -> ControlFlow<()> {
+///         self.0.push_str(text);
+///         ControlFlow::Continue(())
+///     }
+/// }
+///
+/// let mut visitor = BoldText::default();
+/// visit_ansi(&format!("a {} c\x1b]0;title\x07", Effect::Bold.applied_to("b")), &mut visitor);
+/// assert_eq!(visitor.0, "a [b] c");
+/// ```
+///
+/// Returns [`ControlFlow::Break`] if the visitor stopped the visit.
+pub fn visit_ansi(text: &str, visitor: &mut impl Visitor) -> ControlFlow<()> {
+    let mut style = Style::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        if !rest.starts_with(ESC) {
+            let len = rest[1..].find(ESC).map_or(rest.len(), |index| index + 1);
+            let (text, tail) = rest.split_at(len);
+            rest = tail;
+            visitor.visit_text(text)?;
+            continue;
+        }
+
+        let Some((sequence, tail)) = split_sequence(rest) else {
+            let (esc, tail) = rest.split_at(ESC.len_utf8());
+            rest = tail;
+            visitor.visit_unknown(esc)?;
+            continue;
+        };
+        let raw = &rest[..rest.len() - tail.len()];
+        rest = tail;
+        match sequence {
+            Sequence::Sgr(params) => match sgr::apply_params(style, params) {
+                Ok(applied) => {
+                    style = applied;
+                    visitor.visit_style(style, raw)?;
+                }
+                Err(_) => visitor.visit_unknown(raw)?,
+            },
+            Sequence::Other => match raw.strip_prefix("\x1b]") {
+                Some(osc) => {
+                    let content = osc
+                        .strip_suffix('\x07')
+                        .or_else(|| osc.strip_suffix("\x1b\\"))
+                        .unwrap_or(osc);
+                    visitor.visit_osc(content, raw)?;
+                }
+                None => visitor.visit_unknown(raw)?,
+            },
+        }
+    }
+    ControlFlow::Continue(())
+}
+
+/// An event produced by a [`PushParser`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PushEvent<'a> {
+    /// A run of bytes without escape sequences, from a single chunk.
+    Text(&'a [u8]),
+    /// A style change. The value is the complete style in effect after the change.
+    Style(Style),
+}
+
+/// An incremental parser that accepts text in chunks of bytes, as they arrive from a stream.
+///
+/// Escape sequences may be split across chunks: the parser keeps the state of an incomplete sequence until the chunk
+/// that completes it is fed. The events of each chunk are produced by the iterator returned by
+/// [`feed()`](PushParser::feed):
+///
+/// ```
+/// use fluent_ansi::{prelude::*, parse::{PushEvent, PushParser}};
+///
+/// let mut parser = PushParser::new();
+/// let first: Vec<_> = parser.feed(b"plain \x1b[1;3").collect();
+/// assert_eq!(first, [Ok(PushEvent::Text(b"plain "))]);
+///
+/// let second: Vec<_> = parser.feed(b"1mbold red").collect();
+/// assert_eq!(second, [Ok(PushEvent::Style(Color::RED.bold())), Ok(PushEvent::Text(b"bold red"))]);
+/// ```
+///
+/// Text is passed through as bytes, so a multi-byte UTF-8 character may also be split across chunks. Like in
+/// [`Parser`], only SGR sequences are supported, and any other escape sequence results in an error. Unlike in
+/// [`Parser`], parsing continues after the sequence that caused the error.
+#[derive(Debug, Clone)]
+pub struct PushParser {
+    style: Style,
+    state: EscapeState,
+    params: [u8; Style::MAX_RENDERED_LEN],
+    params_len: usize,
+    position: usize,
+    sequence_start: usize,
+    params_start: usize,
+}
+
+impl PushParser {
+    /// Creates a new parser. The initial style is empty.
+    #[must_use]
+    pub const fn new() -> Self {
+        PushParser {
+            style: Style::new(),
+            state: EscapeState::Text,
+            params: [0; Style::MAX_RENDERED_LEN],
+            params_len: 0,
+            position: 0,
+            sequence_start: 0,
+            params_start: 0,
+        }
+    }
+
+    /// Returns the style in effect at the current position.
+    #[must_use]
+    pub const fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns whether an escape sequence was started in a previous chunk and is not complete yet.
+    #[must_use]
+    pub fn is_in_sequence(&self) -> bool {
+        self.state != EscapeState::Text
+    }
+
+    /// Feeds a chunk of bytes, returning an iterator over its events.
+    ///
+    /// The chunk must be consumed through the iterator before the next one is fed. Otherwise, its remaining bytes are
+    /// ignored.
+    pub fn feed<'a>(&'a mut self, chunk: &'a [u8]) -> PushEvents<'a> {
+        PushEvents {
+            parser: self,
+            rest: chunk,
+        }
+    }
+
+    /// Advances the state with a byte of an escape sequence, returning the result of the sequence if it is complete.
+    fn advance(&mut self, byte: u8) -> Option<Result<Style, ParseError>> {
+        let position = self.position;
+        self.position += 1;
+        let previous = self.state;
+        self.state.advance(char::from(byte));
+        let error = |kind| ParseError::new(kind, self.sequence_start..self.position);
+        match (previous, self.state) {
+            (EscapeState::Text, _) => {
+                self.sequence_start = position;
+                None
+            }
+            (EscapeState::Escape, EscapeState::Csi) => {
+                self.params_len = 0;
+                self.params_start = self.position;
+                None
+            }
+            (EscapeState::Csi, EscapeState::Csi) => {
+                // Parameters that don't fit can't be applied, so the overflow is only recorded in the length
+                if let Some(slot) = self.params.get_mut(self.params_len) {
+                    *slot = byte;
+                }
+                self.params_len += 1;
+                None
+            }
+            (EscapeState::Csi, EscapeState::Text) => {
+                let is_private = self.params_len > 0 && matches!(self.params[0], b'<'..=b'?');
+                if byte != b'm' || is_private {
+                    return Some(Err(error(ParseErrorKind::Unsupported)));
+                }
+                let span = self.sequence_start..self.position;
+                let Some(params) = self.params.get(..self.params_len) else {
+                    let error = ParseStyleError::new(
+                        ParseStyleErrorKind::InvalidParameter,
+                        0..self.params_len,
+                    );
+                    return Some(Err(ParseError::invalid_parameter(
+                        span,
+                        self.params_start,
+                        error,
+                    )));
+                };
+                let Ok(params) = core::str::from_utf8(params) else {
+                    return Some(Err(error(ParseErrorKind::Malformed)));
+                };
+                match sgr::apply_params(self.style, params) {
+                    Ok(style) => {
+                        self.style = style;
+                        Some(Ok(style))
+                    }
+                    Err(error) => Some(Err(ParseError::invalid_parameter(
+                        span,
+                        self.params_start,
+                        error,
+                    ))),
+                }
+            }
+            (EscapeState::Escape, EscapeState::Text) if !(0x30..=0x7e).contains(&byte) => {
+                Some(Err(error(ParseErrorKind::Malformed)))
+            }
+            (_, EscapeState::Text) => Some(Err(error(ParseErrorKind::Unsupported))),
+            _ => None,
+        }
+    }
+}
+
+impl Default for PushParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator over the [`PushEvent`]s of a chunk fed to a [`PushParser`].
+///
+/// It is returned by [`PushParser::feed()`].
+#[derive(Debug)]
+pub struct PushEvents<'a> {
+    parser: &'a mut PushParser,
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for PushEvents<'a> {
+    type Item = Result<PushEvent<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.parser.state == EscapeState::Text {
+                let len = self
+                    .rest
+                    .iter()
+                    .position(|&byte| byte == 0x1b)
+                    .unwrap_or(self.rest.len());
+                if len > 0 {
+                    let (text, rest) = self.rest.split_at(len);
+                    self.rest = rest;
+                    self.parser.position += len;
+                    return Some(Ok(PushEvent::Text(text)));
+                }
+            }
+
+            let (&byte, rest) = self.rest.split_first()?;
+            self.rest = rest;
+            if let Some(result) = self.parser.advance(byte) {
+                return Some(result.map(PushEvent::Style));
+            }
+        }
+    }
+}
+
+impl FusedIterator for PushEvents<'_> {}
+
+/// An escape sequence recognized by [`split_sequence()`].
+#[derive(Clone, Copy)]
+pub(crate) enum Sequence<'a> {
+    /// An SGR sequence, with its parameters.
+    Sgr(&'a str),
+    /// Any other well-formed escape sequence.
+    Other,
+}
+
+/// Splits the escape sequence at the start of `text` from the text that follows it.
+///
+/// Returns `None` if `text` doesn't start with a well-formed and complete escape sequence.
+pub(crate) fn split_sequence(text: &str) -> Option<(Sequence<'_>, &str)> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&0x1b) {
+        return None;
+    }
+
+    let len = match *bytes.get(1)? {
+        b'[' => {
+            let params_len = count_bytes(&bytes[2..], 0x30..=0x3f);
+            let intermediates_len = count_bytes(&bytes[2 + params_len..], 0x20..=0x2f);
+            let final_index = 2 + params_len + intermediates_len;
+            let final_byte = *bytes.get(final_index)?;
+            if !(0x40..=0x7e).contains(&final_byte) {
+                return None;
+            }
+
+            let params = &text[2..2 + params_len];
+            let is_sgr = final_byte == b'm'
+                && intermediates_len == 0
+                && !params.starts_with(['<', '=', '>', '?']);
+            if is_sgr {
+                return Some((Sequence::Sgr(params), &text[final_index + 1..]));
+            }
+            final_index + 1
+        }
+        b']' | b'P' | b'X' | b'^' | b'_' => {
+            let bel_allowed = bytes[1] == b']';
+            let content = &bytes[2..];
+            let end = content
+                .iter()
+                .enumerate()
+                .find_map(|(i, &byte)| match byte {
+                    0x07 if bel_allowed => Some(i + 1),
+                    0x1b if content.get(i + 1) == Some(&b'\\') => Some(i + 2),
+                    _ => None,
+                })?;
+            2 + end
+        }
+        _ => {
+            let intermediates_len = count_bytes(&bytes[1..], 0x20..=0x2f);
+            let final_byte = *bytes.get(1 + intermediates_len)?;
+            if !(0x30..=0x7e).contains(&final_byte) {
+                return None;
+            }
+            2 + intermediates_len
+        }
+    };
+
+    Some((Sequence::Other, &text[len..]))
+}
+
+fn count_bytes(bytes: &[u8], range: core::ops::RangeInclusive<u8>) -> usize {
+    bytes.iter().take_while(|byte| range.contains(byte)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ToStyleSet as _, color::BasicColor};
+
+    use super::*;
+
+    fn events(text: &str) -> Vec<Result<Event<'_>, ParseError>> {
+        Parser::new(text).collect()
+    }
+
+    #[test]
+    fn text_only() {
+        assert_eq!(events(""), []);
+        assert_eq!(events("plain text"), [Ok(Event::Text("plain text"))]);
+    }
+
+    #[test]
+    fn style_changes() {
+        let red = Style::new().fg(BasicColor::Red);
+        assert_eq!(
+            events("\x1b[31mred\x1b[1m\x1b[mplain"),
+            [
+                Ok(Event::Style(red)),
+                Ok(Event::Text("red")),
+                Ok(Event::Style(red.bold())),
+                Ok(Event::Style(Style::new())),
+                Ok(Event::Text("plain")),
+            ]
+        );
+    }
+
+    #[test]
+    fn current_style() {
+        let mut parser = Parser::new("\x1b[4mA\x1b[38;5;42mB");
+        assert_eq!(parser.style(), Style::new());
+        parser.next();
+        assert_eq!(parser.style(), Style::new().underline());
+        parser.nth(1);
+        assert_eq!(
+            parser.style(),
+            Style::new().underline().fg(crate::color::IndexedColor(42))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let error = |kind, span| Err(ParseError::new(kind, span));
+
+        assert_eq!(
+            events("A\x1b[2JB"),
+            [
+                Ok(Event::Text("A")),
+                error(ParseErrorKind::Unsupported, 1..5)
+            ]
+        );
+        assert_eq!(
+            events("\x1b]0;title\x07"),
+            [error(ParseErrorKind::Unsupported, 0..10)]
+        );
+        assert_eq!(
+            events("\x1b[?25m"),
+            [error(ParseErrorKind::Unsupported, 0..6)]
+        );
+        assert_eq!(
+            events("A\x1b[31"),
+            [
+                Ok(Event::Text("A")),
+                error(ParseErrorKind::Incomplete, 1..5)
+            ]
+        );
+        assert_eq!(events("\x1b"), [error(ParseErrorKind::Incomplete, 0..1)]);
+        assert_eq!(
+            events("\x1b[1\x07mB"),
+            [error(ParseErrorKind::Malformed, 0..5)]
+        );
+        assert_eq!(
+            events("\x1b\x01B"),
+            [error(ParseErrorKind::Malformed, 0..2)]
+        );
+        assert_eq!(
+            events("\x1b[1\x1b[mB"),
+            [error(ParseErrorKind::Malformed, 0..3)]
+        );
+    }
+
+    #[test]
+    fn lossy() {
+        let events = |text| Parser::new(text).lossy(true).collect::<Vec<_>>();
+
+        assert!(Parser::new("").lossy(true).is_lossy());
+        assert!(!Parser::new("").is_lossy());
+        assert_eq!(
+            events("A\x1b[2JB\x1b[1;99mC\x1b\x1b[1mD\x1b[3"),
+            [
+                Ok(Event::Text("A")),
+                Ok(Event::Unknown("\x1b[2J")),
+                Ok(Event::Text("B")),
+                Ok(Event::Unknown("\x1b[1;99m")),
+                Ok(Event::Text("C")),
+                Ok(Event::Unknown("\x1b")),
+                Ok(Event::Style(Style::new().bold())),
+                Ok(Event::Text("D")),
+                Ok(Event::Unknown("\x1b[3")),
+            ]
+        );
+        assert_eq!(
+            events("\x1b[1\x07mB"),
+            [Ok(Event::Unknown("\x1b[1\x07m")), Ok(Event::Text("B"))]
+        );
+    }
+
+    #[test]
+    fn parameter_errors() {
+        let result = events("A\x1b[1;99mB");
+        let [Ok(Event::Text("A")), Err(error)] = result.as_slice() else {
+            panic!("{result:?}");
+        };
+        assert_eq!(error.kind(), ParseErrorKind::InvalidParameter);
+        assert_eq!(error.span(), 1..8);
+        assert_eq!(
+            error.parameter_error(),
+            Some(ParseStyleError::new(
+                ParseStyleErrorKind::InvalidParameter,
+                5..7
+            ))
+        );
+        assert_eq!(
+            error.to_string(),
+            "invalid parameter at 5..7 in escape sequence at 1..8"
+        );
+        assert_eq!(
+            ParseError::new(ParseErrorKind::Unsupported, 0..3).to_string(),
+            "unsupported escape sequence at 0..3"
+        );
+    }
+
+    #[test]
+    fn sequences() {
+        fn split(text: &str) -> Option<&str> {
+            split_sequence(text).map(|(_, rest)| rest)
+        }
+
+        assert!(matches!(
+            split_sequence("\x1b[1;2mX"),
+            Some((Sequence::Sgr("1;2"), "X"))
+        ));
+        assert_eq!(split("\x1b[2JX"), Some("X"));
+        assert_eq!(split("\x1b[?25hX"), Some("X"));
+        assert_eq!(split("\x1b[1 qX"), Some("X"));
+        assert_eq!(split("\x1b]0;title\x07X"), Some("X"));
+        assert_eq!(split("\x1b]8;;http://x\x1b\\X"), Some("X"));
+        assert_eq!(split("\x1bP1$r\x1b\\X"), Some("X"));
+        assert_eq!(split("\x1b7X"), Some("X"));
+        assert_eq!(split("\x1b(BX"), Some("X"));
+
+        assert_eq!(split("X"), None);
+        assert_eq!(split("\x1b[1;2"), None);
+        assert_eq!(split("\x1b[1\x07m"), None);
+        assert_eq!(split("\x1b]0;title"), None);
+        assert_eq!(split("\x1bP1$r\x07"), None);
+        assert_eq!(split("\x1b("), None);
+    }
+
+    /// Feeds the chunks, merging the text of adjacent events.
+    fn push_events(chunks: &[&[u8]]) -> Vec<Result<(Vec<u8>, Style), ParseError>> {
+        let mut parser = PushParser::new();
+        let mut events: Vec<Result<(Vec<u8>, Style), ParseError>> = Vec::new();
+        for chunk in chunks {
+            for event in parser.feed(chunk) {
+                match (event, events.last_mut()) {
+                    (Ok(PushEvent::Text(text)), Some(Ok((last, _)))) if !last.is_empty() => {
+                        last.extend_from_slice(text);
+                    }
+                    (Ok(PushEvent::Text(text)), _) => {
+                        events.push(Ok((text.to_vec(), parser_style(&events))));
+                    }
+                    (Ok(PushEvent::Style(style)), _) => events.push(Ok((Vec::new(), style))),
+                    (Err(error), _) => events.push(Err(error)),
+                }
+            }
+        }
+        events
+    }
+
+    fn parser_style(events: &[Result<(Vec<u8>, Style), ParseError>]) -> Style {
+        events
+            .iter()
+            .rev()
+            .find_map(|event| event.as_ref().ok().map(|&(_, style)| style))
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn push_parser() {
+        let text = "A\x1b[1;38;2;1;2;3mBé\x1b]8;;url\x1b\\C\x1b[2JD\x1b[m";
+        let bold_rgb = Style::new().bold().fg(crate::color::RGBColor::new(1, 2, 3));
+        let expected = [
+            Ok((b"A".to_vec(), Style::new())),
+            Ok((Vec::new(), bold_rgb)),
+            Ok(("Bé".as_bytes().to_vec(), bold_rgb)),
+            Err(ParseError::new(ParseErrorKind::Unsupported, 19..29)),
+            Ok((b"C".to_vec(), bold_rgb)),
+            Err(ParseError::new(ParseErrorKind::Unsupported, 30..34)),
+            Ok((b"D".to_vec(), bold_rgb)),
+            Ok((Vec::new(), Style::new())),
+        ];
+
+        let bytes = text.as_bytes();
+        assert_eq!(push_events(&[bytes]), expected);
+        for i in 0..=bytes.len() {
+            let (a, b) = bytes.split_at(i);
+            assert_eq!(push_events(&[a, b]), expected, "split at {i}");
+        }
+        let single_bytes: Vec<&[u8]> = bytes.chunks(1).collect();
+        assert_eq!(push_events(&single_bytes), expected);
+    }
+
+    #[test]
+    fn push_parser_state() {
+        let mut parser = PushParser::default();
+        assert_eq!(parser.feed(b"\x1b[3").count(), 0);
+        assert!(parser.is_in_sequence());
+        assert_eq!(
+            parser.feed(b"m").collect::<Vec<_>>(),
+            [Ok(PushEvent::Style(Style::new().italic()))]
+        );
+        assert!(!parser.is_in_sequence());
+        assert_eq!(parser.style(), Style::new().italic());
+
+        assert_eq!(
+            parser
+                .feed(b"\x1b[?25m\x1b[1;99m\x1b\x01")
+                .collect::<Vec<_>>(),
+            [
+                Err(ParseError::new(ParseErrorKind::Unsupported, 4..10)),
+                Err(ParseError::invalid_parameter(
+                    10..17,
+                    12,
+                    ParseStyleError::new(ParseStyleErrorKind::InvalidParameter, 2..4)
+                )),
+                Err(ParseError::new(ParseErrorKind::Malformed, 17..19)),
+            ]
+        );
+        assert_eq!(parser.style(), Style::new().italic());
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        calls: Vec<String>,
+        stop_at: Option<usize>,
+    }
+
+    impl Recorder {
+        fn record(&mut self, call: String) -> ControlFlow<()> {
+            self.calls.push(call);
+            if self.stop_at == Some(self.calls.len()) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    impl Visitor for Recorder {
+        fn visit_text(&mut self, text: &str) -> ControlFlow<()> {
+            self.record(format!("text {text:?}"))
+        }
+
+        fn visit_style(&mut self, style: Style, raw: &str) -> ControlFlow<()> {
+            self.record(format!("style {} {raw:?}", style.to_spec()))
+        }
+
+        fn visit_osc(&mut self, content: &str, raw: &str) -> ControlFlow<()> {
+            self.record(format!("osc {content:?} {raw:?}"))
+        }
+
+        fn visit_unknown(&mut self, raw: &str) -> ControlFlow<()> {
+            self.record(format!("unknown {raw:?}"))
+        }
+    }
+
+    #[test]
+    fn visit() {
+        let mut recorder = Recorder::default();
+        let text = "a\x1b[1mb\x1b[31;99m\x1b]8;;url\x1b\\c\x1b]0;t\x07\x1b[2J\x1b[3md\x1b[";
+        assert_eq!(visit_ansi(text, &mut recorder), ControlFlow::Continue(()));
+        assert_eq!(
+            recorder.calls,
+            [
+                r#"text "a""#,
+                r#"style bold "\u{1b}[1m""#,
+                r#"text "b""#,
+                r#"unknown "\u{1b}[31;99m""#,
+                r#"osc "8;;url" "\u{1b}]8;;url\u{1b}\\""#,
+                r#"text "c""#,
+                r#"osc "0;t" "\u{1b}]0;t\u{7}""#,
+                r#"unknown "\u{1b}[2J""#,
+                r#"style bold italic "\u{1b}[3m""#,
+                r#"text "d""#,
+                r#"unknown "\u{1b}""#,
+                r#"text "[""#,
+            ]
+        );
+
+        let mut recorder = Recorder::default();
+        assert_eq!(visit_ansi("", &mut recorder), ControlFlow::Continue(()));
+        assert!(recorder.calls.is_empty());
+    }
+
+    #[test]
+    fn visit_break() {
+        struct Nothing;
+        impl Visitor for Nothing {}
+
+        let mut recorder = Recorder {
+            stop_at: Some(2),
+            ..Recorder::default()
+        };
+        assert_eq!(
+            visit_ansi("a\x1b[1mb\x1b[0m", &mut recorder),
+            ControlFlow::Break(())
+        );
+        assert_eq!(recorder.calls.len(), 2);
+
+        assert_eq!(
+            visit_ansi("a\x1b[1m\x1b]0;t\x07\x1b[2J", &mut Nothing),
+            ControlFlow::Continue(())
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use core::fmt::{Display, Formatter, Result, Write};
+
+use crate::{
+    Styled,
+    width::{EscapeState, visible_width, write_padded},
+};
+
+/// A value that renders the content of a [`Styled<C>`] value with no escape sequences.
+///
+/// It is returned by [`Styled::plain()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Plain<T>(T);
+
+impl<C: Display> Styled<C> {
+    /// Returns a value that renders only the content, with no escape sequences.
+    ///
+    /// Escape sequences rendered by the content itself, like the ones of nested [`Styled<C>`] values, are also
+    /// stripped. Width, fill and alignment are honored as in the styled rendering. This allows the same value to be
+    /// written both to a terminal and to a plain text destination, like a log file:
+    ///
+    /// ```
+    /// use fluent_ansi::prelude::*;
+    ///
+    /// let styled = Color::RED.bold().applied_to("Error");
+    /// assert_eq!(format!("{styled}"), "\x1b[1;31mError\x1b[0m");
+    /// assert_eq!(format!("{}", styled.plain()), "Error");
+    /// assert_eq!(format!("[{:>7}]", styled.plain()), "[  Error]");
+    /// ```
+    #[must_use]
+    pub const fn plain(&self) -> Plain<&Styled<C>> {
+        Plain(self)
+    }
+}
+
+impl<C: Display> Display for Plain<&Styled<C>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let content = self.0.get_content();
+        if f.width().is_none() {
+            return write!(Stripper::new(f), "{content}");
+        }
+        write_padded(f, visible_width(content), |f| {
+            write!(Stripper::new(f), "{content}")
+        })
+    }
+}
+
+/// A writer adapter that removes the escape sequences written through it, passing everything else through.
+///
+/// It allows code that writes styled output to be reused for a plain text destination, like a log file:
+///
+/// ```
+/// use core::fmt::Write as _;
+/// use fluent_ansi::{prelude::*, Stripper};
+///
+/// let mut stripper = Stripper::new(String::new());
+/// write!(stripper, "{}: disk full", Color::RED.bold().applied_to("Error")).unwrap();
+/// assert_eq!(stripper.into_inner(), "Error: disk full");
+/// ```
+///
+/// Escape sequences split across several writes are removed as well. Control characters, like line breaks and tabs,
+/// are kept.
+#[derive(Debug, Clone)]
+pub struct Stripper<W: Write> {
+    inner: W,
+    state: EscapeState,
+}
+
+impl<W: Write> Stripper<W> {
+    /// Creates a new `Stripper` that writes to the given writer.
+    #[must_use]
+    pub const fn new(inner: W) -> Self {
+        Self {
+            inner,
+            state: EscapeState::Text,
+        }
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for Stripper<W> {
+    fn write_str(&mut self, s: &str) -> Result {
+        let mut start = None;
+        for (i, c) in s.char_indices() {
+            let in_text = self.state == EscapeState::Text && c != '\x1b';
+            self.state.advance(c);
+            match (in_text, start) {
+                (true, None) => start = Some(i),
+                (false, Some(st)) => {
+                    self.inner.write_str(&s[st..i])?;
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(st) = start {
+            self.inner.write_str(&s[st..])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn plain() {
+        let stld = Styled::new("CONTENT").bold().fg(Color::RED);
+        assert_eq!(stld.plain().to_string(), "CONTENT");
+        assert_eq!(Styled::new("CONTENT").plain().to_string(), "CONTENT");
+        assert_eq!(Styled::new(42).italic().plain().to_string(), "42");
+    }
+
+    #[test]
+    fn nested() {
+        let stld = Styled::new(Styled::new("in\x1b]0;title\x07ner").italic()).bold();
+        assert_eq!(stld.plain().to_string(), "inner");
+    }
+
+    #[test]
+    fn control_characters_are_kept() {
+        let stld = Styled::new("a\tb\nc").underline();
+        assert_eq!(stld.plain().to_string(), "a\tb\nc");
+    }
+
+    #[test]
+    fn padding() {
+        let stld = Styled::new(Styled::new("abc").italic()).bold();
+        assert_eq!(format!("{:6}", stld.plain()), "abc   ");
+        assert_eq!(format!("{:>6}", stld.plain()), "   abc");
+        assert_eq!(format!("{:*^6}", stld.plain()), "*abc**");
+        assert_eq!(format!("{:2}", stld.plain()), "abc");
+    }
+
+    #[test]
+    fn stripper() {
+        let mut stripper = Stripper::new(String::new());
+        write!(stripper, "{}", Styled::new("a\tb").bold()).unwrap();
+        stripper.write_str("c\x1b[3").unwrap();
+        stripper.write_str("1md\x1b]8;;url\x1b").unwrap();
+        stripper.write_str("\\e").unwrap();
+        assert_eq!(stripper.get_ref(), "a\tbcde");
+
+        stripper.get_mut().clear();
+        stripper.write_char('f').unwrap();
+        assert_eq!(stripper.into_inner(), "f");
+    }
+}
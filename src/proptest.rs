@@ -0,0 +1,190 @@
+//! Implementations of [`Arbitrary`] for [`proptest`], enabled by the `proptest` feature.
+//!
+//! They allow property tests to take styles, colors and effects as inputs, without writing strategies for them:
+//!
+//! ```
+//! use fluent_ansi::Style;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn spec_round_trip(style: Style) {
+//!         prop_assert_eq!(Style::from_spec(&style.to_spec().to_string()), Ok(style));
+//!     }
+//! }
+//! # spec_round_trip();
+//! ```
+//!
+//! The generated styles have no custom code. The effects that can't be combined are resolved as in
+//! [`ToStyleSet::effect()`](crate::ToStyleSet::effect), with the one added last prevailing.
+
+use alloc::vec::Vec;
+
+use proptest::{
+    arbitrary::{Arbitrary, any},
+    collection::vec,
+    option, prop_oneof,
+    sample::select,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use crate::{
+    ColorTarget, Effect, Style, StyleSet as _, TargetedColor, ToStyleSet as _, UnderlineStyle,
+    color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor},
+};
+
+impl Arbitrary for Effect {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        select(Effect::iter().collect::<Vec<_>>()).boxed()
+    }
+}
+
+impl Arbitrary for UnderlineStyle {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        select(UnderlineStyle::iter().collect::<Vec<_>>()).boxed()
+    }
+}
+
+impl Arbitrary for BasicColor {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        select(&BasicColor::ALL[..]).boxed()
+    }
+}
+
+impl Arbitrary for SimpleColor {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (any::<BasicColor>(), any::<bool>())
+            .prop_map(|(basic_color, bright)| {
+                if bright {
+                    SimpleColor::new_bright(basic_color)
+                } else {
+                    SimpleColor::new(basic_color)
+                }
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for IndexedColor {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<u8>().prop_map(IndexedColor).boxed()
+    }
+}
+
+impl Arbitrary for RGBColor {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<(u8, u8, u8)>()
+            .prop_map(|(r, g, b)| RGBColor::new(r, g, b))
+            .boxed()
+    }
+}
+
+impl Arbitrary for Color {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<SimpleColor>().prop_map(Color::Simple),
+            any::<IndexedColor>().prop_map(Color::Indexed),
+            any::<RGBColor>().prop_map(Color::RGB),
+            Just(Color::Default),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for ColorTarget {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        select(&ColorTarget::ALL[..]).boxed()
+    }
+}
+
+impl Arbitrary for TargetedColor {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (any::<Color>(), any::<ColorTarget>())
+            .prop_map(|(color, target)| TargetedColor::new(color, target))
+            .boxed()
+    }
+}
+
+impl Arbitrary for Style {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        let effects = vec(any::<Effect>(), 0..4);
+        let colors = [(); 3].map(|()| option::of(any::<Color>()));
+        (effects, colors)
+            .prop_map(|(effects, colors)| {
+                let style = effects.into_iter().fold(Style::new(), Style::effect);
+                ColorTarget::ALL
+                    .into_iter()
+                    .zip(colors)
+                    .fold(style, |style, (target, color)| {
+                        style.set_color(target, color)
+                    })
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prop_assert_eq, proptest};
+
+    use crate::AppliedTo as _;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn spec_round_trip(style: Style) {
+            prop_assert_eq!(Style::from_spec(&style.to_spec().to_string()), Ok(style));
+        }
+
+        #[test]
+        fn plain_rendering(style: Style, text in "[a-z ]{0,8}") {
+            prop_assert_eq!(style.applied_to(text.as_str()).plain().to_string(), text);
+        }
+
+        #[test]
+        fn targeted_color(targeted_color: TargetedColor) {
+            prop_assert_eq!(
+                Style::from(targeted_color).get_color(targeted_color.get_target()),
+                Some(targeted_color.get_color())
+            );
+        }
+
+        #[test]
+        fn underline_style(underline_style: UnderlineStyle) {
+            prop_assert_eq!(
+                Style::from(underline_style).get_underline_style(),
+                Some(underline_style)
+            );
+        }
+    }
+}
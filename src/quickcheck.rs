@@ -0,0 +1,160 @@
+//! Implementations of [`Arbitrary`] for [`quickcheck`], enabled by the `quickcheck` feature.
+//!
+//! They allow property tests to take styles, colors and effects as inputs:
+//!
+//! ```
+//! use fluent_ansi::Style;
+//! use quickcheck::quickcheck;
+//!
+//! fn spec_round_trip(style: Style) -> bool {
+//!     Style::from_spec(&style.to_spec().to_string()) == Ok(style)
+//! }
+//!
+//! quickcheck(spec_round_trip as fn(Style) -> bool);
+//! ```
+//!
+//! The generated styles have no custom code. The effects that can't be combined are resolved as in
+//! [`ToStyleSet::effect()`](crate::ToStyleSet::effect), with the one added last prevailing. Styles are shrunk by
+//! removing their effects and colors one at a time.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{
+    ColorTarget, Effect, Style, StyleSet as _, TargetedColor, ToStyleSet as _, UnderlineStyle,
+    color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor},
+};
+
+fn choose<T: Copy>(g: &mut Gen, values: &[T]) -> T {
+    // The slices are never empty
+    g.choose(values).copied().unwrap_or(values[0])
+}
+
+impl Arbitrary for Effect {
+    fn arbitrary(g: &mut Gen) -> Self {
+        choose(g, &Effect::iter().collect::<Vec<_>>())
+    }
+}
+
+impl Arbitrary for UnderlineStyle {
+    fn arbitrary(g: &mut Gen) -> Self {
+        choose(g, &UnderlineStyle::iter().collect::<Vec<_>>())
+    }
+}
+
+impl Arbitrary for BasicColor {
+    fn arbitrary(g: &mut Gen) -> Self {
+        choose(g, &BasicColor::ALL)
+    }
+}
+
+impl Arbitrary for SimpleColor {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let basic_color = BasicColor::arbitrary(g);
+        if bool::arbitrary(g) {
+            SimpleColor::new_bright(basic_color)
+        } else {
+            SimpleColor::new(basic_color)
+        }
+    }
+}
+
+impl Arbitrary for IndexedColor {
+    fn arbitrary(g: &mut Gen) -> Self {
+        IndexedColor(u8::arbitrary(g))
+    }
+}
+
+impl Arbitrary for RGBColor {
+    fn arbitrary(g: &mut Gen) -> Self {
+        RGBColor::new(u8::arbitrary(g), u8::arbitrary(g), u8::arbitrary(g))
+    }
+}
+
+impl Arbitrary for Color {
+    fn arbitrary(g: &mut Gen) -> Self {
+        match choose(g, &[0, 1, 2, 3]) {
+            0 => Color::Simple(SimpleColor::arbitrary(g)),
+            1 => Color::Indexed(IndexedColor::arbitrary(g)),
+            2 => Color::RGB(RGBColor::arbitrary(g)),
+            _ => Color::Default,
+        }
+    }
+}
+
+impl Arbitrary for ColorTarget {
+    fn arbitrary(g: &mut Gen) -> Self {
+        choose(g, &ColorTarget::ALL)
+    }
+}
+
+impl Arbitrary for TargetedColor {
+    fn arbitrary(g: &mut Gen) -> Self {
+        TargetedColor::new(Color::arbitrary(g), ColorTarget::arbitrary(g))
+    }
+}
+
+impl Arbitrary for Style {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut style = Style::new();
+        for _ in 0..choose(g, &[0, 1, 2, 3]) {
+            style = style.effect(Effect::arbitrary(g));
+        }
+        for target in ColorTarget::ALL {
+            style = style.set_color(target, Option::<Color>::arbitrary(g));
+        }
+        style
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let style = *self;
+        let without_effects = style
+            .get_effects()
+            .map(move |effect| style.set_effect(effect, false));
+        let without_colors = ColorTarget::ALL
+            .into_iter()
+            .filter(move |&target| style.get_color(target).is_some())
+            .map(move |target| style.set_color(target, None::<Color>));
+        Box::new(without_effects.chain(without_colors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::quickcheck;
+
+    use super::*;
+
+    #[test]
+    fn spec_round_trip() {
+        fn property(style: Style) -> bool {
+            Style::from_spec(&style.to_spec().to_string()) == Ok(style)
+        }
+        quickcheck(property as fn(Style) -> bool);
+    }
+
+    #[test]
+    fn targeted_color() {
+        fn property(targeted_color: TargetedColor) -> bool {
+            Style::from(targeted_color).get_color(targeted_color.get_target())
+                == Some(targeted_color.get_color())
+        }
+        quickcheck(property as fn(TargetedColor) -> bool);
+    }
+
+    #[test]
+    fn shrink() {
+        let style = Style::new().bold().italic().bg(Color::RED);
+        let shrunk: Vec<Style> = style.shrink().collect();
+        assert_eq!(
+            shrunk,
+            [
+                Style::new().italic().bg(Color::RED),
+                Style::new().bold().bg(Color::RED),
+                Style::new().bold().italic(),
+            ]
+        );
+        assert_eq!(Style::new().shrink().next(), None);
+    }
+}
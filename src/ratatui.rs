@@ -0,0 +1,211 @@
+//! Conversions from and to [`ratatui`] style types, enabled by the `ratatui` feature.
+//!
+//! [`Color::Default`] corresponds to [`Reset`](ratatui::style::Color::Reset). The conversions are lossy in some cases:
+//! - the underline styles are all converted to a solid underline;
+//! - the effects with no corresponding modifier, like [`Effect::Overline`], are dropped;
+//! - the modifiers to be removed ([`sub_modifier`](ratatui::style::Style::sub_modifier)) are dropped.
+
+use ratatui::style::Modifier;
+
+use crate::{
+    Effect, Style, StyleSet as _,
+    color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor},
+};
+
+const EFFECTS: [(Effect, Modifier); 9] = [
+    (Effect::Bold, Modifier::BOLD),
+    (Effect::Faint, Modifier::DIM),
+    (Effect::Italic, Modifier::ITALIC),
+    (Effect::Underline, Modifier::UNDERLINED),
+    (Effect::Blink, Modifier::SLOW_BLINK),
+    (Effect::RapidBlink, Modifier::RAPID_BLINK),
+    (Effect::Reverse, Modifier::REVERSED),
+    (Effect::Conceal, Modifier::HIDDEN),
+    (Effect::Strikethrough, Modifier::CROSSED_OUT),
+];
+
+impl From<Style> for ratatui::style::Style {
+    fn from(style: Style) -> Self {
+        let mut modifier = EFFECTS
+            .iter()
+            .filter(|&&(effect, _)| style.get_effect(effect))
+            .fold(Modifier::empty(), |modifier, &(_, m)| modifier | m);
+        if style.get_underline_style().is_some() {
+            modifier |= Modifier::UNDERLINED;
+        }
+
+        let mut result = ratatui::style::Style::new().add_modifier(modifier);
+        result.fg = style.fg.map(Into::into);
+        result.bg = style.bg.map(Into::into);
+        result.underline_color = style.underline_color.map(Into::into);
+        result
+    }
+}
+
+impl From<ratatui::style::Style> for Style {
+    fn from(style: ratatui::style::Style) -> Self {
+        let mut result = EFFECTS
+            .iter()
+            .filter(|&&(_, modifier)| style.add_modifier.contains(modifier))
+            .fold(Style::new(), |result, &(effect, _)| {
+                result.set_effect(effect, true)
+            });
+
+        result.fg = style.fg.map(Into::into);
+        result.bg = style.bg.map(Into::into);
+        result.underline_color = style.underline_color.map(Into::into);
+        result
+    }
+}
+
+impl From<Color> for ratatui::style::Color {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Simple(simple) => simple.into(),
+            Color::Indexed(indexed) => indexed.into(),
+            Color::RGB(rgb) => rgb.into(),
+            Color::Default => ratatui::style::Color::Reset,
+        }
+    }
+}
+
+impl From<ratatui::style::Color> for Color {
+    fn from(color: ratatui::style::Color) -> Self {
+        use ratatui::style::Color as C;
+
+        let (basic_color, bright) = match color {
+            C::Reset => return Color::Default,
+            C::Indexed(index) => return Color::Indexed(IndexedColor::new(index)),
+            C::Rgb(r, g, b) => return Color::RGB(RGBColor::new(r, g, b)),
+            C::Black => (BasicColor::Black, false),
+            C::Red => (BasicColor::Red, false),
+            C::Green => (BasicColor::Green, false),
+            C::Yellow => (BasicColor::Yellow, false),
+            C::Blue => (BasicColor::Blue, false),
+            C::Magenta => (BasicColor::Magenta, false),
+            C::Cyan => (BasicColor::Cyan, false),
+            C::Gray => (BasicColor::White, false),
+            C::DarkGray => (BasicColor::Black, true),
+            C::LightRed => (BasicColor::Red, true),
+            C::LightGreen => (BasicColor::Green, true),
+            C::LightYellow => (BasicColor::Yellow, true),
+            C::LightBlue => (BasicColor::Blue, true),
+            C::LightMagenta => (BasicColor::Magenta, true),
+            C::LightCyan => (BasicColor::Cyan, true),
+            C::White => (BasicColor::White, true),
+        };
+        let simple = if bright {
+            SimpleColor::new_bright(basic_color)
+        } else {
+            SimpleColor::new(basic_color)
+        };
+        Color::Simple(simple)
+    }
+}
+
+impl From<BasicColor> for ratatui::style::Color {
+    fn from(color: BasicColor) -> Self {
+        color.to_simple_color().into()
+    }
+}
+
+impl From<SimpleColor> for ratatui::style::Color {
+    fn from(color: SimpleColor) -> Self {
+        use ratatui::style::Color as C;
+
+        match (color.get_basic_color(), color.is_bright()) {
+            (BasicColor::Black, false) => C::Black,
+            (BasicColor::Red, false) => C::Red,
+            (BasicColor::Green, false) => C::Green,
+            (BasicColor::Yellow, false) => C::Yellow,
+            (BasicColor::Blue, false) => C::Blue,
+            (BasicColor::Magenta, false) => C::Magenta,
+            (BasicColor::Cyan, false) => C::Cyan,
+            (BasicColor::White, false) => C::Gray,
+            (BasicColor::Black, true) => C::DarkGray,
+            (BasicColor::Red, true) => C::LightRed,
+            (BasicColor::Green, true) => C::LightGreen,
+            (BasicColor::Yellow, true) => C::LightYellow,
+            (BasicColor::Blue, true) => C::LightBlue,
+            (BasicColor::Magenta, true) => C::LightMagenta,
+            (BasicColor::Cyan, true) => C::LightCyan,
+            (BasicColor::White, true) => C::White,
+        }
+    }
+}
+
+impl From<IndexedColor> for ratatui::style::Color {
+    fn from(color: IndexedColor) -> Self {
+        ratatui::style::Color::Indexed(color.get_index())
+    }
+}
+
+impl From<RGBColor> for ratatui::style::Color {
+    fn from(color: RGBColor) -> Self {
+        ratatui::style::Color::Rgb(color.r, color.g, color.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToStyleSet as _;
+
+    use super::*;
+
+    #[test]
+    fn style() {
+        let style = Style::new()
+            .bold()
+            .underline()
+            .reverse()
+            .fg(BasicColor::Red)
+            .bg(Color::Default)
+            .underline_color(IndexedColor::new(42));
+        let mut expected = ratatui::style::Style::new()
+            .fg(ratatui::style::Color::Red)
+            .bg(ratatui::style::Color::Reset)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED | Modifier::REVERSED);
+        expected.underline_color = Some(ratatui::style::Color::Indexed(42));
+
+        assert_eq!(ratatui::style::Style::from(style), expected);
+        assert_eq!(Style::from(expected), style);
+    }
+
+    #[test]
+    fn lossy() {
+        assert_eq!(
+            ratatui::style::Style::from(Style::new().curly_underline().overline()),
+            ratatui::style::Style::new().add_modifier(Modifier::UNDERLINED)
+        );
+        assert_eq!(
+            Style::from(
+                ratatui::style::Style::new()
+                    .add_modifier(Modifier::RAPID_BLINK)
+                    .remove_modifier(Modifier::BOLD)
+            ),
+            Style::new().rapid_blink()
+        );
+    }
+
+    #[test]
+    fn colors() {
+        for basic_color in [BasicColor::Black, BasicColor::Green, BasicColor::White] {
+            for simple_color in [basic_color.to_simple_color(), basic_color.bright()] {
+                let color = Color::Simple(simple_color);
+                assert_eq!(Color::from(ratatui::style::Color::from(color)), color);
+            }
+        }
+        assert_eq!(
+            ratatui::style::Color::from(BasicColor::White),
+            ratatui::style::Color::Gray
+        );
+        assert_eq!(
+            ratatui::style::Color::from(BasicColor::Black.bright()),
+            ratatui::style::Color::DarkGray
+        );
+        assert_eq!(
+            Color::from(ratatui::style::Color::from(RGBColor::new(1, 2, 3))),
+            Color::rgb(1, 2, 3)
+        );
+    }
+}
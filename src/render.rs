@@ -0,0 +1,718 @@
+use core::fmt::{Display, Formatter, Result, Write};
+
+use crate::{
+    Code, CodeWriter, ColorTarget, Effect, Style, StyleSet as _, Styled,
+    color::{Color, RGBColor, SimpleColor},
+    erase::EraseInLine,
+    width::{visible_width, write_padded},
+};
+
+/// Options that control how styles are rendered as escape sequences.
+///
+/// The default options render styles exactly like their [`Display`] implementations. Options are passed to
+/// [`Style::render()`] and [`Styled::render()`], so they can be selected per render:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, RenderOptions};
+///
+/// const OPTIONS: RenderOptions = RenderOptions::new().colon_colors(true);
+///
+/// let style = Color::indexed(123).bold();
+/// assert_eq!(format!("{style}"), "\x1b[1;38;5;123m");
+/// assert_eq!(format!("{}", style.render(OPTIONS)), "\x1b[1;38:5:123m");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RenderOptions {
+    flags: u8,
+}
+
+impl RenderOptions {
+    const COLON_COLORS: u8 = 1 << 0;
+    const LEGACY_UNDERLINE: u8 = 1 << 1;
+    const SPLIT_SEQUENCES: u8 = 1 << 2;
+    const RESET_PREFIX: u8 = 1 << 3;
+    const PER_LINE: u8 = 1 << 4;
+    const FILL_LINE: u8 = 1 << 5;
+
+    /// Creates the default options.
+    #[must_use]
+    pub const fn new() -> Self {
+        RenderOptions { flags: 0 }
+    }
+
+    /// Sets whether extended colors are written in the ITU colon form, like `38:5:123` and `38:2::0:128:255`, instead
+    /// of the semicolon form, like `38;5;123` and `38;2;0;128;255`.
+    #[must_use]
+    pub const fn colon_colors(self, enabled: bool) -> Self {
+        self.with_flag(Self::COLON_COLORS, enabled)
+    }
+
+    /// Returns whether extended colors are written in the ITU colon form.
+    #[must_use]
+    pub const fn get_colon_colors(self) -> bool {
+        self.has_flag(Self::COLON_COLORS)
+    }
+
+    /// Sets whether the underline extensions are downgraded for terminals that don't support them.
+    ///
+    /// When enabled, the curly, dotted and dashed underlines (`4:3`, `4:4` and `4:5`) are written as a solid underline
+    /// (`4`), and underline colors are not written.
+    #[must_use]
+    pub const fn legacy_underline(self, enabled: bool) -> Self {
+        self.with_flag(Self::LEGACY_UNDERLINE, enabled)
+    }
+
+    /// Returns whether the underline extensions are downgraded.
+    #[must_use]
+    pub const fn get_legacy_underline(self) -> bool {
+        self.has_flag(Self::LEGACY_UNDERLINE)
+    }
+
+    /// Sets whether each attribute is written in its own escape sequence, like `ESC [ 1 m ESC [ 31 m`, instead of a
+    /// single combined sequence, like `ESC [ 1 ; 31 m`.
+    #[must_use]
+    pub const fn split_sequences(self, enabled: bool) -> Self {
+        self.with_flag(Self::SPLIT_SEQUENCES, enabled)
+    }
+
+    /// Returns whether each attribute is written in its own escape sequence.
+    #[must_use]
+    pub const fn get_split_sequences(self) -> bool {
+        self.has_flag(Self::SPLIT_SEQUENCES)
+    }
+
+    /// Sets whether every rendered style starts with the reset parameter, like `ESC [ 0 ; 1 ; 31 m`, so that it fully
+    /// defines the appearance regardless of the styles rendered before it.
+    #[must_use]
+    pub const fn reset_prefix(self, enabled: bool) -> Self {
+        self.with_flag(Self::RESET_PREFIX, enabled)
+    }
+
+    /// Returns whether every rendered style starts with the reset parameter.
+    #[must_use]
+    pub const fn get_reset_prefix(self) -> bool {
+        self.has_flag(Self::RESET_PREFIX)
+    }
+
+    /// Sets whether styled content is reset before every line break and styled again after it.
+    ///
+    /// It makes each line of multi-line content fully styled on its own, for pagers like `less -R` and for tools that
+    /// process each line independently. Only [`Styled::render()`] is affected by this option.
+    #[must_use]
+    pub const fn per_line(self, enabled: bool) -> Self {
+        self.with_flag(Self::PER_LINE, enabled)
+    }
+
+    /// Returns whether styled content is reset before every line break and styled again after it.
+    #[must_use]
+    pub const fn get_per_line(self) -> bool {
+        self.has_flag(Self::PER_LINE)
+    }
+
+    /// Sets whether the background color, if set, is extended to the end of the line.
+    ///
+    /// It is done by erasing the rest of the line ([`EraseInLine::ToEnd`]) while the background color is active:
+    /// after the style in [`Style::render()`], and after the content, or after each line of it when rendering
+    /// [per line](Self::per_line), in [`Styled::render()`].
+    #[must_use]
+    pub const fn fill_line(self, enabled: bool) -> Self {
+        self.with_flag(Self::FILL_LINE, enabled)
+    }
+
+    /// Returns whether the background color is extended to the end of the line.
+    #[must_use]
+    pub const fn get_fill_line(self) -> bool {
+        self.has_flag(Self::FILL_LINE)
+    }
+
+    /// Returns whether the line must be filled with the background color of the style.
+    fn fills_line(self, style: Style) -> bool {
+        self.get_fill_line() && style.get_color(ColorTarget::Background).is_some()
+    }
+
+    const fn with_flag(self, flag: u8, enabled: bool) -> Self {
+        let flags = if enabled {
+            self.flags | flag
+        } else {
+            self.flags & !flag
+        };
+        RenderOptions { flags }
+    }
+
+    const fn has_flag(self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+}
+
+/// A value that is rendered with some [`RenderOptions`].
+///
+/// It is returned by [`Style::render()`] and [`Styled::render()`], and renders the escape sequences when formatted
+/// with [`Display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rendered<T> {
+    value: T,
+    options: RenderOptions,
+}
+
+/// Renders a value into a new writer, like a fixed-capacity string, without allocating.
+///
+/// ```
+/// use core::fmt::{Result, Write};
+/// use fluent_ansi::{prelude::*, render_to};
+///
+/// #[derive(Default)]
+/// struct Buffer {
+///     bytes: [u8; 32],
+///     len: usize,
+/// }
+///
+/// impl Write for Buffer {
+///     fn write_str(&mut self, s: &str) -> Result {
+///         let end = self.len + s.len();
+///         self.bytes.get_mut(self.len..end).ok_or(core::fmt::Error)?.copy_from_slice(s.as_bytes());
+///         self.len = end;
+///         Ok(())
+///     }
+/// }
+///
+/// let buffer: Buffer = render_to(Color::RED.applied_to("Error")).unwrap();
+/// assert_eq!(&buffer.bytes[..buffer.len], b"\x1b[31mError\x1b[0m");
+/// ```
+///
+/// With the `heapless` feature, it can be used with `heapless::String`, whose capacity can be sized with
+/// [`Style::MAX_RENDERED_LEN`].
+///
+/// # Errors
+///
+/// Returns an error if writing to the writer fails, like when it runs out of capacity.
+pub fn render_to<W: Write + Default>(
+    value: impl Display,
+) -> core::result::Result<W, core::fmt::Error> {
+    let mut writer = W::default();
+    write!(writer, "{value}")?;
+    Ok(writer)
+}
+
+impl Style {
+    /// Returns a value that renders this style with the given options.
+    #[must_use]
+    pub fn render(self, options: RenderOptions) -> Rendered<Style> {
+        Rendered {
+            value: self,
+            options,
+        }
+    }
+}
+
+impl<C: Display> Styled<C> {
+    /// Returns a value that renders the styled content with the given options.
+    ///
+    /// The options apply to the escape sequence that starts the styling. The reset sequence at the end is always
+    /// `ESC [ 0 m`.
+    #[must_use]
+    pub fn render(&self, options: RenderOptions) -> Rendered<&Styled<C>> {
+        Rendered {
+            value: self,
+            options,
+        }
+    }
+}
+
+impl Display for Rendered<Style> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_style(f, self.value, self.options)?;
+        if self.options.fills_line(self.value) {
+            write!(f, "{}", EraseInLine::ToEnd)?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: Display> Display for Rendered<&Styled<C>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if f.width().is_none() {
+            return self.fmt_unpadded(f);
+        }
+        let content_width = visible_width(self.value.get_content());
+        write_padded(f, content_width, |f| self.fmt_unpadded(f))
+    }
+}
+
+impl<C: Display> Rendered<&Styled<C>> {
+    fn fmt_unpadded(&self, f: &mut Formatter<'_>) -> Result {
+        let style = self.value.get_style();
+        if rendered_codes(style, self.options).next().is_none() {
+            write!(f, "{}", self.value.get_content())
+        } else if self.options.get_per_line() {
+            let mut writer = LineStyler {
+                f,
+                style,
+                options: self.options,
+                styled: false,
+            };
+            write!(writer, "{}", self.value.get_content())?;
+            writer.end_line()
+        } else {
+            write_style(f, style, self.options)?;
+            write!(f, "{}", self.value.get_content())?;
+            if self.options.fills_line(style) {
+                write!(f, "{}", EraseInLine::ToEnd)?;
+            }
+            write_style(f, Style::new(), RenderOptions::new())
+        }
+    }
+}
+
+/// A writer that styles each line separately.
+///
+/// The style is written lazily, before the first text of each line, so empty lines are left unstyled.
+struct LineStyler<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    style: Style,
+    options: RenderOptions,
+    styled: bool,
+}
+
+impl LineStyler<'_, '_> {
+    fn write_line_part(&mut self, part: &str) -> Result {
+        if part.is_empty() {
+            return Ok(());
+        }
+        if !self.styled {
+            self.styled = true;
+            write_style(self.f, self.style, self.options)?;
+        }
+        self.f.write_str(part)
+    }
+
+    fn end_line(&mut self) -> Result {
+        if self.styled {
+            self.styled = false;
+            if self.options.fills_line(self.style) {
+                write!(self.f, "{}", EraseInLine::ToEnd)?;
+            }
+            write_style(self.f, Style::new(), RenderOptions::new())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Write for LineStyler<'_, '_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        let mut lines = s.split('\n');
+        if let Some(first) = lines.next() {
+            self.write_line_part(first)?;
+        }
+        for line in lines {
+            self.end_line()?;
+            self.f.write_char('\n')?;
+            self.write_line_part(line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the escape sequence for the style, or nothing if the options drop all of its codes.
+fn write_style(f: &mut Formatter<'_>, style: Style, options: RenderOptions) -> Result {
+    if style == Style::new() {
+        return f.write_str("\x1b[0m");
+    }
+    if !options.get_reset_prefix()
+        && !options.get_legacy_underline()
+        && let Some(sequence) = static_sequence(style)
+    {
+        return f.write_str(sequence);
+    }
+
+    if options.get_split_sequences() {
+        for code in rendered_codes(style, options) {
+            write!(f, "\x1b[{}m", RenderedCode(code, options))?;
+        }
+        return Ok(());
+    }
+
+    let mut codes = rendered_codes(style, options).peekable();
+    if codes.peek().is_none() {
+        return Ok(());
+    }
+    f.write_str("\x1b[")?;
+    let mut code_writer = CodeWriter::new(f);
+    for code in codes {
+        code_writer.write_code(RenderedCode(code, options))?;
+    }
+    f.write_str("m")
+}
+
+/// Returns the escape sequence of a style made of a single effect or a single simple color, which are the most common
+/// ones, so that it is written as is, without formatting its parameters.
+fn static_sequence(style: Style) -> Option<&'static str> {
+    if style.custom_code.is_some() {
+        return None;
+    }
+    match (
+        style.effect_count(),
+        style.fg,
+        style.bg,
+        style.underline_color,
+    ) {
+        (1, None, None, None) => style
+            .get_effects()
+            .next()
+            .map(|effect| effect_sequence(effect.emitted_effect())),
+        (0, Some(Color::Simple(color)), None, None) => {
+            Some(FG_SEQUENCES[simple_color_index(color)])
+        }
+        (0, None, Some(Color::Simple(color)), None) => {
+            Some(BG_SEQUENCES[simple_color_index(color)])
+        }
+        _ => None,
+    }
+}
+
+fn effect_sequence(effect: Effect) -> &'static str {
+    match effect {
+        Effect::Bold => "\x1b[1m",
+        Effect::Faint => "\x1b[2m",
+        Effect::Italic => "\x1b[3m",
+        Effect::Underline => "\x1b[4m",
+        Effect::CurlyUnderline => "\x1b[4:3m",
+        Effect::DottedUnderline => "\x1b[4:4m",
+        Effect::DashedUnderline => "\x1b[4:5m",
+        Effect::Blink => "\x1b[5m",
+        Effect::RapidBlink => "\x1b[6m",
+        Effect::Reverse => "\x1b[7m",
+        Effect::Conceal => "\x1b[8m",
+        Effect::Strikethrough => "\x1b[9m",
+        Effect::Fraktur => "\x1b[20m",
+        Effect::DoubleUnderline => "\x1b[21m",
+        Effect::Framed => "\x1b[51m",
+        Effect::Encircled => "\x1b[52m",
+        Effect::Overline => "\x1b[53m",
+        Effect::Superscript => "\x1b[73m",
+        Effect::Subscript => "\x1b[74m",
+    }
+}
+
+fn simple_color_index(color: SimpleColor) -> usize {
+    usize::from(color.get_basic_color().code_offset()) + if color.is_bright() { 8 } else { 0 }
+}
+
+const FG_SEQUENCES: [&str; 16] = [
+    "\x1b[30m", "\x1b[31m", "\x1b[32m", "\x1b[33m", "\x1b[34m", "\x1b[35m", "\x1b[36m", "\x1b[37m",
+    "\x1b[90m", "\x1b[91m", "\x1b[92m", "\x1b[93m", "\x1b[94m", "\x1b[95m", "\x1b[96m", "\x1b[97m",
+];
+
+const BG_SEQUENCES: [&str; 16] = [
+    "\x1b[40m",
+    "\x1b[41m",
+    "\x1b[42m",
+    "\x1b[43m",
+    "\x1b[44m",
+    "\x1b[45m",
+    "\x1b[46m",
+    "\x1b[47m",
+    "\x1b[100m",
+    "\x1b[101m",
+    "\x1b[102m",
+    "\x1b[103m",
+    "\x1b[104m",
+    "\x1b[105m",
+    "\x1b[106m",
+    "\x1b[107m",
+];
+
+fn rendered_codes(style: Style, options: RenderOptions) -> impl Iterator<Item = Code> {
+    let reset = options.get_reset_prefix().then_some(Code::Single(0));
+    reset
+        .into_iter()
+        .chain(style.codes().filter_map(move |code| {
+            if options.get_legacy_underline() {
+                match code {
+                    Code::Sub(4, _) => Some(Code::Single(4)),
+                    Code::Indexed(58, _) | Code::RGB(58, _) | Code::Single(59) => None,
+                    code => Some(code),
+                }
+            } else {
+                Some(code)
+            }
+        }))
+}
+
+struct RenderedCode(Code, RenderOptions);
+
+impl Display for RenderedCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Code::Indexed(param, index) if self.1.get_colon_colors() => {
+                write!(f, "{param}:5:{index}")
+            }
+            Code::RGB(param, RGBColor { r, g, b }) if self.1.get_colon_colors() => {
+                write!(f, "{param}:2::{r}:{g}:{b}")
+            }
+            code => code.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AppliedTo as _, ToStyleSet as _, assert_display,
+        color::{BasicColor, IndexedColor, RGBColor},
+    };
+
+    use super::*;
+
+    #[test]
+    fn default_options() {
+        let style = Style::new()
+            .bold()
+            .curly_underline()
+            .fg(IndexedColor::new(123))
+            .bg(RGBColor::new(0, 128, 255));
+        assert_eq!(
+            style.render(RenderOptions::new()).to_string(),
+            style.to_string()
+        );
+        assert_display!(Style::new().render(RenderOptions::default()), "\x1b[0m");
+
+        let stld = style.applied_to("CONTENT");
+        assert_eq!(
+            stld.render(RenderOptions::new()).to_string(),
+            stld.to_string()
+        );
+    }
+
+    #[test]
+    fn colon_colors() {
+        let options = RenderOptions::new().colon_colors(true);
+        assert!(options.get_colon_colors());
+
+        #[cfg(feature = "truecolor")]
+        let bg = "48:2::0:128:255";
+        #[cfg(not(feature = "truecolor"))]
+        let bg = "46";
+        #[cfg(feature = "underline-color")]
+        let underline_color = ";58:5:1";
+        #[cfg(not(feature = "underline-color"))]
+        let underline_color = "";
+        assert_eq!(
+            Style::new()
+                .fg(IndexedColor::new(123))
+                .bg(RGBColor::new(0, 128, 255))
+                .underline_color(IndexedColor::new(1))
+                .render(options)
+                .to_string(),
+            format!("\x1b[38:5:123;{bg}{underline_color}m")
+        );
+        #[cfg(feature = "extended-underline")]
+        assert_display!(
+            Style::new()
+                .curly_underline()
+                .fg(BasicColor::Red)
+                .render(options),
+            "\x1b[4:3;31m"
+        );
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(
+            Style::new()
+                .curly_underline()
+                .fg(BasicColor::Red)
+                .render(options),
+            "\x1b[4;31m"
+        );
+        assert_display!(
+            Style::new()
+                .fg(IndexedColor::new(7))
+                .applied_to("CONTENT")
+                .render(options),
+            "\x1b[38:5:7mCONTENT\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn legacy_underline() {
+        let options = RenderOptions::new().legacy_underline(true);
+        assert!(options.get_legacy_underline());
+
+        assert_display!(
+            Style::new()
+                .curly_underline()
+                .fg(BasicColor::Red)
+                .underline_color(RGBColor::new(0, 128, 255))
+                .render(options),
+            "\x1b[4;31m"
+        );
+        assert_display!(Style::new().dashed_underline().render(options), "\x1b[4m");
+        assert_display!(Style::new().double_underline().render(options), "\x1b[21m");
+        assert_display!(
+            Style::new()
+                .underline_color(BasicColor::Red)
+                .render(options),
+            ""
+        );
+        assert_display!(
+            Style::new()
+                .underline_color(BasicColor::Red)
+                .applied_to("CONTENT")
+                .render(options),
+            "CONTENT"
+        );
+    }
+
+    #[test]
+    fn split_sequences() {
+        let options = RenderOptions::new().split_sequences(true);
+        assert!(options.get_split_sequences());
+
+        #[cfg(feature = "extended-underline")]
+        assert_display!(
+            Style::new()
+                .bold()
+                .curly_underline()
+                .fg(BasicColor::Red)
+                .bg(IndexedColor::new(123))
+                .render(options),
+            "\x1b[1m\x1b[4:3m\x1b[31m\x1b[48;5;123m"
+        );
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(
+            Style::new()
+                .bold()
+                .curly_underline()
+                .fg(BasicColor::Red)
+                .bg(IndexedColor::new(123))
+                .render(options),
+            "\x1b[1m\x1b[4m\x1b[31m\x1b[48;5;123m"
+        );
+        assert_display!(Style::new().render(options), "\x1b[0m");
+        #[cfg(feature = "truecolor")]
+        assert_display!(
+            Style::new()
+                .bold()
+                .fg(RGBColor::new(0, 128, 255))
+                .applied_to("CONTENT")
+                .render(options.colon_colors(true)),
+            "\x1b[1m\x1b[38:2::0:128:255mCONTENT\x1b[0m"
+        );
+        #[cfg(not(feature = "truecolor"))]
+        assert_display!(
+            Style::new()
+                .bold()
+                .fg(RGBColor::new(0, 128, 255))
+                .applied_to("CONTENT")
+                .render(options.colon_colors(true)),
+            "\x1b[1m\x1b[36mCONTENT\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn per_line() {
+        let options = RenderOptions::new().per_line(true);
+        assert!(options.get_per_line());
+
+        let style = Style::new().bold().fg(BasicColor::Red);
+        assert_display!(
+            style.applied_to("one\ntwo").render(options),
+            "\x1b[1;31mone\x1b[0m\n\x1b[1;31mtwo\x1b[0m"
+        );
+        assert_display!(
+            style.applied_to("\none\n\ntwo\n").render(options),
+            "\n\x1b[1;31mone\x1b[0m\n\n\x1b[1;31mtwo\x1b[0m\n"
+        );
+        assert_display!(
+            style.applied_to("single").render(options),
+            "\x1b[1;31msingle\x1b[0m"
+        );
+        assert_display!(style.applied_to("").render(options), "");
+        assert_display!(Styled::new("one\ntwo").render(options), "one\ntwo");
+        assert_display!(style.render(options), "\x1b[1;31m");
+    }
+
+    #[test]
+    fn fill_line() {
+        let options = RenderOptions::new().fill_line(true);
+        assert!(options.get_fill_line());
+
+        let style = Style::new().bold().bg(BasicColor::Blue);
+        assert_display!(style.render(options), "\x1b[1;44m\x1b[K");
+        assert_display!(
+            style.applied_to("Status").render(options),
+            "\x1b[1;44mStatus\x1b[K\x1b[0m"
+        );
+        assert_display!(
+            style
+                .applied_to("one\n\ntwo")
+                .render(options.per_line(true)),
+            "\x1b[1;44mone\x1b[K\x1b[0m\n\n\x1b[1;44mtwo\x1b[K\x1b[0m"
+        );
+        assert_display!(
+            Style::new().bold().applied_to("Status").render(options),
+            "\x1b[1mStatus\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn reset_prefix() {
+        let options = RenderOptions::new().reset_prefix(true);
+        assert!(options.get_reset_prefix());
+
+        assert_display!(
+            Style::new().bold().fg(BasicColor::Red).render(options),
+            "\x1b[0;1;31m"
+        );
+        assert_display!(Style::new().render(options), "\x1b[0m");
+        assert_display!(
+            Style::new()
+                .underline_color(BasicColor::Red)
+                .render(options.legacy_underline(true)),
+            "\x1b[0m"
+        );
+        assert_display!(
+            Style::new()
+                .bold()
+                .applied_to("CONTENT")
+                .render(options.split_sequences(true)),
+            "\x1b[0m\x1b[1mCONTENT\x1b[0m"
+        );
+        assert_display!(
+            Styled::new("CONTENT").render(options),
+            "\x1b[0mCONTENT\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn static_sequences() {
+        let simple_colors = BasicColor::ALL
+            .into_iter()
+            .flat_map(|basic_color| [basic_color.to_simple_color(), basic_color.bright()]);
+        for style in Effect::iter()
+            .map(Style::from)
+            .chain(simple_colors.clone().map(|color| Style::new().fg(color)))
+            .chain(simple_colors.map(|color| Style::new().bg(color)))
+        {
+            let code = style.codes().next().unwrap();
+            assert_eq!(
+                static_sequence(style),
+                Some(format!("\x1b[{code}m").as_str())
+            );
+            assert_eq!(style.to_string(), format!("\x1b[{code}m"));
+        }
+
+        assert_eq!(static_sequence(Style::new().bold().italic()), None);
+        assert_eq!(
+            static_sequence(Style::new().bold().fg(BasicColor::Red)),
+            None
+        );
+        assert_eq!(static_sequence(Style::new().fg(IndexedColor(1))), None);
+        assert_eq!(
+            static_sequence(Style::new().underline_color(BasicColor::Red)),
+            None
+        );
+        assert_eq!(
+            static_sequence(Style::new().bold().add(crate::CustomCode::new("60"))),
+            None
+        );
+    }
+}
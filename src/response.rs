@@ -0,0 +1,73 @@
+use core::{
+    error::Error,
+    fmt::{Display, Formatter, Result},
+};
+
+/// The error returned when parsing a response sent by the terminal fails.
+///
+/// See [`CursorPositionReport::parse()`](crate::cursor::CursorPositionReport::parse),
+/// [`TextAreaSizeReport::parse()`](crate::window::TextAreaSizeReport::parse) and
+/// [`DefaultColorReport::parse()`](crate::osc::DefaultColorReport::parse).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseResponseError;
+
+impl Display for ParseResponseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("invalid terminal response")
+    }
+}
+
+impl Error for ParseResponseError {}
+
+/// Parses a CSI response like `ESC [ 12 ; 34 R`, returning the numeric parameters.
+pub(crate) fn parse_csi_response<const N: usize>(
+    response: &str,
+    final_byte: char,
+) -> core::result::Result<[u16; N], ParseResponseError> {
+    let params = response
+        .strip_prefix("\x1b[")
+        .and_then(|response| response.strip_suffix(final_byte))
+        .ok_or(ParseResponseError)?;
+
+    let mut values = [0; N];
+    let mut params = params.split(';');
+    for value in &mut values {
+        let param = params.next().ok_or(ParseResponseError)?;
+        if param.is_empty() || !param.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseResponseError);
+        }
+        *value = param.parse().map_err(|_| ParseResponseError)?;
+    }
+    if params.next().is_some() {
+        return Err(ParseResponseError);
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csi_response() {
+        assert_eq!(parse_csi_response("\x1b[12;34R", 'R'), Ok([12, 34]));
+        assert_eq!(parse_csi_response("\x1b[8;24;80t", 't'), Ok([8, 24, 80]));
+
+        for invalid in [
+            "\x1b[12;34",
+            "[12;34R",
+            "\x1b[12R",
+            "\x1b[12;34;56R",
+            "\x1b[12;R",
+            "\x1b[+12;34R",
+            "\x1b[12;70000R",
+            "\x1b[12;34t",
+        ] {
+            assert_eq!(
+                parse_csi_response::<2>(invalid, 'R'),
+                Err(ParseResponseError),
+                "{invalid:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use core::fmt::{Display, Formatter, Result, Write};
+
+use crate::width::{visible_width, write_padded};
+
+/// A value that renders its content with the control characters made harmless.
+///
+/// Content that comes from an untrusted source, like user input or the contents of a file, may contain escape
+/// sequences that change the styling, move the cursor or hide the text that follows them when written to a terminal.
+/// Wrapping it in a `Sanitized` value before styling it escapes those characters, so they are rendered as visible text:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, Sanitized};
+///
+/// let user_name = "eve\x1b[2K\rroot";
+/// assert_eq!(
+///     format!("{}", Color::CYAN.applied_to(Sanitized::new(user_name))),
+///     "\x1b[36meve\\u{1b}[2K\\rroot\x1b[0m"
+/// );
+/// ```
+///
+/// The control characters are the C0 and C1 ones, as well as `DEL`. They are escaped like in
+/// [`char::escape_default()`], except for line breaks and tabs, which are kept. With
+/// [`strip()`](Sanitized::strip), they are removed instead.
+///
+/// Width, fill and alignment are honored, and apply to the sanitized rendering.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Sanitized<C> {
+    content: C,
+    strip: bool,
+}
+
+impl<C> Sanitized<C> {
+    /// Creates a new `Sanitized` value with the given content, which escapes the control characters.
+    #[must_use]
+    pub const fn new(content: C) -> Self {
+        Self {
+            content,
+            strip: false,
+        }
+    }
+
+    /// Gets the content.
+    #[must_use]
+    pub const fn get_content(&self) -> &C {
+        &self.content
+    }
+
+    /// Returns a new value that removes the control characters instead of escaping them, or not.
+    ///
+    /// ```
+    /// use fluent_ansi::Sanitized;
+    ///
+    /// assert_eq!(format!("{}", Sanitized::new("a\x1b[31mb").strip(true)), "a[31mb");
+    /// ```
+    #[must_use]
+    pub fn strip(self, enabled: bool) -> Self {
+        Self {
+            strip: enabled,
+            ..self
+        }
+    }
+
+    /// Returns whether the control characters are removed instead of escaped.
+    #[must_use]
+    pub const fn get_strip(&self) -> bool {
+        self.strip
+    }
+}
+
+impl<C: Display> Display for Sanitized<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let unpadded = Unpadded(self);
+        if f.width().is_none() {
+            return write!(f, "{unpadded}");
+        }
+        write_padded(f, visible_width(&unpadded), |f| write!(f, "{unpadded}"))
+    }
+}
+
+/// Renders a [`Sanitized`] value ignoring the formatting options.
+struct Unpadded<'a, C>(&'a Sanitized<C>);
+
+impl<C: Display> Display for Unpadded<'_, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut sanitizer = Sanitizer {
+            inner: f,
+            strip: self.0.strip,
+        };
+        write!(sanitizer, "{}", self.0.content)
+    }
+}
+
+/// A writer adapter that escapes or removes the control characters written through it.
+struct Sanitizer<'a, W: Write> {
+    inner: &'a mut W,
+    strip: bool,
+}
+
+impl<W: Write> Write for Sanitizer<'_, W> {
+    fn write_str(&mut self, s: &str) -> Result {
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            if !c.is_control() || c == '\n' || c == '\t' {
+                continue;
+            }
+            self.inner.write_str(&s[start..i])?;
+            if !self.strip {
+                write!(self.inner, "{}", c.escape_default())?;
+            }
+            start = i + c.len_utf8();
+        }
+        self.inner.write_str(&s[start..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, Styled, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn escape() {
+        assert_eq!(Sanitized::new("plain").to_string(), "plain");
+        assert_eq!(
+            Sanitized::new("a\x1b]0;title\x07b").to_string(),
+            "a\\u{1b}]0;title\\u{7}b"
+        );
+        assert_eq!(
+            Sanitized::new("\r\x7f\u{9b}31m").to_string(),
+            "\\r\\u{7f}\\u{9b}31m"
+        );
+        assert_eq!(Sanitized::new("a\tb\nc").to_string(), "a\tb\nc");
+        assert_eq!(Sanitized::new(42).to_string(), "42");
+    }
+
+    #[test]
+    fn strip() {
+        let sanitized = Sanitized::new("a\x1b[1mb\x07\tc").strip(true);
+        assert!(sanitized.get_strip());
+        assert_eq!(sanitized.get_content(), &"a\x1b[1mb\x07\tc");
+        assert_eq!(sanitized.to_string(), "a[1mb\tc");
+        assert!(!sanitized.strip(false).get_strip());
+    }
+
+    #[test]
+    fn nested_styled_content_is_escaped() {
+        let inner = Styled::new("x").bold();
+        assert_eq!(Sanitized::new(inner).to_string(), "\\u{1b}[1mx\\u{1b}[0m");
+    }
+
+    #[test]
+    fn padding() {
+        let sanitized = Sanitized::new("a\x1b");
+        assert_eq!(format!("{sanitized:8}"), "a\\u{1b} ");
+        assert_eq!(format!("{sanitized:>8}"), " a\\u{1b}");
+        assert_eq!(format!("{:4}", sanitized.strip(true)), "a   ");
+        assert_eq!(
+            Color::RED.applied_to(Sanitized::new("\x1b")).to_string(),
+            "\x1b[31m\\u{1b}\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Scrolling sequences.
+//!
+//! The values in this module render CSI sequences that set the scroll region and scroll its content when formatted
+//! with [`Display`]. For example, a pane with a pinned header:
+//!
+//! ```
+//! use fluent_ansi::{cursor, scroll::{ResetScrollRegion, ScrollRegion}};
+//!
+//! // Keep the first two rows in place
+//! let setup = format!("{}{}", ScrollRegion::new(3, 24), cursor::MoveTo::new(24, 1));
+//! assert_eq!(setup, "\x1b[3;24r\x1b[24;1H");
+//!
+//! assert_eq!(format!("{ResetScrollRegion}"), "\x1b[r");
+//! ```
+//!
+//! Rows are 1-based, like in the escape sequences themselves. Scrolling by zero lines renders nothing, since
+//! terminals treat a zero count as one.
+
+use core::fmt::{Display, Formatter, Result};
+
+use crate::sequence::fixed_sequence;
+
+/// Restricts scrolling to the rows from `top` to `bottom`, inclusive (DECSTBM).
+///
+/// Terminals move the cursor to the home position when the scroll region is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScrollRegion {
+    /// The first row of the region, starting at 1.
+    pub top: u16,
+    /// The last row of the region, starting at 1.
+    pub bottom: u16,
+}
+
+impl ScrollRegion {
+    /// Creates a value that restricts scrolling to the rows from `top` to `bottom`, inclusive.
+    #[must_use]
+    pub const fn new(top: u16, bottom: u16) -> Self {
+        ScrollRegion { top, bottom }
+    }
+}
+
+impl Display for ScrollRegion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "\x1b[{};{}r", self.top, self.bottom)
+    }
+}
+
+fixed_sequence!(
+    /// Resets the scroll region to the whole display (DECSTBM without parameters).
+    ResetScrollRegion,
+    "\x1b[r"
+);
+
+/// Scrolls the content of the scroll region up by the given number of lines, adding blank lines at the bottom (SU).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScrollUp(pub u16);
+
+impl Display for ScrollUp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_scroll(f, self.0, 'S')
+    }
+}
+
+/// Scrolls the content of the scroll region down by the given number of lines, adding blank lines at the top (SD).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScrollDown(pub u16);
+
+impl Display for ScrollDown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_scroll(f, self.0, 'T')
+    }
+}
+
+fn write_scroll(f: &mut Formatter<'_>, lines: u16, final_byte: char) -> Result {
+    if lines == 0 {
+        Ok(())
+    } else {
+        write!(f, "\x1b[{lines}{final_byte}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_display;
+
+    use super::*;
+
+    #[test]
+    fn scroll_region() {
+        assert_display!(ScrollRegion::new(2, 10), "\x1b[2;10r");
+        assert_display!(ResetScrollRegion, "\x1b[r");
+    }
+
+    #[test]
+    fn scroll() {
+        assert_display!(ScrollUp(1), "\x1b[1S");
+        assert_display!(ScrollDown(3), "\x1b[3T");
+        assert_display!(ScrollUp(0), "");
+        assert_display!(ScrollDown(0), "");
+    }
+}
@@ -0,0 +1,16 @@
+/// Defines a unit struct that renders a fixed escape sequence.
+macro_rules! fixed_sequence {
+    ($(#[$attr:meta])* $name:ident, $sequence:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name;
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str($sequence)
+            }
+        }
+    };
+}
+
+pub(crate) use fixed_sequence;
@@ -0,0 +1,367 @@
+//! Implementations of [`Serialize`] and [`Deserialize`], enabled by the `serde` feature.
+//!
+//! Values are represented with human-friendly strings:
+//! - [`Style`] as its specification string (see [`Style::from_spec()`]), like `"bold red on blue"`. Styles with a
+//!   [custom code](crate::CustomCode) can't be serialized;
+//! - colors as their specification strings (see [`Color::to_spec()`]), like `"red"`, `"123"` or `"#00ff88"`;
+//! - [`Effect`] as its name in the specification grammar, like `"curly-underline"`;
+//! - [`UnderlineStyle`] as `"solid"`, `"curly"`, `"dotted"`, `"dashed"` or `"double"`;
+//! - [`TargetedColor`] as its color, prefixed with `on` for the background or `under` for the underline, like `"on red"`;
+//! - [`ColorTarget`] as `"foreground"`, `"background"` or `"underline"`;
+//! - [`Theme`] as a map from key names (like `"error"`) to style specification strings.
+
+use core::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, MapAccess, Unexpected, Visitor},
+    ser::{self, SerializeMap as _},
+};
+
+use crate::{
+    ColorTarget, Effect, Style, TargetedColor, UnderlineStyle,
+    color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor},
+    style::{effect_name, parse_effect_name},
+    theme::{Key, Theme},
+};
+
+struct ParseVisitor<T> {
+    expecting: &'static str,
+    parse: fn(&str) -> Option<T>,
+}
+
+impl<T> Visitor<'_> for ParseVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        (self.parse)(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+fn deserialize_str<'de, D: Deserializer<'de>, T>(
+    deserializer: D,
+    expecting: &'static str,
+    parse: fn(&str) -> Option<T>,
+) -> Result<T, D::Error> {
+    deserializer.deserialize_str(ParseVisitor { expecting, parse })
+}
+
+macro_rules! impl_serde_via_str {
+    ($ty:ty, $expecting:literal, |$ser_value:ident| $to_str:expr, |$de_str:ident| $from_str:expr $(,)?) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let $ser_value = *self;
+                serializer.collect_str(&$to_str)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_str(deserializer, $expecting, |$de_str| $from_str)
+            }
+        }
+    };
+}
+
+/// Serializes a style as its specification string.
+///
+/// A [custom code](crate::CustomCode) has no representation in the specification, so serializing a style that has
+/// one fails instead of losing it.
+impl Serialize for Style {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.custom_code.is_some() {
+            return Err(ser::Error::custom(
+                "a style with a custom code can't be serialized",
+            ));
+        }
+        serializer.collect_str(&self.to_spec())
+    }
+}
+
+impl<'de> Deserialize<'de> for Style {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(deserializer, "a style specification", |s| {
+            Style::from_spec(s).ok()
+        })
+    }
+}
+
+impl_serde_via_str!(Color, "a color", |color| color.to_spec(), |s| s
+    .parse()
+    .ok(),);
+
+impl_serde_via_str!(
+    BasicColor,
+    "a basic color",
+    |color| Color::from(color).to_spec(),
+    |s| match s.parse() {
+        Ok(Color::Simple(simple)) if !simple.is_bright() => Some(simple.get_basic_color()),
+        _ => None,
+    },
+);
+
+impl_serde_via_str!(
+    SimpleColor,
+    "a simple color",
+    |color| Color::from(color).to_spec(),
+    |s| match s.parse() {
+        Ok(Color::Simple(simple)) => Some(simple),
+        _ => None,
+    },
+);
+
+impl_serde_via_str!(
+    IndexedColor,
+    "an indexed color",
+    |color| Color::from(color).to_spec(),
+    |s| match s.parse() {
+        Ok(Color::Indexed(indexed)) => Some(indexed),
+        _ => None,
+    },
+);
+
+impl_serde_via_str!(
+    RGBColor,
+    "an RGB color",
+    |color| Color::from(color).to_spec(),
+    |s| match s.parse() {
+        Ok(Color::RGB(rgb)) => Some(rgb),
+        _ => None,
+    },
+);
+
+impl_serde_via_str!(
+    Effect,
+    "an effect name",
+    |effect| effect_name(effect),
+    |s| parse_effect_name(s),
+);
+
+const UNDERLINE_STYLE_NAMES: [(&str, UnderlineStyle); 5] = [
+    ("solid", UnderlineStyle::Solid),
+    ("curly", UnderlineStyle::Curly),
+    ("dotted", UnderlineStyle::Dotted),
+    ("dashed", UnderlineStyle::Dashed),
+    ("double", UnderlineStyle::Double),
+];
+
+impl_serde_via_str!(
+    UnderlineStyle,
+    "an underline style name",
+    |underline_style| UNDERLINE_STYLE_NAMES
+        .iter()
+        .find(|&&(_, u)| u == underline_style)
+        .map_or("", |&(name, _)| name),
+    |s| UNDERLINE_STYLE_NAMES
+        .iter()
+        .find(|(name, _)| s.eq_ignore_ascii_case(name))
+        .map(|&(_, underline_style)| underline_style),
+);
+
+const COLOR_TARGET_NAMES: [(&str, ColorTarget); 3] = [
+    ("foreground", ColorTarget::Foreground),
+    ("background", ColorTarget::Background),
+    ("underline", ColorTarget::Underline),
+];
+
+impl_serde_via_str!(
+    ColorTarget,
+    "a color target name",
+    |target| COLOR_TARGET_NAMES
+        .iter()
+        .find(|&&(_, t)| t == target)
+        .map_or("", |&(name, _)| name),
+    |s| COLOR_TARGET_NAMES
+        .iter()
+        .find(|(name, _)| s.eq_ignore_ascii_case(name))
+        .map(|&(_, target)| target),
+);
+
+impl Serialize for TargetedColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let prefix = match self.get_target() {
+            ColorTarget::Foreground => "",
+            ColorTarget::Background => "on ",
+            ColorTarget::Underline => "under ",
+        };
+        serializer.collect_str(&format_args!("{prefix}{}", self.get_color().to_spec()))
+    }
+}
+
+impl<'de> Deserialize<'de> for TargetedColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(
+            deserializer,
+            "a color, optionally prefixed with `on` or `under`",
+            |s| {
+                let (target, color) = match s.split_once(' ') {
+                    Some(("on", color)) => (ColorTarget::Background, color),
+                    Some(("under", color)) => (ColorTarget::Underline, color),
+                    _ => (ColorTarget::Foreground, s),
+                };
+                let color: Color = color.trim_start().parse().ok()?;
+                Some(TargetedColor::new(color, target))
+            },
+        )
+    }
+}
+
+impl Serialize for Key {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(deserializer, "a theme key name", Key::from_name)
+    }
+}
+
+/// Serializes a theme as a map with an entry for each key that has a style.
+impl Serialize for Theme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.iter().count()))?;
+        for (key, style) in self {
+            map.serialize_entry(&key, &style)?;
+        }
+        map.end()
+    }
+}
+
+/// Deserializes a theme from a map. The keys missing in the map have no entry in the theme.
+///
+/// To use the entries as overrides for another theme, use [`Theme::merge()`]:
+///
+/// ```
+/// # use fluent_ansi::{prelude::*, theme::{Key, Theme}};
+/// let overrides: Theme = serde_json::from_str(r#"{ "error": "bold magenta" }"#).unwrap();
+/// let theme = Theme::default().merge(&overrides);
+///
+/// assert_eq!(theme.style(Key::Error), Color::MAGENTA.bold());
+/// assert_eq!(theme.style(Key::Warning), Theme::default().style(Key::Warning));
+/// ```
+impl<'de> Deserialize<'de> for Theme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ThemeVisitor(PhantomData<Theme>);
+
+        impl<'de> Visitor<'de> for ThemeVisitor {
+            type Value = Theme;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("a map from theme keys to style specifications")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut theme = Theme::new();
+                while let Some((key, style)) = map.next_entry::<Key, Style>()? {
+                    theme = theme.with(key, style);
+                }
+                Ok(theme)
+            }
+        }
+
+        deserializer.deserialize_map(ThemeVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeOwned;
+
+    use crate::{CustomCode, ToStyleSet as _, color::ColorKind as _};
+
+    use super::*;
+
+    fn assert_round_trip<T>(value: &T, json: &str)
+    where
+        T: Serialize + DeserializeOwned + PartialEq + fmt::Debug,
+    {
+        assert_eq!(serde_json::to_string(value).unwrap(), json);
+        assert_eq!(&serde_json::from_str::<T>(json).unwrap(), value);
+    }
+
+    #[test]
+    fn style() {
+        assert_round_trip(&Style::new(), r#""""#);
+        assert_round_trip(
+            &Style::new().bold().fg(BasicColor::Red).bg(BasicColor::Blue),
+            r#""bold red on blue""#,
+        );
+        assert!(serde_json::from_str::<Style>(r#""bold on""#).is_err());
+        assert!(serde_json::from_str::<Style>("1").is_err());
+    }
+
+    #[test]
+    fn style_with_custom_code() {
+        let style = Style::new().bold().add(CustomCode::new("60"));
+        let error = serde_json::to_string(&style).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "a style with a custom code can't be serialized"
+        );
+        assert!(serde_json::to_string(&Theme::new().with(Key::Error, style)).is_err());
+    }
+
+    #[test]
+    fn colors() {
+        assert_round_trip(&BasicColor::Red.to_color(), r#""red""#);
+        assert_round_trip(&Color::Default, r#""default""#);
+        assert_round_trip(&BasicColor::Red, r#""red""#);
+        assert_round_trip(&BasicColor::Red.bright(), r#""bright-red""#);
+        assert_round_trip(&IndexedColor(42), r#""42""#);
+        assert_round_trip(&RGBColor::new(0, 128, 255), r##""#0080ff""##);
+
+        assert!(serde_json::from_str::<BasicColor>(r#""bright-red""#).is_err());
+        assert!(serde_json::from_str::<IndexedColor>(r#""red""#).is_err());
+        assert!(serde_json::from_str::<RGBColor>(r#""42""#).is_err());
+        assert!(serde_json::from_str::<Color>(r#""nope""#).is_err());
+    }
+
+    #[test]
+    fn effect() {
+        assert_round_trip(&Effect::Bold, r#""bold""#);
+        assert_round_trip(&Effect::CurlyUnderline, r#""curly-underline""#);
+        assert!(serde_json::from_str::<Effect>(r#""boldd""#).is_err());
+    }
+
+    #[test]
+    fn underline_style() {
+        assert_round_trip(&UnderlineStyle::Solid, r#""solid""#);
+        assert_round_trip(&UnderlineStyle::Double, r#""double""#);
+        assert!(serde_json::from_str::<UnderlineStyle>(r#""wavy""#).is_err());
+    }
+
+    #[test]
+    fn color_target() {
+        assert_round_trip(&ColorTarget::Foreground, r#""foreground""#);
+        assert_round_trip(&ColorTarget::Background, r#""background""#);
+        assert_round_trip(&ColorTarget::Underline, r#""underline""#);
+    }
+
+    #[test]
+    fn targeted_color() {
+        assert_round_trip(&BasicColor::Red.for_fg(), r#""red""#);
+        assert_round_trip(&BasicColor::Red.for_bg(), r#""on red""#);
+        assert_round_trip(&IndexedColor(42).for_underline(), r#""under 42""#);
+        assert!(serde_json::from_str::<TargetedColor>(r#""on""#).is_err());
+    }
+
+    #[test]
+    fn theme() {
+        let theme = Theme::new()
+            .with(Key::Error, BasicColor::Red.bold())
+            .with(Key::Muted, Style::new().faint());
+        assert_round_trip(&theme, r#"{"error":"bold red","muted":"faint"}"#);
+
+        assert!(serde_json::from_str::<Theme>(r#"{"nope":"bold"}"#).is_err());
+        assert!(serde_json::from_str::<Theme>(r#"{"error":"nope"}"#).is_err());
+    }
+}
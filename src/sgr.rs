@@ -0,0 +1,495 @@
+use core::ops::Range;
+
+use crate::{
+    ColorTarget, Effect, ParseStyleError, ParseStyleErrorKind, Style, StyleSet as _, Underline,
+    UnderlineStyle,
+    color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor},
+};
+
+impl Style {
+    /// Creates a style from a list of SGR parameters, as found between `ESC [` and `m` in an escape sequence.
+    ///
+    /// The parameters are applied in order to an empty style, so `0` resets the style built so far, and a
+    /// parameter like `22` or `39` clears an attribute. Extended colors are accepted both in the semicolon form
+    /// (`38;5;n`, `38;2;r;g;b`) and in the colon form (`38:5:n`, `38:2::r:g:b`), as are the underline style
+    /// forms (`4:0` to `4:5`). An empty parameter is interpreted as `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a parameter is invalid or not supported.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let style = Style::from_sgr_params("1;4:3;38;2;0;128;255").unwrap();
+    /// assert_eq!(style, Style::new().bold().curly_underline().fg(Color::rgb(0, 128, 255)));
+    ///
+    /// assert!(Style::from_sgr_params("1;x").is_err());
+    /// ```
+    pub fn from_sgr_params(params: &str) -> Result<Style, ParseStyleError> {
+        apply_params(Style::new(), params)
+    }
+
+    /// Creates a style from a list of numeric SGR parameters.
+    ///
+    /// It works like [`Style::from_sgr_params()`], except that the colon forms can't be represented.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a parameter is not supported, or an extended color is incomplete. The
+    /// [span](ParseStyleError::span) of the error is the range of indexes of the offending codes.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let style = Style::from_codes(&[1, 31, 48, 5, 236]).unwrap();
+    /// assert_eq!(style, Style::new().bold().fg(Color::RED).bg(Color::indexed(236)));
+    ///
+    /// assert!(Style::from_codes(&[38, 5]).is_err());
+    /// ```
+    pub fn from_codes(codes: &[u8]) -> Result<Style, ParseStyleError> {
+        let mut style = Style::new();
+        let mut codes = codes
+            .iter()
+            .enumerate()
+            .map(|(index, &code)| (index..index + 1, Some(code)));
+
+        while let Some((span, code)) = codes.next() {
+            let code = code.ok_or(ParseStyleError::new(
+                ParseStyleErrorKind::InvalidParameter,
+                span.clone(),
+            ))?;
+            style = apply_param(style, code, span, &mut codes)?;
+        }
+
+        Ok(style)
+    }
+}
+
+/// Applies a list of SGR parameters, as found between `ESC [` and `m`, to the given style.
+///
+/// Returns an error if any parameter is invalid or unsupported. Its span is relative to `params`.
+pub(crate) fn apply_params(style: Style, params: &str) -> Result<Style, ParseStyleError> {
+    let mut style = style;
+    let mut offset = 0;
+    let mut params = params.split(';').map(|param| {
+        let span = offset..offset + param.len();
+        offset = span.end + 1;
+        (span, param)
+    });
+
+    while let Some((span, param)) = params.next() {
+        let invalid = || ParseStyleError::new(ParseStyleErrorKind::InvalidParameter, span.clone());
+        style = if param.contains(':') {
+            apply_colon_param(style, param).ok_or_else(invalid)?
+        } else {
+            let code = parse_number(param).ok_or_else(invalid)?;
+            apply_param(
+                style,
+                code,
+                span,
+                &mut params
+                    .by_ref()
+                    .map(|(span, param)| (span, parse_number(param))),
+            )?
+        };
+    }
+
+    Ok(style)
+}
+
+/// Applies a single parameter, found at `span`. The extended color forms take their sub-parameters from `params`,
+/// where a `None` number represents an invalid parameter.
+fn apply_param(
+    style: Style,
+    code: u8,
+    span: Range<usize>,
+    params: &mut impl Iterator<Item = (Range<usize>, Option<u8>)>,
+) -> Result<Style, ParseStyleError> {
+    let style = match code {
+        0 => Style::new(),
+        1 => style.set_effect(Effect::Bold, true),
+        2 => style.set_effect(Effect::Faint, true),
+        3 => style.set_effect(Effect::Italic, true),
+        4 => style.set_effect(Effect::Underline, true),
+        5 => style.set_effect(Effect::Blink, true),
+        6 => style.set_effect(Effect::RapidBlink, true),
+        7 => style.set_effect(Effect::Reverse, true),
+        8 => style.set_effect(Effect::Conceal, true),
+        9 => style.set_effect(Effect::Strikethrough, true),
+        20 => style.set_effect(Effect::Fraktur, true),
+        21 => style.set_effect(Effect::DoubleUnderline, true),
+        22 => style
+            .set_effect(Effect::Bold, false)
+            .set_effect(Effect::Faint, false),
+        23 => style
+            .set_effect(Effect::Italic, false)
+            .set_effect(Effect::Fraktur, false),
+        24 => style.set(Underline, None),
+        25 => style
+            .set_effect(Effect::Blink, false)
+            .set_effect(Effect::RapidBlink, false),
+        27 => style.set_effect(Effect::Reverse, false),
+        28 => style.set_effect(Effect::Conceal, false),
+        29 => style.set_effect(Effect::Strikethrough, false),
+        30..=37 => style.set_color(
+            ColorTarget::Foreground,
+            Some(simple_color(code - 30, false)),
+        ),
+        38 => style.set_color(ColorTarget::Foreground, Some(extended_color(span, params)?)),
+        39 => style.set_color(ColorTarget::Foreground, Color::none()),
+        40..=47 => style.set_color(
+            ColorTarget::Background,
+            Some(simple_color(code - 40, false)),
+        ),
+        48 => style.set_color(ColorTarget::Background, Some(extended_color(span, params)?)),
+        49 => style.set_color(ColorTarget::Background, Color::none()),
+        51 => style.set_effect(Effect::Framed, true),
+        52 => style.set_effect(Effect::Encircled, true),
+        53 => style.set_effect(Effect::Overline, true),
+        54 => style
+            .set_effect(Effect::Framed, false)
+            .set_effect(Effect::Encircled, false),
+        55 => style.set_effect(Effect::Overline, false),
+        58 => style.set_color(ColorTarget::Underline, Some(extended_color(span, params)?)),
+        59 => style.set_color(ColorTarget::Underline, Color::none()),
+        73 => style.set_effect(Effect::Superscript, true),
+        74 => style.set_effect(Effect::Subscript, true),
+        75 => style
+            .set_effect(Effect::Superscript, false)
+            .set_effect(Effect::Subscript, false),
+        90..=97 => style.set_color(ColorTarget::Foreground, Some(simple_color(code - 90, true))),
+        100..=107 => style.set_color(
+            ColorTarget::Background,
+            Some(simple_color(code - 100, true)),
+        ),
+        _ => {
+            return Err(ParseStyleError::new(
+                ParseStyleErrorKind::InvalidParameter,
+                span,
+            ));
+        }
+    };
+    Ok(style)
+}
+
+fn apply_colon_param(style: Style, param: &str) -> Option<Style> {
+    let mut subparams = param.split(':');
+    let code = parse_number(subparams.next()?)?;
+
+    let style = match code {
+        4 => {
+            let underline_style = match parse_number(subparams.next()?)? {
+                0 => None,
+                1 => Some(UnderlineStyle::Solid),
+                2 => Some(UnderlineStyle::Double),
+                3 => Some(UnderlineStyle::Curly),
+                4 => Some(UnderlineStyle::Dotted),
+                5 => Some(UnderlineStyle::Dashed),
+                _ => return None,
+            };
+            style.set(Underline, underline_style)
+        }
+        38 | 48 | 58 => {
+            let target = match code {
+                38 => ColorTarget::Foreground,
+                48 => ColorTarget::Background,
+                _ => ColorTarget::Underline,
+            };
+            let color: Color = match parse_number(subparams.next()?)? {
+                5 => IndexedColor(parse_number(subparams.next()?)?).into(),
+                2 => {
+                    // The color space identifier is optional in the colon form.
+                    let mut components = [0u8; 4];
+                    let mut count = 0;
+                    for subparam in subparams.by_ref() {
+                        *components.get_mut(count)? = parse_number(subparam)?;
+                        count += 1;
+                    }
+                    let [r, g, b] = match count {
+                        3 => [components[0], components[1], components[2]],
+                        4 => [components[1], components[2], components[3]],
+                        _ => return None,
+                    };
+                    RGBColor::new(r, g, b).into()
+                }
+                _ => return None,
+            };
+            style.set_color(target, Some(color))
+        }
+        _ => return None,
+    };
+
+    subparams.next().is_none().then_some(style)
+}
+
+/// Parses the sub-parameters of the extended color parameter found at `span`.
+fn extended_color(
+    span: Range<usize>,
+    params: &mut impl Iterator<Item = (Range<usize>, Option<u8>)>,
+) -> Result<Color, ParseStyleError> {
+    let start = span.start;
+    let mut end = span.end;
+    let mut next_number = || {
+        let (span, number) = params.next().ok_or(ParseStyleError::new(
+            ParseStyleErrorKind::IncompleteColor,
+            start..end,
+        ))?;
+        end = span.end;
+        number.ok_or(ParseStyleError::new(
+            ParseStyleErrorKind::InvalidParameter,
+            span,
+        ))
+    };
+
+    match next_number()? {
+        5 => Ok(IndexedColor(next_number()?).into()),
+        2 => Ok(RGBColor::new(next_number()?, next_number()?, next_number()?).into()),
+        _ => Err(ParseStyleError::new(
+            ParseStyleErrorKind::InvalidParameter,
+            start..end,
+        )),
+    }
+}
+
+fn simple_color(offset: u8, bright: bool) -> Color {
+    let basic_color = match offset {
+        0 => BasicColor::Black,
+        1 => BasicColor::Red,
+        2 => BasicColor::Green,
+        3 => BasicColor::Yellow,
+        4 => BasicColor::Blue,
+        5 => BasicColor::Magenta,
+        6 => BasicColor::Cyan,
+        _ => BasicColor::White,
+    };
+    let simple_color = SimpleColor::new(basic_color);
+    if bright {
+        simple_color.bright().into()
+    } else {
+        simple_color.into()
+    }
+}
+
+/// Parses a numeric parameter. An empty parameter is interpreted as zero.
+fn parse_number(param: &str) -> Option<u8> {
+    if param.is_empty() {
+        Some(0)
+    } else if param.bytes().all(|b| b.is_ascii_digit()) {
+        param.parse().ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToStyleSet as _;
+
+    use super::*;
+
+    fn parse(params: &str) -> Result<Style, ParseStyleError> {
+        apply_params(Style::new(), params)
+    }
+
+    #[test]
+    fn reset() {
+        assert_eq!(parse(""), Ok(Style::new()));
+        assert_eq!(parse("0"), Ok(Style::new()));
+        assert_eq!(apply_params(Style::new().bold(), "0"), Ok(Style::new()));
+        assert_eq!(
+            apply_params(Style::new().bold(), "0;3"),
+            Ok(Style::new().italic())
+        );
+    }
+
+    #[test]
+    fn effects() {
+        assert_eq!(parse("1"), Ok(Style::new().bold()));
+        assert_eq!(parse("01"), Ok(Style::new().bold()));
+        assert_eq!(parse("2"), Ok(Style::new().faint()));
+        assert_eq!(parse("3"), Ok(Style::new().italic()));
+        assert_eq!(parse("4"), Ok(Style::new().underline()));
+        assert_eq!(parse("4:3"), Ok(Style::new().curly_underline()));
+        assert_eq!(parse("4:4"), Ok(Style::new().dotted_underline()));
+        assert_eq!(parse("4:5"), Ok(Style::new().dashed_underline()));
+        assert_eq!(parse("5"), Ok(Style::new().blink()));
+        assert_eq!(parse("6"), Ok(Style::new().rapid_blink()));
+        assert_eq!(parse("5;6"), Ok(Style::new().rapid_blink()));
+        assert_eq!(parse("7"), Ok(Style::new().reverse()));
+        assert_eq!(parse("8"), Ok(Style::new().conceal()));
+        assert_eq!(parse("9"), Ok(Style::new().strikethrough()));
+        assert_eq!(parse("21"), Ok(Style::new().double_underline()));
+        assert_eq!(parse("20"), Ok(Style::new().fraktur()));
+        assert_eq!(parse("51"), Ok(Style::new().framed()));
+        assert_eq!(parse("52"), Ok(Style::new().encircled()));
+        assert_eq!(parse("53"), Ok(Style::new().overline()));
+        assert_eq!(parse("73"), Ok(Style::new().superscript()));
+        assert_eq!(parse("74"), Ok(Style::new().subscript()));
+        assert_eq!(parse("1;3"), Ok(Style::new().bold().italic()));
+    }
+
+    #[test]
+    fn effects_off() {
+        let all = Style::new()
+            .bold()
+            .faint()
+            .italic()
+            .curly_underline()
+            .blink()
+            .reverse()
+            .conceal()
+            .strikethrough()
+            .fraktur()
+            .framed()
+            .encircled()
+            .overline()
+            .superscript();
+
+        assert_eq!(
+            apply_params(all, "22;23;24;25;27;28;29;54;55;75"),
+            Ok(Style::new())
+        );
+        assert_eq!(
+            apply_params(Style::new().double_underline(), "4:0"),
+            Ok(Style::new())
+        );
+        assert_eq!(
+            apply_params(Style::new().subscript(), "75"),
+            Ok(Style::new())
+        );
+        assert_eq!(
+            apply_params(Style::new().rapid_blink(), "25"),
+            Ok(Style::new())
+        );
+    }
+
+    #[test]
+    fn simple_colors() {
+        assert_eq!(parse("31"), Ok(Style::new().fg(BasicColor::Red)));
+        assert_eq!(parse("41"), Ok(Style::new().bg(BasicColor::Red)));
+        assert_eq!(parse("91"), Ok(Style::new().fg(BasicColor::Red.bright())));
+        assert_eq!(parse("101"), Ok(Style::new().bg(BasicColor::Red.bright())));
+        assert_eq!(
+            parse("30;47"),
+            Ok(Style::new().fg(BasicColor::Black).bg(BasicColor::White))
+        );
+    }
+
+    #[test]
+    fn extended_colors() {
+        assert_eq!(parse("38;5;42"), Ok(Style::new().fg(IndexedColor(42))));
+        assert_eq!(parse("48;5;42"), Ok(Style::new().bg(IndexedColor(42))));
+        assert_eq!(
+            parse("58;5;42"),
+            Ok(Style::new().underline_color(IndexedColor(42)))
+        );
+        assert_eq!(
+            parse("38;2;0;128;255"),
+            Ok(Style::new().fg(RGBColor::new(0, 128, 255)))
+        );
+        assert_eq!(
+            parse("1;48;2;0;128;255;4"),
+            Ok(Style::new()
+                .bold()
+                .underline()
+                .bg(RGBColor::new(0, 128, 255)))
+        );
+        assert_eq!(parse("38:5:42"), Ok(Style::new().fg(IndexedColor(42))));
+        assert_eq!(
+            parse("48:2::0:128:255"),
+            Ok(Style::new().bg(RGBColor::new(0, 128, 255)))
+        );
+        assert_eq!(
+            parse("58:2:0:128:255"),
+            Ok(Style::new().underline_color(RGBColor::new(0, 128, 255)))
+        );
+    }
+
+    #[test]
+    fn colors_off() {
+        let style = Style::new()
+            .fg(BasicColor::Red)
+            .bg(BasicColor::Green)
+            .underline_color(BasicColor::Blue);
+        assert_eq!(apply_params(style, "39;49;59"), Ok(Style::new()));
+    }
+
+    #[test]
+    fn invalid() {
+        let invalid = |span| {
+            Err(ParseStyleError::new(
+                ParseStyleErrorKind::InvalidParameter,
+                span,
+            ))
+        };
+        let incomplete = |span| {
+            Err(ParseStyleError::new(
+                ParseStyleErrorKind::IncompleteColor,
+                span,
+            ))
+        };
+
+        assert_eq!(parse("x"), invalid(0..1));
+        assert_eq!(parse("1;x"), invalid(2..3));
+        assert_eq!(parse("256"), invalid(0..3));
+        assert_eq!(parse("1;;10"), invalid(3..5));
+        assert_eq!(parse("38"), incomplete(0..2));
+        assert_eq!(parse("1;38;5"), incomplete(2..6));
+        assert_eq!(parse("38;2;0;128"), incomplete(0..10));
+        assert_eq!(parse("38;3;0"), invalid(0..4));
+        assert_eq!(parse("38;5;x"), invalid(5..6));
+        assert_eq!(parse("4:6"), invalid(0..3));
+        assert_eq!(parse("1;4:3:1"), invalid(2..7));
+        assert_eq!(parse("38:2:0:128"), invalid(0..10));
+        assert_eq!(parse("38:2::0:128:255:1"), invalid(0..17));
+        assert_eq!(parse("1:1"), invalid(0..3));
+    }
+
+    #[test]
+    fn from_sgr_params() {
+        assert_eq!(Style::from_sgr_params(""), Ok(Style::new()));
+        assert_eq!(
+            Style::from_sgr_params("1;38:5:42"),
+            Ok(Style::new().bold().fg(IndexedColor(42)))
+        );
+        assert_eq!(Style::from_sgr_params("1;"), Ok(Style::new()));
+        assert_eq!(
+            Style::from_sgr_params("38;5"),
+            Err(ParseStyleError::new(
+                ParseStyleErrorKind::IncompleteColor,
+                0..4
+            ))
+        );
+    }
+
+    #[test]
+    fn from_codes() {
+        assert_eq!(Style::from_codes(&[]), Ok(Style::new()));
+        assert_eq!(
+            Style::from_codes(&[3, 4, 48, 2, 0, 128, 255, 59]),
+            Ok(Style::new()
+                .italic()
+                .underline()
+                .bg(RGBColor::new(0, 128, 255)))
+        );
+        assert_eq!(
+            Style::from_codes(&[1, 0, 91]),
+            Ok(Style::new().fg(BasicColor::Red.bright()))
+        );
+        assert_eq!(
+            Style::from_codes(&[1, 10]),
+            Err(ParseStyleError::new(
+                ParseStyleErrorKind::InvalidParameter,
+                1..2
+            ))
+        );
+        assert_eq!(
+            Style::from_codes(&[38, 2, 0, 128]),
+            Err(ParseStyleError::new(
+                ParseStyleErrorKind::IncompleteColor,
+                0..4
+            ))
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use core::{
+    fmt::{Display, Formatter, Result, Write as _},
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    Style,
+    parse::Sequence,
+    sgr,
+    spans::Transition,
+    width::char_width,
+    wrap::{Unit, Units},
+};
+
+/// Slices text containing escape sequences between visible columns, like for horizontal scrolling.
+///
+/// Only the characters that fit entirely within the range of columns are kept, so a wide character that is cut by
+/// either end is dropped. The style that is active at the start of the slice is applied before it, and reset at its
+/// end:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, slice_visible};
+///
+/// let text = format!("{} {}", Color::RED.applied_to("red"), Effect::Bold.applied_to("bold"));
+/// assert_eq!(
+///     slice_visible(&text, 2..6).to_string(),
+///     "\x1b[31md\x1b[0m \x1b[1mbo\x1b[0m"
+/// );
+/// ```
+///
+/// Other escape sequences and control characters are kept if they are within the slice.
+#[must_use]
+pub fn slice_visible(text: &str, range: impl RangeBounds<usize>) -> SliceVisible<'_> {
+    let (start, end) = column_bounds(&range);
+    SliceVisible { text, start, end }
+}
+
+/// Text sliced between visible columns.
+///
+/// It is returned by [`slice_visible()`], and renders the sliced text when formatted with [`Display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SliceVisible<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl SliceVisible<'_> {
+    fn contains(&self, column: usize) -> bool {
+        self.start <= column && column < self.end
+    }
+}
+
+impl Display for SliceVisible<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut column = 0;
+        let mut style = Style::new();
+        let mut written = Style::new();
+        for unit in Units(self.text) {
+            if column > self.end {
+                break;
+            }
+            match unit {
+                Unit::Sequence(Sequence::Sgr(params), raw) => {
+                    match sgr::apply_params(style, params) {
+                        Ok(applied) => style = applied,
+                        Err(_) if self.contains(column) => f.write_str(raw)?,
+                        Err(_) => {}
+                    }
+                }
+                Unit::Sequence(_, raw) if self.contains(column) => f.write_str(raw)?,
+                Unit::Sequence(..) => {}
+                Unit::Char(c) if c.is_control() => {
+                    if self.contains(column) {
+                        f.write_char(c)?;
+                    }
+                }
+                Unit::Char(c) => {
+                    let width = char_width(c);
+                    if column >= self.start && column + width <= self.end {
+                        write!(
+                            f,
+                            "{}",
+                            Transition {
+                                from: written,
+                                to: style
+                            }
+                        )?;
+                        written = style;
+                        f.write_char(c)?;
+                    }
+                    column += width;
+                }
+            }
+        }
+        if written != Style::new() {
+            write!(f, "{}", Style::new())?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a range of columns to its inclusive start and exclusive end.
+pub(crate) fn column_bounds(range: &impl RangeBounds<usize>) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => usize::MAX,
+    };
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(slice_visible("", ..).to_string(), "");
+        assert_eq!(slice_visible("abcdef", ..).to_string(), "abcdef");
+        assert_eq!(slice_visible("abcdef", 2..4).to_string(), "cd");
+        assert_eq!(slice_visible("abcdef", 2..=4).to_string(), "cde");
+        assert_eq!(slice_visible("abcdef", 4..).to_string(), "ef");
+        assert_eq!(slice_visible("abcdef", ..2).to_string(), "ab");
+        assert_eq!(slice_visible("abcdef", 10..20).to_string(), "");
+        assert_eq!(slice_visible("abcdef", 3..3).to_string(), "");
+    }
+
+    #[cfg(feature = "unicode-width")]
+    #[test]
+    fn wide_characters() {
+        assert_eq!(slice_visible("a日本b", 1..5).to_string(), "日本");
+        assert_eq!(slice_visible("a日本b", 2..5).to_string(), "本");
+        assert_eq!(slice_visible("a日本b", 1..4).to_string(), "日");
+    }
+
+    #[test]
+    fn styled_text() {
+        let text = Color::RED.bold().applied_to("abcdef").to_string();
+        assert_eq!(
+            slice_visible(&text, 1..3).to_string(),
+            "\x1b[1;31mbc\x1b[0m"
+        );
+
+        let text = format!(
+            "{}{}",
+            Color::RED.applied_to("abc"),
+            Color::BLUE.applied_to("def")
+        );
+        assert_eq!(slice_visible(&text, ..3).to_string(), "\x1b[31mabc\x1b[0m");
+        assert_eq!(slice_visible(&text, 3..).to_string(), "\x1b[34mdef\x1b[0m");
+        assert_eq!(
+            slice_visible(&text, 2..4).to_string(),
+            "\x1b[31mc\x1b[34md\x1b[0m"
+        );
+
+        assert_eq!(
+            slice_visible("\x1b[1mab\x1b[3mcd\x1b[0mef", 1..5).to_string(),
+            "\x1b[1mb\x1b[3mcd\x1b[0me"
+        );
+    }
+
+    #[test]
+    fn other_sequences() {
+        let text = "ab\x1b]8;;http://example.com\x1b\\cd\x1b]8;;\x1b\\ef";
+        assert_eq!(
+            slice_visible(text, 1..5).to_string(),
+            "b\x1b]8;;http://example.com\x1b\\cd\x1b]8;;\x1b\\e"
+        );
+        assert_eq!(slice_visible(text, 4..).to_string(), "\x1b]8;;\x1b\\ef");
+        assert_eq!(slice_visible("a\tb", 1..).to_string(), "\tb");
+    }
+}
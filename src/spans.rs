@@ -0,0 +1,294 @@
+use core::fmt::{Display, Formatter, Result};
+
+use crate::{
+    CodeWriter, ColorTarget, Effect, Style, StyleSet as _, Styled, color::Color,
+    width::visible_width,
+};
+
+/// A value that renders a sequence of styled spans, like a syntax-highlighted line.
+///
+/// Unlike rendering each [`Styled<C>`] value in turn, the styling is not reset after each span. Instead, only the
+/// changes from one span's style to the next one's are written, and the styling is reset once, at the end:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, Style, Styled, StyledSpans};
+///
+/// let keyword = Color::MAGENTA.bold();
+/// let spans = [
+///     keyword.applied_to("let"),
+///     Styled::new(" x = "),
+///     keyword.applied_to("true"),
+///     keyword.italic().applied_to(";"),
+/// ];
+/// assert_eq!(
+///     format!("{}", StyledSpans::new(&spans)),
+///     "\x1b[1;35mlet\x1b[0m x = \x1b[1;35mtrue\x1b[3m;\x1b[0m"
+/// );
+/// ```
+///
+/// When a style is removed, it is unset with the specific SGR parameter, like `22` for bold, unless resetting all
+/// the styling and setting the next style is shorter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyledSpans<'a, C: Display> {
+    spans: &'a [Styled<C>],
+}
+
+impl<'a, C: Display> StyledSpans<'a, C> {
+    /// Creates a new `StyledSpans` value that renders the given spans.
+    #[must_use]
+    pub const fn new(spans: &'a [Styled<C>]) -> Self {
+        Self { spans }
+    }
+
+    /// Gets the spans.
+    #[must_use]
+    pub const fn get_spans(&self) -> &'a [Styled<C>] {
+        self.spans
+    }
+}
+
+impl<C: Display> Display for StyledSpans<'_, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut current = Style::new();
+        for span in self.spans {
+            let style = span.get_style();
+            write!(
+                f,
+                "{}",
+                Transition {
+                    from: current,
+                    to: style
+                }
+            )?;
+            write!(f, "{}", span.get_content())?;
+            current = style;
+        }
+        write!(
+            f,
+            "{}",
+            Transition {
+                from: current,
+                to: Style::new()
+            }
+        )
+    }
+}
+
+/// Renders the escape sequence that changes the styling from a style to another.
+pub(crate) struct Transition {
+    pub(crate) from: Style,
+    pub(crate) to: Style,
+}
+
+impl Display for Transition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.from == self.to {
+            return Ok(());
+        }
+        if !self.to.emits_codes() {
+            if self.from.emits_codes() {
+                write!(f, "{}", Style::new())?;
+            }
+            return Ok(());
+        }
+
+        let changes = Changes {
+            from: self.from,
+            to: self.to,
+        };
+        let reset = ResetAndSet(self.to);
+        if changes.is_possible() && visible_width(&changes) <= visible_width(&reset) {
+            // All the changed codes may be compiled out
+            if visible_width(&changes) == 0 {
+                return Ok(());
+            }
+            write!(f, "\x1b[{changes}m")
+        } else {
+            write!(f, "\x1b[{reset}m")
+        }
+    }
+}
+
+/// The SGR parameters that unset effects. See [`Effect::reset_code()`].
+const EFFECT_RESET_CODES: [u8; 10] = [22, 23, 24, 25, 27, 28, 29, 54, 55, 75];
+
+/// Renders the SGR parameters that change the styling from a style to another, without resetting all the styling.
+struct Changes {
+    from: Style,
+    to: Style,
+}
+
+impl Changes {
+    /// A custom code can't be unset without resetting all the styling.
+    fn is_possible(&self) -> bool {
+        self.from.custom_code.is_none() || self.from.custom_code == self.to.custom_code
+    }
+}
+
+impl Display for Changes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut code_writer = CodeWriter::new(f);
+
+        for reset_code in EFFECT_RESET_CODES {
+            let group = || Effect::iter().filter(move |effect| effect.reset_code() == reset_code);
+            if group().all(|effect| self.from.get_effect(effect) == self.to.get_effect(effect)) {
+                continue;
+            }
+            if group().any(|effect| self.from.get_effect(effect)) {
+                code_writer.write_code(reset_code)?;
+            }
+            for effect in group().filter(|&effect| self.to.get_effect(effect)) {
+                code_writer.write_code(effect.emitted_code())?;
+            }
+        }
+
+        for target in ColorTarget::ALL {
+            let color = self.to.get_color(target);
+            if color != self.from.get_color(target) {
+                let color = color.unwrap_or(Color::Default);
+                if let Some(code) = color.emitted_code(target) {
+                    code_writer.write_code(code)?;
+                }
+            }
+        }
+
+        if let Some(custom_code) = self.to.custom_code
+            && self.from.custom_code != self.to.custom_code
+        {
+            code_writer.write_code(custom_code.params())?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders the SGR parameters that reset all the styling and then set a style.
+struct ResetAndSet(Style);
+
+impl Display for ResetAndSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut code_writer = CodeWriter::new(f);
+        code_writer.write_code(0)?;
+        self.0.write_codes(&mut code_writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, CustomCode, ToStyleSet as _};
+
+    use super::*;
+
+    fn transition(from: Style, to: Style) -> String {
+        Transition { from, to }.to_string()
+    }
+
+    #[test]
+    fn spans() {
+        let spans = [
+            Styled::new("a").bold(),
+            Styled::new("b").bold(),
+            Styled::new("c"),
+            Styled::new("d").fg(Color::RED),
+        ];
+        let styled_spans = StyledSpans::new(&spans);
+
+        assert_eq!(styled_spans.get_spans(), &spans);
+        assert_eq!(
+            styled_spans.to_string(),
+            "\x1b[1mab\x1b[0mc\x1b[31md\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn empty_spans() {
+        assert_eq!(StyledSpans::<&str>::new(&[]).to_string(), "");
+        assert_eq!(
+            StyledSpans::new(&[Styled::new("a"), Styled::new("b")]).to_string(),
+            "ab"
+        );
+    }
+
+    #[test]
+    fn added_styles() {
+        let style = Style::new().bold();
+        assert_eq!(transition(style, style), "");
+        assert_eq!(transition(Style::new(), style), "\x1b[1m");
+        assert_eq!(transition(style, style.italic()), "\x1b[3m");
+        assert_eq!(
+            transition(style, style.fg(Color::RED).bg(Color::indexed(42))),
+            "\x1b[31;48;5;42m"
+        );
+    }
+
+    #[test]
+    fn removed_styles() {
+        let style = Style::new().bold().italic().fg(Color::RED).bg(Color::BLUE);
+        assert_eq!(transition(style, Style::new()), "\x1b[0m");
+        assert_eq!(
+            transition(style, Style::new().bold().fg(Color::RED)),
+            "\x1b[23;49m"
+        );
+        assert_eq!(transition(style, Style::new().fg(Color::RED)), "\x1b[0;31m");
+        assert_eq!(transition(style, style.unset(Effect::Italic)), "\x1b[23m");
+        assert_eq!(
+            transition(style, style.unset(ColorTarget::Background)),
+            "\x1b[49m"
+        );
+    }
+
+    #[test]
+    fn shared_reset_codes() {
+        let style = Style::new().bold().faint();
+        assert_eq!(transition(style, Style::new().bold()), "\x1b[0;1m");
+        assert_eq!(
+            transition(style.italic(), style.italic().unset(Effect::Faint)),
+            "\x1b[22;1m"
+        );
+    }
+
+    #[test]
+    fn changed_styles() {
+        let style = Style::new().italic().underline().fg(Color::RED);
+        assert_eq!(transition(style, style.fg(Color::GREEN)), "\x1b[32m");
+        #[cfg(feature = "extended-underline")]
+        assert_eq!(transition(style, style.curly_underline()), "\x1b[24;4:3m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_eq!(transition(style, style.curly_underline()), "\x1b[24;4m");
+    }
+
+    #[test]
+    fn custom_codes() {
+        let custom = CustomCode::new("60");
+        let style = Style::new().bold();
+        assert_eq!(transition(style, style.add(custom)), "\x1b[60m");
+        assert_eq!(transition(style.add(custom), style.italic()), "\x1b[0;1;3m");
+        assert_eq!(
+            transition(style.add(custom), style.add(custom).italic()),
+            "\x1b[3m"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "underline-color"))]
+    fn without_underline_color() {
+        let spans = [
+            Styled::new("a").bold().underline_color(Color::RED),
+            Styled::new("b").bold(),
+            Styled::new("c").underline_color(Color::RED),
+        ];
+        assert_eq!(StyledSpans::new(&spans).to_string(), "\x1b[1mab\x1b[0mc");
+        assert_eq!(StyledSpans::new(&spans[2..]).to_string(), "c");
+    }
+
+    #[test]
+    fn applied_styles() {
+        let spans = [
+            Color::RED.applied_to("1"),
+            Color::RED.bold().applied_to("2"),
+        ];
+        assert_eq!(
+            StyledSpans::new(&spans).to_string(),
+            "\x1b[31m1\x1b[1m2\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::string::{String, ToString as _};
+use core::fmt::Display;
+
+use crate::{Style, Styled};
+
+impl<C: Display> Styled<C> {
+    /// Renders this value into a `String`, with the escape sequences and the content.
+    ///
+    /// It is the same as [`to_string()`](alloc::string::ToString::to_string), but the name makes it clear that the
+    /// result is not only the content:
+    ///
+    /// ```
+    /// use fluent_ansi::prelude::*;
+    ///
+    /// let styled = Color::GREEN.applied_to("ok");
+    /// assert_eq!(styled.to_styled_string(), "\x1b[32mok\x1b[0m");
+    /// ```
+    ///
+    /// Requires the `alloc` feature.
+    #[must_use]
+    pub fn to_styled_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Renders the escape sequence of a style.
+///
+/// ```
+/// use fluent_ansi::{prelude::*, Style};
+///
+/// let sequence: String = Style::new().bold().fg(Color::RED).into();
+/// assert_eq!(sequence, "\x1b[1;31m");
+/// ```
+///
+/// Requires the `alloc` feature.
+impl From<Style> for String {
+    fn from(style: Style) -> Self {
+        style.to_string()
+    }
+}
+
+/// Renders a styled value, with the escape sequences and the content.
+///
+/// ```
+/// use fluent_ansi::prelude::*;
+///
+/// let fragments: Vec<String> = vec![Color::RED.applied_to("a").into(), Color::BLUE.applied_to(1).into()];
+/// assert_eq!(fragments, ["\x1b[31ma\x1b[0m", "\x1b[34m1\x1b[0m"]);
+/// ```
+///
+/// Requires the `alloc` feature.
+impl<C: Display> From<Styled<C>> for String {
+    fn from(styled: Styled<C>) -> Self {
+        styled.to_styled_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn style() {
+        assert_eq!(String::from(Style::new()), "\x1b[0m");
+        assert_eq!(String::from(Style::new().italic()), "\x1b[3m");
+    }
+
+    #[test]
+    fn styled() {
+        let stld = Styled::new("abc").bold().bg(Color::RED);
+        assert_eq!(stld.to_styled_string(), "\x1b[1;41mabc\x1b[0m");
+        assert_eq!(String::from(stld), "\x1b[1;41mabc\x1b[0m");
+        assert_eq!(String::from(Styled::new(42)), "42");
+    }
+}
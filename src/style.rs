@@ -1,15 +1,17 @@
 use core::fmt::{Display, Formatter, Result, Write};
 
 use crate::{
-    AppliedTo, ColorTarget, Effect, Reset, StyleAttribute, StyleElement, StyleSet, Styled,
-    TargetedColor, ToStyle, ToStyleSet, UnderlineStyle,
-    color::{Color, ColorKind, WriteColorCodes as _},
-    style::encoded_effects::EncodedEffects,
+    AppliedTo, ColorTarget, CustomCode, Effect, RenderOptions, Reset, StyleAttribute, StyleElement,
+    StyleSet, Styled, TargetedColor, ToStyle, ToStyleSet, Underline, UnderlineStyle,
+    color::{Color, ColorKind, Palette, RGBColor},
 };
 
 pub use encoded_effects::*;
+pub use spec::*;
 
 mod encoded_effects;
+mod git_color;
+mod spec;
 
 /// A structure representing text styling with effects and colors.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -18,6 +20,7 @@ pub struct Style {
     pub(crate) fg: Option<Color>,
     pub(crate) bg: Option<Color>,
     pub(crate) underline_color: Option<Color>,
+    pub(crate) custom_code: Option<CustomCode>,
 }
 
 impl Style {
@@ -29,8 +32,301 @@ impl Style {
             fg: None,
             bg: None,
             underline_color: None,
+            custom_code: None,
         }
     }
+
+    /// Returns a new `Style` value with the given effect added.
+    ///
+    /// It is equivalent to [`ToStyleSet::effect()`], but can be used in `const` contexts.
+    #[must_use]
+    pub const fn with_effect(self, effect: Effect) -> Self {
+        Style {
+            encoded_effects: self.encoded_effects.add(effect),
+            ..self
+        }
+    }
+
+    /// Returns a new `Style` value with the given foreground color.
+    ///
+    /// It is equivalent to [`ToStyleSet::fg()`], but can be used in `const` contexts.
+    #[must_use]
+    pub const fn with_fg(self, color: Color) -> Self {
+        Style {
+            fg: Some(color),
+            ..self
+        }
+    }
+
+    /// Returns a new `Style` value with the given background color.
+    ///
+    /// It is equivalent to [`ToStyleSet::bg()`], but can be used in `const` contexts.
+    #[must_use]
+    pub const fn with_bg(self, color: Color) -> Self {
+        Style {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    /// Returns a new `Style` value with the given underline color.
+    ///
+    /// It is equivalent to [`ToStyleSet::underline_color()`], but can be used in `const` contexts.
+    #[must_use]
+    pub const fn with_underline_color(self, color: Color) -> Self {
+        Style {
+            underline_color: Some(color),
+            ..self
+        }
+    }
+
+    /// Returns a new `Style` value with the foreground and background colors swapped.
+    ///
+    /// This computes, for example, the style of a selected row from the style of a normal row. When the colors are
+    /// not set, swapping them has no visible effect, and toggling the [`Reverse`](Effect::Reverse) effect may be
+    /// used as well:
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let normal = Style::new().bold().fg(Color::WHITE).bg(Color::BLUE);
+    /// assert_eq!(normal.invert(), Style::new().bold().fg(Color::BLUE).bg(Color::WHITE));
+    ///
+    /// let normal = Style::new().italic();
+    /// assert_eq!(normal.invert().toggle_effect(Effect::Reverse), Style::new().italic().reverse());
+    /// ```
+    #[must_use]
+    pub const fn invert(self) -> Self {
+        Style {
+            fg: self.bg,
+            bg: self.fg,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this style with only the effects retained.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let style = Style::new().bold().fg(Color::RED).bg(Color::BLUE);
+    /// assert_eq!(style.effects_only(), Style::new().bold());
+    /// assert_eq!(style.colors_only(), Style::new().fg(Color::RED).bg(Color::BLUE));
+    /// assert_eq!(style.without_colors(), Style::new().bold());
+    /// assert_eq!(style.without_effects(), Style::new().fg(Color::RED).bg(Color::BLUE));
+    /// ```
+    #[must_use]
+    pub const fn effects_only(self) -> Self {
+        Style {
+            encoded_effects: self.encoded_effects,
+            ..Style::new()
+        }
+    }
+
+    /// Returns a copy of this style with only the colors retained.
+    #[must_use]
+    pub const fn colors_only(self) -> Self {
+        Style {
+            fg: self.fg,
+            bg: self.bg,
+            underline_color: self.underline_color,
+            ..Style::new()
+        }
+    }
+
+    /// Returns a copy of this style with all the colors removed.
+    ///
+    /// Unlike [`effects_only()`](Style::effects_only), the custom code is retained.
+    #[must_use]
+    pub const fn without_colors(self) -> Self {
+        Style {
+            fg: None,
+            bg: None,
+            underline_color: None,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this style with all the effects removed.
+    ///
+    /// Unlike [`colors_only()`](Style::colors_only), the custom code is retained.
+    #[must_use]
+    pub const fn without_effects(self) -> Self {
+        Style {
+            encoded_effects: EncodedEffects::new(),
+            ..self
+        }
+    }
+
+    /// Returns whether this style has no effects, colors or custom code, so rendering it with some content writes
+    /// only the content.
+    ///
+    /// It is equivalent to comparing with [`Style::new()`]:
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// assert!(Style::new().is_plain());
+    /// assert!(!Style::new().bold().is_plain());
+    /// assert!(!Style::new().bg(Color::BLUE).is_plain());
+    /// ```
+    #[must_use]
+    pub const fn is_plain(&self) -> bool {
+        self.encoded_effects.count() == 0 && !self.has_colors() && self.custom_code.is_none()
+    }
+
+    /// Returns the number of effects that are set.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// assert_eq!(Style::new().bold().curly_underline().fg(Color::RED).effect_count(), 2);
+    /// ```
+    #[must_use]
+    pub const fn effect_count(&self) -> usize {
+        self.encoded_effects.count()
+    }
+
+    /// Returns whether a color is set for any target.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// assert!(Style::new().underline_color(Color::RED).has_colors());
+    /// assert!(!Style::new().bold().has_colors());
+    /// ```
+    #[must_use]
+    pub const fn has_colors(&self) -> bool {
+        self.fg.is_some() || self.bg.is_some() || self.underline_color.is_some()
+    }
+
+    /// Interpolates between two styles, like for animations and heat maps.
+    ///
+    /// `t` is the fraction of the way from `a` to `b`, and is clamped to the range from `0.0` to `1.0`. Colors set in
+    /// both styles are interpolated as with [`RGBColor::lerp()`], using xterm's default palette for non-RGB colors (see
+    /// [`lerp_in()`](Style::lerp_in) to use another palette). Everything else, including the effects and the colors
+    /// that can't be interpolated, is taken from the nearest style:
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let cold = Style::new().fg(Color::rgb(0, 0, 255));
+    /// let hot = Style::new().bold().fg(Color::rgb(255, 0, 0));
+    /// assert_eq!(Style::lerp(cold, hot, 0.25), Style::new().fg(Color::rgb(64, 0, 191)));
+    /// assert_eq!(Style::lerp(cold, hot, 0.75), Style::new().bold().fg(Color::rgb(191, 0, 64)));
+    /// ```
+    #[must_use]
+    pub fn lerp(a: Self, b: Self, t: f32) -> Self {
+        Self::lerp_in(a, b, t, &Palette::XTERM)
+    }
+
+    /// Interpolates between two styles, using the given palette for non-RGB colors.
+    ///
+    /// See [`lerp()`](Style::lerp).
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, color::{IndexedColor, Palette, RGBColor}, Style};
+    ///
+    /// let palette = Palette::XTERM.with_entry(IndexedColor(1), RGBColor::new(200, 0, 0));
+    /// let style = Style::lerp_in(Style::new().fg(Color::RED), Style::new().fg(Color::BLACK), 0.5, &palette);
+    /// assert_eq!(style, Style::new().fg(Color::rgb(100, 0, 0)));
+    /// ```
+    #[must_use]
+    pub fn lerp_in(a: Self, b: Self, t: f32, palette: &Palette) -> Self {
+        if t >= 1.0 {
+            return b;
+        } else if t <= 0.0 || t.is_nan() {
+            return a;
+        }
+        let nearest = if t >= 0.5 { b } else { a };
+        let color = |a: Option<Color>, b: Option<Color>, nearest: Option<Color>| {
+            if let (Some(a), Some(b)) = (a, b)
+                && a != b
+                && let (Some(a), Some(b)) = (palette.resolve(a), palette.resolve(b))
+            {
+                Some(Color::RGB(RGBColor::lerp(a, b, t)))
+            } else {
+                nearest
+            }
+        };
+        Style {
+            fg: color(a.fg, b.fg, nearest.fg),
+            bg: color(a.bg, b.bg, nearest.bg),
+            underline_color: color(
+                a.underline_color,
+                b.underline_color,
+                nearest.underline_color,
+            ),
+            ..nearest
+        }
+    }
+}
+
+impl Style {
+    /// Sets the given effect to the specified value, in place.
+    ///
+    /// It is equivalent to [`StyleSet::set_effect()`], but modifies this value instead of returning a new one. This
+    /// is convenient when a style is adjusted incrementally:
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, ColorTarget, Style};
+    ///
+    /// let mut style = Style::new();
+    /// for (i, effect) in [Effect::Bold, Effect::Italic, Effect::Underline].into_iter().enumerate() {
+    ///     style.set_effect_mut(effect, i % 2 == 0);
+    /// }
+    /// style.set_color_mut(ColorTarget::Foreground, Some(Color::RED));
+    /// assert_eq!(style, Style::new().bold().underline().fg(Color::RED));
+    /// ```
+    pub fn set_effect_mut(&mut self, effect: impl Into<Effect>, value: bool) -> &mut Self {
+        self.set_mut(effect.into(), value)
+    }
+
+    /// Sets the underline style, in place.
+    ///
+    /// It is equivalent to [`StyleSet::set_underline_style()`], but modifies this value instead of returning a new
+    /// one.
+    pub fn set_underline_style_mut(
+        &mut self,
+        underline_style: Option<UnderlineStyle>,
+    ) -> &mut Self {
+        self.set_mut(Underline, underline_style)
+    }
+
+    /// Sets the color for the given color target, in place.
+    ///
+    /// It is equivalent to [`StyleSet::set_color()`], but modifies this value instead of returning a new one.
+    pub fn set_color_mut(
+        &mut self,
+        target: ColorTarget,
+        color: Option<impl Into<Color>>,
+    ) -> &mut Self {
+        let color: Option<Color> = color.map(Into::into);
+        self.set_mut(target, color)
+    }
+
+    /// Sets the given attribute to the specified value, in place.
+    ///
+    /// It is equivalent to [`StyleSet::set()`], but modifies this value instead of returning a new one.
+    pub fn set_mut<A: StyleAttribute>(&mut self, attr: A, value: A::Value) -> &mut Self {
+        *self = self.set(attr, value);
+        self
+    }
+
+    /// Unsets the given attribute, in place.
+    ///
+    /// It is equivalent to [`StyleSet::unset()`], but modifies this value instead of returning a new one.
+    pub fn unset_mut<A: StyleAttribute>(&mut self, attr: A) -> &mut Self {
+        self.set_mut(attr, A::Value::default())
+    }
+
+    /// Adds the given element, in place.
+    ///
+    /// It is equivalent to [`ToStyleSet::add()`], but modifies this value instead of returning a new one.
+    pub fn add_mut(&mut self, element: impl StyleElement) -> &mut Self {
+        *self = self.add(element);
+        self
+    }
 }
 
 impl ToStyleSet for Style {
@@ -71,35 +367,131 @@ impl StyleSet for Style {
     }
 }
 
+impl Style {
+    /// Writes the SGR parameters of this style to the given code writer.
+    ///
+    /// It allows a custom [`Display`] implementation to combine the parameters of a style with its own
+    /// parameters in a single escape sequence. See [`CodeWriter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying formatter fails.
+    pub fn write_codes(self, code_writer: &mut CodeWriter) -> Result {
+        for code in self.codes() {
+            code_writer.write_code(code)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the escape sequence that starts styled output to the given formatter.
+    ///
+    /// Together with [`fmt_suffix()`](Style::fmt_suffix), it allows a custom [`Display`] implementation to style its
+    /// own output, without wrapping it in a [`Styled`] value:
+    ///
+    /// ```
+    /// use core::fmt::{Display, Formatter, Result};
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// struct Warning(&'static str);
+    ///
+    /// impl Display for Warning {
+    ///     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    ///         let style = Color::YELLOW.bold();
+    ///         style.fmt_prefix(f)?;
+    ///         write!(f, "warning: {}", self.0)?;
+    ///         style.fmt_suffix(f)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(format!("{}", Warning("unused")), "\x1b[1;33mwarning: unused\x1b[0m");
+    /// ```
+    ///
+    /// Like in [`Styled`] values, nothing is written for a plain style, or for a style whose codes are all compiled
+    /// out by the disabled Cargo features.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the formatter fails.
+    pub fn fmt_prefix(self, f: &mut Formatter<'_>) -> Result {
+        if self.emits_codes() {
+            self.render(RenderOptions::new()).fmt(f)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Writes the escape sequence that ends styled output started with [`fmt_prefix()`](Style::fmt_prefix) to the
+    /// given formatter.
+    ///
+    /// Nothing is written when nothing is written by [`fmt_prefix()`](Style::fmt_prefix).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the formatter fails.
+    pub fn fmt_suffix(self, f: &mut Formatter<'_>) -> Result {
+        if self.emits_codes() {
+            Style::new().render(RenderOptions::new()).fmt(f)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Renders the escape sequence of the style.
+///
+/// With the alternate flag (`{:#}`), a readable description is rendered instead, which is handy for logging which
+/// style was chosen. The effects, the colors prefixed with `fg:`, `bg:` and `ul:` for their targets, and the custom
+/// code prefixed with `code:` are joined with `+`, or `plain` is rendered if the style has no styling:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, CustomCode, Style};
+///
+/// let style = Color::RED.bold().italic();
+/// assert_eq!(format!("{style}"), "\x1b[1;3;31m");
+/// assert_eq!(format!("{style:#}"), "bold + italic + fg:red");
+///
+/// let style = Style::new().underline_color(Color::indexed(42)).add(CustomCode::new("60"));
+/// assert_eq!(format!("{style:#}"), "ul:42 + code:60");
+/// assert_eq!(format!("{:#}", Style::new()), "plain");
+/// ```
 impl Display for Style {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        if *self == Style::new() {
-            write_escape_sequence(f, 0)
+        if f.alternate() {
+            self.fmt_description(f)
         } else {
-            struct Codes(Style);
-            impl Display for Codes {
-                fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-                    let mut code_writer = CodeWriter { f, any: false };
-
-                    for effect in Effect::all() {
-                        if self.0.get_effect(effect) {
-                            effect.write_codes(&mut code_writer)?;
-                        }
-                    }
-                    if let Some(color) = self.0.fg {
-                        color.write_color_codes(ColorTarget::Foreground, &mut code_writer)?;
-                    }
-                    if let Some(color) = self.0.bg {
-                        color.write_color_codes(ColorTarget::Background, &mut code_writer)?;
-                    }
-                    if let Some(color) = self.0.underline_color {
-                        color.write_color_codes(ColorTarget::Underline, &mut code_writer)?;
-                    }
-                    Ok(())
-                }
+            self.render(RenderOptions::new()).fmt(f)
+        }
+    }
+}
+
+impl Style {
+    fn fmt_description(self, f: &mut Formatter<'_>) -> Result {
+        if self.is_plain() {
+            return f.write_str("plain");
+        }
+        let mut separator = "";
+        let mut write_element = |f: &mut Formatter<'_>, element: &dyn Display| {
+            let result = write!(f, "{separator}{element}");
+            separator = " + ";
+            result
+        };
+
+        for effect in self.get_effects() {
+            write_element(f, &effect_name(effect))?;
+        }
+        for (target, prefix) in [
+            (ColorTarget::Foreground, "fg"),
+            (ColorTarget::Background, "bg"),
+            (ColorTarget::Underline, "ul"),
+        ] {
+            if let Some(color) = self.get_color(target) {
+                write_element(f, &format_args!("{prefix}:{}", color.to_spec()))?;
             }
-            write_escape_sequence(f, Codes(*self))
         }
+        if let Some(custom_code) = self.custom_code {
+            write_element(f, &format_args!("code:{}", custom_code.params()))?;
+        }
+        Ok(())
     }
 }
 
@@ -121,6 +513,12 @@ impl From<TargetedColor> for Style {
     }
 }
 
+impl From<CustomCode> for Style {
+    fn from(custom_code: CustomCode) -> Self {
+        Style::new().add(custom_code)
+    }
+}
+
 impl<CK: ColorKind> From<CK> for Style {
     fn from(color: CK) -> Self {
         Style::new().fg(color)
@@ -139,13 +537,64 @@ impl PartialEq<Reset> for Style {
     }
 }
 
-pub(crate) struct CodeWriter<'a, 'b> {
+impl PartialEq<Effect> for Style {
+    fn eq(&self, other: &Effect) -> bool {
+        *self == other.to_style()
+    }
+}
+
+impl PartialEq<UnderlineStyle> for Style {
+    fn eq(&self, other: &UnderlineStyle) -> bool {
+        *self == other.to_style()
+    }
+}
+
+impl PartialEq<TargetedColor> for Style {
+    fn eq(&self, other: &TargetedColor) -> bool {
+        *self == other.to_style()
+    }
+}
+
+/// A writer of SGR parameters, which separates them with `;`.
+///
+/// It writes only the parameters, not the `ESC [` prefix and the `m` suffix of an escape sequence:
+///
+/// ```
+/// use core::fmt::{Display, Formatter, Result};
+/// use fluent_ansi::{prelude::*, CodeWriter, Style};
+///
+/// struct IdeogramUnderline(Style);
+///
+/// impl Display for IdeogramUnderline {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+///         f.write_str("\x1b[")?;
+///         let mut code_writer = CodeWriter::new(f);
+///         self.0.write_codes(&mut code_writer)?;
+///         code_writer.write_code(60)?;
+///         f.write_str("m")
+///     }
+/// }
+///
+/// assert_eq!(format!("{}", IdeogramUnderline(Color::RED.bold())), "\x1b[1;31;60m");
+/// ```
+pub struct CodeWriter<'a, 'b> {
     f: &'a mut Formatter<'b>,
     any: bool,
 }
 
-impl CodeWriter<'_, '_> {
-    pub(crate) fn write_code(&mut self, code: impl Display) -> Result {
+impl<'a, 'b> CodeWriter<'a, 'b> {
+    /// Creates a code writer that writes to the given formatter.
+    #[must_use]
+    pub fn new(f: &'a mut Formatter<'b>) -> Self {
+        CodeWriter { f, any: false }
+    }
+
+    /// Writes a parameter, preceded by a `;` if it is not the first one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying formatter fails.
+    pub fn write_code(&mut self, code: impl Display) -> Result {
         if self.any {
             self.f.write_char(';')?;
         }
@@ -155,10 +604,6 @@ impl CodeWriter<'_, '_> {
     }
 }
 
-fn write_escape_sequence(f: &mut impl Write, codes: impl Display) -> Result {
-    write!(f, "\x1b[{codes}m")
-}
-
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -181,9 +626,18 @@ mod tests {
         assert_display!(stl.faint(), "\x1b[2m");
         assert_display!(stl.italic(), "\x1b[3m");
         assert_display!(stl.underline(), "\x1b[4m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(stl.curly_underline(), "\x1b[4:3m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(stl.curly_underline(), "\x1b[4m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(stl.dotted_underline(), "\x1b[4:4m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(stl.dotted_underline(), "\x1b[4m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(stl.dashed_underline(), "\x1b[4:5m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(stl.dashed_underline(), "\x1b[4m");
         assert_display!(stl.blink(), "\x1b[5m");
         assert_display!(stl.reverse(), "\x1b[7m");
         assert_display!(stl.conceal(), "\x1b[8m");
@@ -210,6 +664,38 @@ mod tests {
         assert_display!(stl, "\x1b[1;4;31;42m");
     }
 
+    #[test]
+    fn alternate_display() {
+        assert_eq!(std::format!("{:#}", Style::new()), "plain");
+        assert_eq!(
+            std::format!(
+                "{:#}",
+                Style::new()
+                    .underline()
+                    .fg(RGBColor::new(0, 128, 255))
+                    .bg(BasicColor::Blue.bright())
+                    .underline_color(IndexedColor(42))
+            ),
+            "underline + fg:#0080ff + bg:bright-blue + ul:42"
+        );
+        assert_eq!(std::format!("{:#}", Effect::Bold), "bold");
+        assert_eq!(std::format!("{:#}", Color::RED.for_bg()), "bg:red");
+        assert_eq!(
+            std::format!("{:#}", Style::new().add(CustomCode::new("60;61"))),
+            "code:60;61"
+        );
+        assert_eq!(
+            std::format!("{:#}", Style::new().bold().add(CustomCode::new("60"))),
+            "bold + code:60"
+        );
+
+        let style = Style::new().bold();
+        assert_eq!(
+            std::format!("{:#}", Styled::new("x").bold()),
+            std::format!("{style}x{}", Style::new())
+        );
+    }
+
     #[test]
     fn applied_to() {
         let stld = Style::new().bold().applied_to("CONTENT");
@@ -223,6 +709,149 @@ mod tests {
         assert_display!(Style::default(), "\x1b[0m");
     }
 
+    #[test]
+    fn invert() {
+        let style = Style::new()
+            .underline()
+            .fg(Color::RED)
+            .underline_color(Color::GREEN);
+        assert_eq!(
+            style.invert(),
+            Style::new()
+                .underline()
+                .bg(Color::RED)
+                .underline_color(Color::GREEN)
+        );
+        assert_eq!(style.invert().invert(), style);
+        assert_eq!(Style::new().invert(), Style::new());
+    }
+
+    #[test]
+    fn toggle_effect() {
+        let style = Style::new().bold().blink();
+        assert_eq!(style.toggle_effect(Effect::Bold), Style::new().blink());
+        assert_eq!(
+            style.toggle_effect(Effect::RapidBlink),
+            Style::new().bold().rapid_blink()
+        );
+        assert_eq!(
+            style.toggle_effect(UnderlineStyle::Dotted),
+            style.dotted_underline()
+        );
+    }
+
+    #[test]
+    fn projections() {
+        let code = CustomCode::new("60");
+        let style = Style::new()
+            .italic()
+            .curly_underline()
+            .fg(Color::RED)
+            .bg(Color::BLUE)
+            .underline_color(Color::GREEN)
+            .add(code);
+        let colors = Style::new()
+            .fg(Color::RED)
+            .bg(Color::BLUE)
+            .underline_color(Color::GREEN);
+        let effects = Style::new().italic().curly_underline();
+
+        assert_eq!(style.effects_only(), effects);
+        assert_eq!(style.colors_only(), colors);
+        assert_eq!(style.without_colors(), effects.add(code));
+        assert_eq!(style.without_effects(), colors.add(code));
+        assert_eq!(Style::new().effects_only(), Style::new());
+        assert_eq!(Style::new().colors_only(), Style::new());
+    }
+
+    #[test]
+    fn queries() {
+        let style = Style::new();
+        assert!(style.is_plain());
+        assert_eq!(style.effect_count(), 0);
+        assert!(!style.has_colors());
+
+        let style = Style::new().bold().italic().double_underline();
+        assert!(!style.is_plain());
+        assert_eq!(style.effect_count(), 3);
+        assert!(!style.has_colors());
+
+        for style in [
+            Style::new().fg(Color::RED),
+            Style::new().bg(Color::RED),
+            Style::new().underline_color(Color::RED),
+            Style::new().fg(Color::Default),
+        ] {
+            assert!(!style.is_plain());
+            assert_eq!(style.effect_count(), 0);
+            assert!(style.has_colors());
+        }
+
+        let style = Style::new().add(CustomCode::new("60"));
+        assert!(!style.is_plain());
+        assert_eq!(style.effect_count(), 0);
+        assert!(!style.has_colors());
+    }
+
+    #[test]
+    fn mutation() {
+        let mut style = Style::new();
+
+        style
+            .set_effect_mut(Effect::Bold, true)
+            .set_effect_mut(Effect::Italic, true);
+        assert_eq!(style, Style::new().bold().italic());
+        style.set_effect_mut(Effect::Bold, false);
+        assert_eq!(style, Style::new().italic());
+
+        style.set_underline_style_mut(Some(UnderlineStyle::Curly));
+        assert_eq!(style, Style::new().italic().curly_underline());
+        style.set_underline_style_mut(None);
+        assert_eq!(style, Style::new().italic());
+
+        style.set_color_mut(ColorTarget::Background, Some(BasicColor::Red));
+        assert_eq!(style, Style::new().italic().bg(BasicColor::Red));
+        style.set_color_mut(ColorTarget::Background, Color::none());
+        assert_eq!(style, Style::new().italic());
+
+        style.set_mut(ColorTarget::Foreground, Some(Color::BLUE.to_color()));
+        assert_eq!(style, Style::new().italic().fg(Color::BLUE));
+        style
+            .unset_mut(ColorTarget::Foreground)
+            .unset_mut(Effect::Italic);
+        assert_eq!(style, Style::new());
+
+        style.add_mut(Effect::Blink).add_mut(Color::GREEN.for_bg());
+        assert_eq!(style, Style::new().blink().bg(Color::GREEN));
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Style::new()
+            .italic()
+            .fg(RGBColor::new(0, 0, 0))
+            .bg(BasicColor::Red)
+            .underline_color(Color::Default);
+        let b = Style::new()
+            .bold()
+            .fg(IndexedColor::new(231))
+            .underline_color(RGBColor::new(0, 0, 0));
+
+        assert_eq!(Style::lerp(a, b, 0.0), a);
+        assert_eq!(Style::lerp(a, b, 1.0), b);
+        assert_eq!(Style::lerp(a, b, 0.25), a.fg(RGBColor::new(64, 64, 64)));
+        assert_eq!(Style::lerp(a, b, 0.5), b.fg(RGBColor::new(128, 128, 128)));
+        assert_eq!(Style::lerp(a, a, 0.5), a);
+        assert_eq!(Style::lerp(a, b, 2.0), b);
+        assert_eq!(Style::lerp(a, b, f32::NAN), a);
+
+        let palette = Palette::XTERM.with_entry(IndexedColor::new(231), RGBColor::new(100, 0, 200));
+        assert_eq!(
+            Style::lerp_in(a, b, 0.75, &palette),
+            b.fg(RGBColor::new(75, 0, 150))
+        );
+    }
+
     #[test]
     fn to_style() {
         let stl = Style::new().bold().fg(BasicColor::Red);
@@ -266,4 +895,32 @@ mod tests {
     fn from_reset() {
         assert_eq!(Style::from(Reset), Style::new());
     }
+
+    #[test]
+    fn fmt_prefix_and_suffix() {
+        struct Wrapped(Style);
+
+        impl Display for Wrapped {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                self.0.fmt_prefix(f)?;
+                f.write_str("CONTENT")?;
+                self.0.fmt_suffix(f)
+            }
+        }
+
+        for style in [
+            Style::new(),
+            Style::new().bold(),
+            Style::new()
+                .italic()
+                .fg(BasicColor::Red)
+                .bg(IndexedColor(42)),
+            Style::new().underline_color(BasicColor::Red),
+        ] {
+            assert_eq!(
+                Wrapped(style).to_string(),
+                style.applied_to("CONTENT").to_string()
+            );
+        }
+    }
 }
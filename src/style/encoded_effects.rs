@@ -1,9 +1,15 @@
-use crate::{AllEffects, Effect, UnderlineStyle};
+use crate::{Effect, EffectIter, UnderlineStyle};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub(crate) struct EncodedEffects(u16);
+pub(crate) struct EncodedEffects(u32);
 
 impl EncodedEffects {
+    const UNDERLINE_MASK: u32 = Self::bit_mask(Effect::Underline)
+        | Self::bit_mask(Effect::CurlyUnderline)
+        | Self::bit_mask(Effect::DottedUnderline)
+        | Self::bit_mask(Effect::DashedUnderline)
+        | Self::bit_mask(Effect::DoubleUnderline);
+
     #[must_use]
     pub(crate) const fn new() -> Self {
         Self(0)
@@ -19,10 +25,11 @@ impl EncodedEffects {
     }
 
     #[must_use]
-    fn add(self, effect: Effect) -> Self {
-        let underline_style = UnderlineStyle::all().find(|t| t.to_effect() == effect);
-        if let Some(underline_style) = underline_style {
-            self.set_underline(Some(underline_style))
+    pub(crate) const fn add(self, effect: Effect) -> Self {
+        if Self::UNDERLINE_MASK & Self::bit_mask(effect) != 0 {
+            self.remove_underline().set_bit(effect)
+        } else if let Some(exclusive_effect) = effect.exclusive_with() {
+            self.clear_bit(exclusive_effect).set_bit(effect)
         } else {
             self.set_bit(effect)
         }
@@ -44,49 +51,67 @@ impl EncodedEffects {
     }
 
     #[must_use]
-    fn remove_underline(self) -> Self {
-        let mut encoded_effects = self;
-        for underline_style in UnderlineStyle::all() {
-            encoded_effects = encoded_effects.clear_bit(underline_style.to_effect());
-        }
-        encoded_effects
+    const fn remove_underline(self) -> Self {
+        Self(self.0 & !Self::UNDERLINE_MASK)
     }
 
     #[must_use]
-    pub(crate) fn get(self, effect: Effect) -> bool {
+    pub(crate) const fn get(self, effect: Effect) -> bool {
         self.0 & Self::bit_mask(effect) != 0
     }
 
+    #[must_use]
+    pub(crate) const fn count(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
     #[must_use]
     pub(crate) fn get_effects(self) -> GetEffects {
         GetEffects {
-            inner: Effect::all(),
+            inner: Effect::iter(),
             encoded_effects: self,
         }
     }
 
     #[must_use]
-    fn set_bit(self, effect: Effect) -> Self {
+    pub(crate) const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[must_use]
+    pub(crate) const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[must_use]
+    pub(crate) const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Sets the bit of an effect, without unsetting the effects that are exclusive with it.
+    #[must_use]
+    pub(crate) const fn set_bit(self, effect: Effect) -> Self {
         let bits = self.0 | Self::bit_mask(effect);
         Self(bits)
     }
 
     #[must_use]
-    fn clear_bit(self, effect: Effect) -> Self {
+    pub(crate) const fn clear_bit(self, effect: Effect) -> Self {
         let bits = self.0 & !Self::bit_mask(effect);
         Self(bits)
     }
 
     #[must_use]
-    fn bit_mask(effect: Effect) -> u16 {
-        let bit_index = effect as u16;
+    const fn bit_mask(effect: Effect) -> u32 {
+        let bit_index = effect as u32;
         1 << bit_index
     }
 }
 
 /// An iterator over the effects that are currently set.
+#[derive(Debug, Clone)]
 pub struct GetEffects {
-    inner: AllEffects,
+    inner: EffectIter,
     encoded_effects: EncodedEffects,
 }
 impl Iterator for GetEffects {
@@ -104,24 +129,24 @@ mod tests {
     use super::*;
 
     fn underline_effects() -> impl Iterator<Item = Effect> {
-        UnderlineStyle::all().map(UnderlineStyle::to_effect)
+        UnderlineStyle::iter().map(UnderlineStyle::to_effect)
     }
 
     #[test]
     fn default() {
         let effects = EncodedEffects::default();
 
-        for effect in Effect::all() {
+        for effect in Effect::iter() {
             assert_eq!(effects.get(effect), false);
         }
     }
 
     #[test]
     fn set_true() {
-        for added_effect in Effect::all() {
+        for added_effect in Effect::iter() {
             let effects = EncodedEffects(0).set(added_effect, true);
 
-            for checked_effect in Effect::all() {
+            for checked_effect in Effect::iter() {
                 assert_eq!(
                     effects.get(checked_effect),
                     added_effect == checked_effect,
@@ -133,10 +158,10 @@ mod tests {
 
     #[test]
     fn set_false() {
-        for removed_effect in Effect::all() {
+        for removed_effect in Effect::iter() {
             let effects = EncodedEffects(!0).set(removed_effect, false);
 
-            for checked_effect in Effect::all() {
+            for checked_effect in Effect::iter() {
                 assert_eq!(
                     effects.get(checked_effect),
                     removed_effect != checked_effect,
@@ -175,11 +200,11 @@ mod tests {
 
     #[test]
     fn set_underline_some() {
-        for initial_style in UnderlineStyle::all() {
+        for initial_style in UnderlineStyle::iter() {
             // Add some effect
             let encoded_effects = EncodedEffects::default().set(initial_style.to_effect(), true);
 
-            for other_style in UnderlineStyle::all() {
+            for other_style in UnderlineStyle::iter() {
                 if other_style == initial_style {
                     continue;
                 }
@@ -202,14 +227,14 @@ mod tests {
 
     macro_rules! test_clear_underline {
         ($method:ident ( $( $arg:expr )?) ) => {
-            for initial_style in UnderlineStyle::all() {
+            for initial_style in UnderlineStyle::iter() {
                 // Add some effect
                 let encoded_effects = EncodedEffects::default().set(initial_style.to_effect(), true);
 
                 // Clear underline effect
                 let encoded_effects = encoded_effects.$method( $( $arg )? );
 
-                for checked_style in UnderlineStyle::all() {
+                for checked_style in UnderlineStyle::iter() {
                     assert!(
                         !encoded_effects.get(checked_style.to_effect()),
                         "{initial_style:?} should not be set"
@@ -229,6 +254,25 @@ mod tests {
         test_clear_underline!(remove_underline());
     }
 
+    #[test]
+    fn set_exclusive() {
+        let effects = EncodedEffects::default()
+            .add(Effect::Blink)
+            .add(Effect::RapidBlink);
+        assert!(!effects.get(Effect::Blink));
+        assert!(effects.get(Effect::RapidBlink));
+
+        let effects = EncodedEffects::default()
+            .add(Effect::Superscript)
+            .add(Effect::Subscript);
+        assert!(!effects.get(Effect::Superscript));
+        assert!(effects.get(Effect::Subscript));
+
+        let effects = effects.add(Effect::Superscript);
+        assert!(effects.get(Effect::Superscript));
+        assert!(!effects.get(Effect::Subscript));
+    }
+
     #[test]
     fn get_effects() {
         let effects = EncodedEffects::default()
@@ -0,0 +1,212 @@
+use crate::{
+    ColorTarget, Effect, ParseStyleError, ParseStyleErrorKind, Style, StyleSet as _,
+    color::{Color, IndexedColor, parse_basic_color, parse_hex},
+    style::word_span,
+};
+
+impl Style {
+    /// Parses a style from a string in the syntax of git's `color.*` configuration values.
+    ///
+    /// The value is a whitespace-separated list of up to two colors and any number of attributes:
+    /// - the first color sets the foreground, and the second one sets the background;
+    /// - colors are `normal` (leaves the color unset), `default`, the basic color names (`red`),
+    ///   their bright variants (`brightred`), indexes from 0 to 255, and hexadecimal RGB values (`#ff0ab3` or `#f1b`);
+    /// - attributes are `bold`, `dim`, `italic`, `ul`, `blink`, `reverse` and `strike`;
+    /// - an attribute prefixed with `no` or `no-` (like `nobold` or `no-ul`) is cleared;
+    /// - the `reset` attribute is accepted, but has no effect since a `Style` is always rendered from scratch.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let style = Style::from_git_color("bold red blue").unwrap();
+    /// assert_eq!(style, Style::new().bold().fg(Color::RED).bg(Color::BLUE));
+    ///
+    /// let style = Style::from_git_color("ul brightgreen").unwrap();
+    /// assert_eq!(style, Style::new().underline().fg(Color::GREEN.bright()));
+    ///
+    /// let style = Style::from_git_color("normal #ff0ab3 italic").unwrap();
+    /// assert_eq!(style, Style::new().italic().bg(Color::rgb(0xff, 0x0a, 0xb3)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseStyleError`] if a word is neither a color nor an attribute, or if more than two colors are given.
+    pub fn from_git_color(value: &str) -> Result<Style, ParseStyleError> {
+        let mut style = Style::new();
+        let mut targets = [ColorTarget::Foreground, ColorTarget::Background].into_iter();
+
+        for word in value.split_whitespace() {
+            if word.eq_ignore_ascii_case("reset") {
+                continue;
+            }
+
+            if let Some((effect, value)) = parse_git_attribute(word) {
+                style = style.set_effect(effect, value);
+            } else {
+                let error = |kind| ParseStyleError::new(kind, word_span(value, word));
+                let color = if word.eq_ignore_ascii_case("normal") {
+                    None
+                } else {
+                    Some(
+                        parse_git_color(word)
+                            .ok_or_else(|| error(ParseStyleErrorKind::UnknownWord))?,
+                    )
+                };
+                let target = targets
+                    .next()
+                    .ok_or_else(|| error(ParseStyleErrorKind::DuplicateColor))?;
+                style = style.set_color(target, color);
+            }
+        }
+
+        Ok(style)
+    }
+}
+
+fn parse_git_attribute(word: &str) -> Option<(Effect, bool)> {
+    const ATTRIBUTES: [(&str, Effect); 7] = [
+        ("bold", Effect::Bold),
+        ("dim", Effect::Faint),
+        ("italic", Effect::Italic),
+        ("ul", Effect::Underline),
+        ("blink", Effect::Blink),
+        ("reverse", Effect::Reverse),
+        ("strike", Effect::Strikethrough),
+    ];
+
+    let (name, value) = match word.get(..2) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("no") => {
+            let name = &word[2..];
+            (name.strip_prefix('-').unwrap_or(name), false)
+        }
+        _ => (word, true),
+    };
+
+    ATTRIBUTES
+        .iter()
+        .find(|(attr_name, _)| name.eq_ignore_ascii_case(attr_name))
+        .map(|&(_, effect)| (effect, value))
+}
+
+/// Parses a git color other than `normal`, which leaves the color unset.
+fn parse_git_color(word: &str) -> Option<Color> {
+    let color = if word.eq_ignore_ascii_case("default") {
+        Color::Default
+    } else if let Some(hex) = word.strip_prefix('#') {
+        parse_hex(hex.as_bytes())?.into()
+    } else if let Ok(index) = word.parse::<u8>() {
+        IndexedColor(index).into()
+    } else if let Some(basic) = parse_basic_color(word) {
+        basic.into()
+    } else {
+        let basic = word
+            .get(.."bright".len())
+            .filter(|prefix| prefix.eq_ignore_ascii_case("bright"))
+            .and_then(|prefix| parse_basic_color(&word[prefix.len()..]))?;
+        basic.bright().into()
+    };
+    Some(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ToStyleSet as _, color::BasicColor};
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(Style::from_git_color(""), Ok(Style::new()));
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(
+            Style::from_git_color("red"),
+            Ok(Style::new().fg(BasicColor::Red))
+        );
+        assert_eq!(
+            Style::from_git_color("red blue"),
+            Ok(Style::new().fg(BasicColor::Red).bg(BasicColor::Blue))
+        );
+        assert_eq!(
+            Style::from_git_color("normal blue"),
+            Ok(Style::new().bg(BasicColor::Blue))
+        );
+        assert_eq!(
+            Style::from_git_color("default normal"),
+            Ok(Style::new().fg(Color::Default))
+        );
+        assert_eq!(
+            Style::from_git_color("brightred brightblack"),
+            Ok(Style::new()
+                .fg(BasicColor::Red.bright())
+                .bg(BasicColor::Black.bright()))
+        );
+        assert_eq!(
+            Style::from_git_color("123 #ff0ab3"),
+            Ok(Style::new()
+                .fg(Color::indexed(123))
+                .bg(Color::rgb(0xff, 0x0a, 0xb3)))
+        );
+        assert_eq!(
+            Style::from_git_color("#f1b"),
+            Ok(Style::new().fg(Color::rgb(0xff, 0x11, 0xbb)))
+        );
+    }
+
+    #[test]
+    fn attributes() {
+        assert_eq!(
+            Style::from_git_color("bold dim italic ul blink reverse strike"),
+            Ok(Style::new()
+                .bold()
+                .faint()
+                .italic()
+                .underline()
+                .blink()
+                .reverse()
+                .strikethrough())
+        );
+        assert_eq!(
+            Style::from_git_color("ul brightgreen"),
+            Ok(Style::new().underline().fg(BasicColor::Green.bright()))
+        );
+        assert_eq!(
+            Style::from_git_color("red bold blue"),
+            Ok(Style::new().bold().fg(BasicColor::Red).bg(BasicColor::Blue))
+        );
+    }
+
+    #[test]
+    fn negated_attributes() {
+        assert_eq!(Style::from_git_color("bold nobold"), Ok(Style::new()));
+        assert_eq!(
+            Style::from_git_color("ul italic no-ul"),
+            Ok(Style::new().italic())
+        );
+        assert_eq!(Style::from_git_color("noreverse"), Ok(Style::new()));
+    }
+
+    #[test]
+    fn reset() {
+        assert_eq!(Style::from_git_color("reset bold"), Ok(Style::new().bold()));
+    }
+
+    #[test]
+    fn invalid() {
+        let unknown = |span| Err(ParseStyleError::new(ParseStyleErrorKind::UnknownWord, span));
+
+        assert_eq!(
+            Style::from_git_color("red blue green"),
+            Err(ParseStyleError::new(
+                ParseStyleErrorKind::DuplicateColor,
+                9..14
+            ))
+        );
+        assert_eq!(Style::from_git_color("underline"), unknown(0..9));
+        assert_eq!(Style::from_git_color("bold no-red"), unknown(5..11));
+        assert_eq!(Style::from_git_color("256"), unknown(0..3));
+        assert_eq!(Style::from_git_color(" bright "), unknown(1..7));
+    }
+}
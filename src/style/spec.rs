@@ -0,0 +1,523 @@
+use core::{
+    error::Error,
+    fmt::{Display, Formatter},
+    ops::Range,
+    str::FromStr,
+};
+
+use crate::{
+    ColorTarget, Effect, Style, StyleSet as _,
+    color::{BASIC_COLOR_NAMES, BasicColor, Color},
+};
+
+/// A value that renders a specification string.
+///
+/// It is returned by [`Style::to_spec()`] and [`Color::to_spec()`], and renders the
+/// specification when formatted with [`Display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Spec<T>(T);
+
+/// The error returned when parsing a [`Style`] fails.
+///
+/// See [`Style::from_spec()`], [`Style::from_git_color()`], [`Style::from_sgr_params()`] and [`Style::from_codes()`].
+///
+/// Besides the [kind](ParseStyleError::kind) of the error, it tells the [span](ParseStyleError::span) of the
+/// offending word or parameter, which can be used to point at it in the parsed string:
+///
+/// ```
+/// use fluent_ansi::{ParseStyleErrorKind, Style};
+///
+/// let spec = "bold yelow on black";
+/// let error = Style::from_spec(spec).unwrap_err();
+/// assert_eq!(error.kind(), ParseStyleErrorKind::UnknownWord);
+/// assert_eq!(&spec[error.span()], "yelow");
+/// assert_eq!(error.to_string(), "unknown word at 5..10");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseStyleError {
+    kind: ParseStyleErrorKind,
+    start: usize,
+    end: usize,
+}
+
+impl ParseStyleError {
+    pub(crate) const fn new(kind: ParseStyleErrorKind, span: Range<usize>) -> Self {
+        Self {
+            kind,
+            start: span.start,
+            end: span.end,
+        }
+    }
+
+    /// Returns the same error, with its span moved forward by `offset` bytes.
+    pub(crate) const fn offset_by(self, offset: usize) -> Self {
+        Self {
+            start: self.start + offset,
+            end: self.end + offset,
+            ..self
+        }
+    }
+
+    /// Gets the kind of the error.
+    #[must_use]
+    pub const fn kind(&self) -> ParseStyleErrorKind {
+        self.kind
+    }
+
+    /// Gets the byte range of the offending word or parameter in the parsed string.
+    ///
+    /// For [`Style::from_codes()`], the range is of the indexes of the offending codes.
+    #[must_use]
+    pub const fn span(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl Display for ParseStyleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.start, self.end)
+    }
+}
+
+impl Error for ParseStyleError {}
+
+/// The kind of a [`ParseStyleError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseStyleErrorKind {
+    /// A word is neither an effect nor a color.
+    UnknownWord,
+    /// A word that must be followed by a color, like `on`, is not.
+    MissingColor,
+    /// A color is given for a target that already has one, or there are more colors than targets.
+    DuplicateColor,
+    /// A parameter is invalid or not supported.
+    InvalidParameter,
+    /// An extended color parameter is missing some of its sub-parameters.
+    IncompleteColor,
+}
+
+impl Display for ParseStyleErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ParseStyleErrorKind::UnknownWord => "unknown word",
+            ParseStyleErrorKind::MissingColor => "missing color",
+            ParseStyleErrorKind::DuplicateColor => "duplicate color",
+            ParseStyleErrorKind::InvalidParameter => "invalid parameter",
+            ParseStyleErrorKind::IncompleteColor => "incomplete color",
+        })
+    }
+}
+
+impl Style {
+    /// Parses a style from a human-readable specification string.
+    ///
+    /// The specification is a whitespace-separated list of words, in any order:
+    /// - an effect name, like `bold`, `italic` or `curly-underline`, sets that effect;
+    /// - a color sets the foreground color;
+    /// - `on` followed by a color sets the background color;
+    /// - `under` followed by a color sets the underline color.
+    ///
+    /// Colors are parsed as described in the [`FromStr`] implementation for [`Color`].
+    /// Effect names are case-insensitive and accept either `-` or `_` as word separators.
+    /// `dim` and `hidden` are accepted as aliases for `faint` and `conceal`.
+    ///
+    /// Each color target can be set only once. An empty specification results in an empty style.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let style = Style::from_spec("bold underline yellow on #222").unwrap();
+    /// assert_eq!(style, Style::new().bold().underline().fg(Color::YELLOW).bg(Color::rgb(0x22, 0x22, 0x22)));
+    ///
+    /// let style = Style::from_spec("curly-underline under red").unwrap();
+    /// assert_eq!(style, Style::new().curly_underline().underline_color(Color::RED));
+    ///
+    /// assert!(Style::from_spec("bold on").is_err());
+    /// assert!(Style::from_spec("red blue").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseStyleError`] if a word is not recognized, if `on` or `under` is not followed by a color,
+    /// or if a color target is set more than once.
+    pub fn from_spec(spec: &str) -> Result<Style, ParseStyleError> {
+        let mut style = Style::new();
+        let mut words = spec
+            .split_whitespace()
+            .map(|word| (word_span(spec, word), word));
+
+        while let Some((span, word)) = words.next() {
+            let target = if word.eq_ignore_ascii_case("on") {
+                Some(ColorTarget::Background)
+            } else if word.eq_ignore_ascii_case("under") {
+                Some(ColorTarget::Underline)
+            } else {
+                None
+            };
+
+            if let Some(target) = target {
+                let (span, color) = words.next().ok_or(ParseStyleError::new(
+                    ParseStyleErrorKind::MissingColor,
+                    span,
+                ))?;
+                style = set_spec_color(style, target, color, span)?;
+            } else if let Some(effect) = parse_effect_name(word) {
+                style = style.set_effect(effect, true);
+            } else {
+                style = set_spec_color(style, ColorTarget::Foreground, word, span)?;
+            }
+        }
+
+        Ok(style)
+    }
+
+    /// Returns a value that renders the specification string of this style.
+    ///
+    /// Effects are rendered first, followed by the foreground color, the background color (prefixed with `on`)
+    /// and the underline color (prefixed with `under`).
+    ///
+    /// The rendered specification parses back to the same style with [`Style::from_spec()`], except for the
+    /// [custom code](crate::CustomCode), which has no representation in the specification and is not rendered.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let style = Color::RED.bold().bg(Color::BLUE);
+    /// assert_eq!(format!("{}", style.to_spec()), "bold red on blue");
+    ///
+    /// let style = Style::new().curly_underline().underline_color(Color::rgb(0, 128, 255));
+    /// assert_eq!(format!("{}", style.to_spec()), "curly-underline under #0080ff");
+    /// ```
+    #[must_use]
+    pub const fn to_spec(self) -> Spec<Style> {
+        Spec(self)
+    }
+}
+
+impl Color {
+    /// Returns a value that renders the specification string of this color.
+    ///
+    /// The rendered specification parses back to the same color. See the [`FromStr`] implementation for [`Color`].
+    ///
+    /// ```
+    /// use fluent_ansi::prelude::*;
+    ///
+    /// assert_eq!(format!("{}", Color::RED.to_color().to_spec()), "red");
+    /// assert_eq!(format!("{}", Color::RED.bright().to_color().to_spec()), "bright-red");
+    /// assert_eq!(format!("{}", Color::indexed(123).to_color().to_spec()), "123");
+    /// assert_eq!(format!("{}", Color::rgb(0, 255, 136).to_color().to_spec()), "#00ff88");
+    /// ```
+    #[must_use]
+    pub const fn to_spec(self) -> Spec<Color> {
+        Spec(self)
+    }
+}
+
+impl Display for Spec<Style> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let style = self.0;
+        let mut separator = "";
+        let mut write_word = |f: &mut Formatter<'_>, word: &dyn Display| {
+            let result = write!(f, "{separator}{word}");
+            separator = " ";
+            result
+        };
+
+        for effect in style.get_effects() {
+            write_word(f, &effect_name(effect))?;
+        }
+        if let Some(color) = style.get_color(ColorTarget::Foreground) {
+            write_word(f, &color.to_spec())?;
+        }
+        if let Some(color) = style.get_color(ColorTarget::Background) {
+            write_word(f, &format_args!("on {}", color.to_spec()))?;
+        }
+        if let Some(color) = style.get_color(ColorTarget::Underline) {
+            write_word(f, &format_args!("under {}", color.to_spec()))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Spec<Color> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Color::Simple(simple) => {
+                if simple.is_bright() {
+                    f.write_str("bright-")?;
+                }
+                f.write_str(basic_color_name(simple.get_basic_color()))
+            }
+            Color::Indexed(indexed) => write!(f, "{}", indexed.get_index()),
+            Color::RGB(rgb) => write!(f, "#{:02x}{:02x}{:02x}", rgb.r, rgb.g, rgb.b),
+            Color::Default => f.write_str("default"),
+        }
+    }
+}
+
+/// Parses a style from a specification string.
+///
+/// See [`Style::from_spec()`].
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Style::from_spec(s)
+    }
+}
+
+fn set_spec_color(
+    style: Style,
+    target: ColorTarget,
+    word: &str,
+    span: Range<usize>,
+) -> Result<Style, ParseStyleError> {
+    let color: Color = word
+        .parse()
+        .map_err(|_| ParseStyleError::new(ParseStyleErrorKind::UnknownWord, span.clone()))?;
+    if style.get_color(target).is_some() {
+        return Err(ParseStyleError::new(
+            ParseStyleErrorKind::DuplicateColor,
+            span,
+        ));
+    }
+    Ok(style.set_color(target, Some(color)))
+}
+
+/// Gets the byte range of a word in the text it was split from.
+pub(crate) fn word_span(text: &str, word: &str) -> Range<usize> {
+    let start = word.as_ptr().addr() - text.as_ptr().addr();
+    start..start + word.len()
+}
+
+pub(crate) fn basic_color_name(basic_color: BasicColor) -> &'static str {
+    BASIC_COLOR_NAMES
+        .iter()
+        .find(|&&(color, _)| color == basic_color)
+        .map_or("", |&(_, name)| name)
+}
+
+pub(crate) fn effect_name(effect: Effect) -> &'static str {
+    match effect {
+        Effect::Bold => "bold",
+        Effect::Faint => "faint",
+        Effect::Italic => "italic",
+        Effect::Underline => "underline",
+        Effect::CurlyUnderline => "curly-underline",
+        Effect::DottedUnderline => "dotted-underline",
+        Effect::DashedUnderline => "dashed-underline",
+        Effect::Blink => "blink",
+        Effect::RapidBlink => "rapid-blink",
+        Effect::Reverse => "reverse",
+        Effect::Conceal => "conceal",
+        Effect::Strikethrough => "strikethrough",
+        Effect::Fraktur => "fraktur",
+        Effect::DoubleUnderline => "double-underline",
+        Effect::Framed => "framed",
+        Effect::Encircled => "encircled",
+        Effect::Overline => "overline",
+        Effect::Superscript => "superscript",
+        Effect::Subscript => "subscript",
+    }
+}
+
+pub(crate) fn parse_effect_name(word: &str) -> Option<Effect> {
+    let matches = |name: &str| {
+        name.len() == word.len()
+            && name
+                .bytes()
+                .zip(word.bytes())
+                .all(|(n, w)| n == w.to_ascii_lowercase() || (n == b'-' && w == b'_'))
+    };
+
+    if matches("dim") {
+        Some(Effect::Faint)
+    } else if matches("hidden") {
+        Some(Effect::Conceal)
+    } else {
+        Effect::iter().find(|&effect| matches(effect_name(effect)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CustomCode, ToStyleSet as _, color::BasicColor};
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(Style::from_spec(""), Ok(Style::new()));
+        assert_eq!(Style::from_spec("  "), Ok(Style::new()));
+    }
+
+    #[test]
+    fn effects() {
+        for effect in Effect::iter() {
+            assert_eq!(
+                Style::from_spec(effect_name(effect)),
+                Ok(Style::new().effect(effect)),
+                "{effect:?}"
+            );
+        }
+
+        assert_eq!(Style::from_spec("dim"), Ok(Style::new().faint()));
+        assert_eq!(Style::from_spec("hidden"), Ok(Style::new().conceal()));
+        assert_eq!(Style::from_spec("BOLD"), Ok(Style::new().bold()));
+        assert_eq!(
+            Style::from_spec("Curly_Underline"),
+            Ok(Style::new().curly_underline())
+        );
+        assert_eq!(
+            Style::from_spec("bold italic"),
+            Ok(Style::new().bold().italic())
+        );
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(
+            Style::from_spec("red"),
+            Ok(Style::new().fg(BasicColor::Red))
+        );
+        assert_eq!(
+            Style::from_spec("on red"),
+            Ok(Style::new().bg(BasicColor::Red))
+        );
+        assert_eq!(
+            Style::from_spec("under red"),
+            Ok(Style::new().underline_color(BasicColor::Red))
+        );
+        assert_eq!(
+            Style::from_spec("on blue red under #00ff00"),
+            Ok(Style::new()
+                .fg(BasicColor::Red)
+                .bg(BasicColor::Blue)
+                .underline_color(Color::rgb(0, 255, 0)))
+        );
+    }
+
+    #[test]
+    fn combined() {
+        assert_eq!(
+            Style::from_spec("bold underline yellow on #222"),
+            Ok(Style::new()
+                .bold()
+                .underline()
+                .fg(BasicColor::Yellow)
+                .bg(Color::rgb(0x22, 0x22, 0x22)))
+        );
+        assert_eq!(
+            "italic bright-green".parse(),
+            Ok(Style::new().italic().fg(BasicColor::Green.bright()))
+        );
+    }
+
+    #[test]
+    fn to_spec() {
+        assert_eq!(format!("{}", Style::new().to_spec()), "");
+        assert_eq!(format!("{}", Style::new().bold().to_spec()), "bold");
+        assert_eq!(
+            format!("{}", Style::new().italic().bold().to_spec()),
+            "bold italic"
+        );
+        assert_eq!(
+            format!("{}", Style::new().fg(BasicColor::Red).to_spec()),
+            "red"
+        );
+        assert_eq!(
+            format!("{}", Style::new().bg(BasicColor::Red).to_spec()),
+            "on red"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Style::new().underline_color(BasicColor::Red).to_spec()
+            ),
+            "under red"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Style::new()
+                    .underline_color(Color::indexed(42))
+                    .bg(Color::rgb(0, 128, 255))
+                    .fg(BasicColor::Green.bright())
+                    .dotted_underline()
+                    .bold()
+                    .to_spec()
+            ),
+            "bold dotted-underline bright-green on #0080ff under 42"
+        );
+        assert_eq!(
+            format!("{}", Style::new().bg(Color::Default).to_spec()),
+            "on default"
+        );
+    }
+
+    #[test]
+    fn to_spec_round_trip() {
+        let styles = [
+            Style::new(),
+            Style::new().bold().faint().italic().blink(),
+            Style::new().reverse().conceal().strikethrough().overline(),
+            Style::new().double_underline().fg(Color::Default),
+            Style::new()
+                .dashed_underline()
+                .fg(BasicColor::Black)
+                .bg(BasicColor::White.bright())
+                .underline_color(Color::rgb(1, 2, 3)),
+        ];
+
+        for style in styles {
+            let spec = format!("{}", style.to_spec());
+            assert_eq!(Style::from_spec(&spec), Ok(style), "{spec}");
+        }
+    }
+
+    #[test]
+    fn to_spec_drops_custom_code() {
+        let style = Style::new().bold().fg(BasicColor::Red);
+        let spec = format!("{}", style.add(CustomCode::new("60")).to_spec());
+        assert_eq!(spec, "bold red");
+        assert_eq!(Style::from_spec(&spec), Ok(style));
+    }
+
+    #[test]
+    fn invalid() {
+        let error = |kind, span| Err(ParseStyleError::new(kind, span));
+
+        assert_eq!(
+            Style::from_spec("bold on"),
+            error(ParseStyleErrorKind::MissingColor, 5..7)
+        );
+        assert_eq!(
+            Style::from_spec("under"),
+            error(ParseStyleErrorKind::MissingColor, 0..5)
+        );
+        assert_eq!(
+            Style::from_spec("on bold"),
+            error(ParseStyleErrorKind::UnknownWord, 3..7)
+        );
+        assert_eq!(
+            Style::from_spec("red blue"),
+            error(ParseStyleErrorKind::DuplicateColor, 4..8)
+        );
+        assert_eq!(
+            Style::from_spec("on red  on\tblue"),
+            error(ParseStyleErrorKind::DuplicateColor, 11..15)
+        );
+        assert_eq!(
+            Style::from_spec(" boldd"),
+            error(ParseStyleErrorKind::UnknownWord, 1..6)
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        let error = ParseStyleError::new(ParseStyleErrorKind::MissingColor, 5..7);
+        assert_eq!(error.kind(), ParseStyleErrorKind::MissingColor);
+        assert_eq!(error.span(), 5..7);
+        assert_eq!(error.to_string(), "missing color at 5..7");
+    }
+}
@@ -0,0 +1,333 @@
+use core::{
+    error::Error,
+    fmt::{Display, Formatter, Result},
+};
+
+use enum_iterator::Sequence;
+
+use crate::{
+    ColorTarget, Effect, Style, StyleSet as _, ToStyleSet as _, UnderlineStyle, color::Color,
+    style::effect_name,
+};
+
+/// A builder of [`Style`] values that validates the combination of the elements added to it.
+///
+/// Unlike the [`ToStyleSet`](crate::ToStyleSet) methods, which silently replace an effect or color that can't be
+/// combined with the one being added, the builder reports the conflict as an error when the style is built:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, Style, StyleBuildError, StyleBuilder};
+///
+/// let builder = StyleBuilder::new().effect(Effect::Bold).fg(Color::RED);
+/// assert_eq!(builder.build(), Ok(Style::new().bold().fg(Color::RED)));
+///
+/// let builder = StyleBuilder::new().effect(Effect::Blink).effect(Effect::RapidBlink);
+/// assert_eq!(builder.build(), Err(StyleBuildError::ConflictingEffects(Effect::Blink, Effect::RapidBlink)));
+/// ```
+///
+/// Combinations that are valid but probably unintended are reported as warnings by
+/// [`warnings()`](StyleBuilder::warnings):
+///
+/// ```
+/// use fluent_ansi::{prelude::*, StyleBuilder, StyleWarning};
+///
+/// let builder = StyleBuilder::new().underline_color(Color::RED);
+/// assert!(builder.warnings().eq([StyleWarning::UnderlineColorWithoutUnderline]));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StyleBuilder {
+    style: Style,
+    error: Option<StyleBuildError>,
+}
+
+impl StyleBuilder {
+    /// Creates a new `StyleBuilder` value, with no effects or colors.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new(),
+            error: None,
+        }
+    }
+
+    /// Adds an effect.
+    ///
+    /// It is an error to add an effect that can't be combined with a previously added one, like blink and rapid
+    /// blink, superscript and subscript, or two different underline styles.
+    #[must_use]
+    pub fn effect(mut self, effect: impl Into<Effect>) -> Self {
+        let effect = effect.into();
+        if let Some(other) = Effect::iter()
+            .find(|&other| self.style.get_effect(other) && effects_conflict(other, effect))
+        {
+            self.set_error(StyleBuildError::ConflictingEffects(other, effect));
+        }
+        self.style = self.style.effect(effect);
+        self
+    }
+
+    /// Sets the color of a target.
+    ///
+    /// It is an error to set a different color for a target that already has one.
+    #[must_use]
+    pub fn color(mut self, target: ColorTarget, color: impl Into<Color>) -> Self {
+        let color = color.into();
+        if self
+            .style
+            .get_color(target)
+            .is_some_and(|current| current != color)
+        {
+            self.set_error(StyleBuildError::ConflictingColors(target));
+        }
+        self.style = self.style.set_color(target, Some(color));
+        self
+    }
+
+    /// Sets the foreground color. See [`color()`](StyleBuilder::color).
+    #[must_use]
+    pub fn fg(self, color: impl Into<Color>) -> Self {
+        self.color(ColorTarget::Foreground, color)
+    }
+
+    /// Sets the background color. See [`color()`](StyleBuilder::color).
+    #[must_use]
+    pub fn bg(self, color: impl Into<Color>) -> Self {
+        self.color(ColorTarget::Background, color)
+    }
+
+    /// Sets the underline color. See [`color()`](StyleBuilder::color).
+    #[must_use]
+    pub fn underline_color(self, color: impl Into<Color>) -> Self {
+        self.color(ColorTarget::Underline, color)
+    }
+
+    /// Returns an iterator over the warnings about the style being built.
+    #[must_use]
+    pub fn warnings(&self) -> StyleWarnings {
+        StyleWarnings {
+            style: self.style,
+            all: enum_iterator::all(),
+        }
+    }
+
+    /// Builds the style.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`StyleBuildError`] found while adding the elements.
+    pub fn build(self) -> core::result::Result<Style, StyleBuildError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.style),
+        }
+    }
+
+    fn set_error(&mut self, error: StyleBuildError) {
+        self.error.get_or_insert(error);
+    }
+}
+
+fn effects_conflict(a: Effect, b: Effect) -> bool {
+    let is_underline = |effect| UnderlineStyle::iter().any(|style| style.to_effect() == effect);
+    a != b && (a.exclusive_with() == Some(b) || (is_underline(a) && is_underline(b)))
+}
+
+/// The error returned by [`StyleBuilder::build()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleBuildError {
+    /// Two effects that can't be combined were added, in this order.
+    ConflictingEffects(Effect, Effect),
+    /// Different colors were set for the same target.
+    ConflictingColors(ColorTarget),
+}
+
+impl Display for StyleBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match *self {
+            StyleBuildError::ConflictingEffects(a, b) => write!(
+                f,
+                "conflicting effects: {} and {}",
+                effect_name(a),
+                effect_name(b)
+            ),
+            StyleBuildError::ConflictingColors(target) => {
+                let target = match target {
+                    ColorTarget::Foreground => "foreground",
+                    ColorTarget::Background => "background",
+                    ColorTarget::Underline => "underline",
+                };
+                write!(f, "conflicting {target} colors")
+            }
+        }
+    }
+}
+
+impl Error for StyleBuildError {}
+
+/// A combination of style elements that is valid, but probably unintended.
+///
+/// See [`StyleBuilder::warnings()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
+pub enum StyleWarning {
+    /// An underline color is set, but no underline effect is, so the color has no visible effect.
+    UnderlineColorWithoutUnderline,
+    /// The foreground and background colors are the same, so the text is not readable.
+    SameForegroundAndBackground,
+}
+
+impl StyleWarning {
+    fn applies_to(self, style: Style) -> bool {
+        match self {
+            StyleWarning::UnderlineColorWithoutUnderline => {
+                style.get_color(ColorTarget::Underline).is_some()
+                    && style.get_underline_style().is_none()
+            }
+            StyleWarning::SameForegroundAndBackground => {
+                let fg = style.get_color(ColorTarget::Foreground);
+                fg.is_some() && fg == style.get_color(ColorTarget::Background)
+            }
+        }
+    }
+}
+
+impl Display for StyleWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            StyleWarning::UnderlineColorWithoutUnderline => "underline color without underline",
+            StyleWarning::SameForegroundAndBackground => "same foreground and background colors",
+        })
+    }
+}
+
+/// An iterator over the warnings about a style.
+///
+/// It is returned by [`StyleBuilder::warnings()`].
+#[derive(Debug, Clone)]
+pub struct StyleWarnings {
+    style: Style,
+    all: enum_iterator::All<StyleWarning>,
+}
+
+impl Iterator for StyleWarnings {
+    type Item = StyleWarning;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let style = self.style;
+        self.all.find(|warning| warning.applies_to(style))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::color::Color;
+
+    use super::*;
+
+    #[test]
+    fn build() {
+        assert_eq!(StyleBuilder::new().build(), Ok(Style::new()));
+        assert_eq!(StyleBuilder::default(), StyleBuilder::new());
+        assert_eq!(
+            StyleBuilder::new()
+                .effect(Effect::Bold)
+                .effect(UnderlineStyle::Curly)
+                .effect(Effect::Bold)
+                .fg(Color::RED)
+                .bg(Color::BLUE)
+                .underline_color(Color::GREEN)
+                .fg(Color::RED)
+                .build(),
+            Ok(Style::new()
+                .bold()
+                .curly_underline()
+                .fg(Color::RED)
+                .bg(Color::BLUE)
+                .underline_color(Color::GREEN))
+        );
+    }
+
+    #[test]
+    fn conflicts() {
+        let error = |builder: StyleBuilder| builder.build().unwrap_err();
+
+        assert_eq!(
+            error(
+                StyleBuilder::new()
+                    .effect(Effect::RapidBlink)
+                    .effect(Effect::Blink)
+            ),
+            StyleBuildError::ConflictingEffects(Effect::RapidBlink, Effect::Blink)
+        );
+        assert_eq!(
+            error(
+                StyleBuilder::new()
+                    .effect(Effect::Superscript)
+                    .effect(Effect::Subscript)
+            ),
+            StyleBuildError::ConflictingEffects(Effect::Superscript, Effect::Subscript)
+        );
+        assert_eq!(
+            error(
+                StyleBuilder::new()
+                    .effect(Effect::Underline)
+                    .effect(Effect::DoubleUnderline)
+            ),
+            StyleBuildError::ConflictingEffects(Effect::Underline, Effect::DoubleUnderline)
+        );
+        assert_eq!(
+            error(StyleBuilder::new().bg(Color::RED).bg(Color::BLUE)),
+            StyleBuildError::ConflictingColors(ColorTarget::Background)
+        );
+        assert_eq!(
+            error(
+                StyleBuilder::new()
+                    .fg(Color::RED)
+                    .fg(Color::BLUE)
+                    .effect(Effect::Blink)
+                    .effect(Effect::RapidBlink)
+            ),
+            StyleBuildError::ConflictingColors(ColorTarget::Foreground)
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            StyleBuildError::ConflictingEffects(Effect::Blink, Effect::RapidBlink).to_string(),
+            "conflicting effects: blink and rapid-blink"
+        );
+        assert_eq!(
+            StyleBuildError::ConflictingColors(ColorTarget::Underline).to_string(),
+            "conflicting underline colors"
+        );
+    }
+
+    #[test]
+    fn warnings() {
+        assert_eq!(StyleBuilder::new().warnings().next(), None);
+        assert_eq!(
+            StyleBuilder::new()
+                .effect(Effect::Underline)
+                .underline_color(Color::RED)
+                .fg(Color::RED)
+                .warnings()
+                .next(),
+            None
+        );
+        assert!(
+            StyleBuilder::new()
+                .underline_color(Color::RED)
+                .fg(Color::BLUE)
+                .bg(Color::BLUE)
+                .warnings()
+                .eq([
+                    StyleWarning::UnderlineColorWithoutUnderline,
+                    StyleWarning::SameForegroundAndBackground,
+                ])
+        );
+        assert_eq!(
+            StyleWarning::SameForegroundAndBackground.to_string(),
+            "same foreground and background colors"
+        );
+    }
+}
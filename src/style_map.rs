@@ -0,0 +1,249 @@
+use core::str::Split;
+
+use crate::{ParseStyleError, Style, sgr, style::word_span};
+
+/// A lookup of styles parsed from a `key=SGR-params` list, like the ones in the
+/// `LS_COLORS` and `GREP_COLORS` environment variables.
+///
+/// Entries are separated by `:`, and each entry associates a key with a list of SGR parameters
+/// separated by `;`. Entries without a `=` (like the boolean capabilities in `GREP_COLORS`) are ignored.
+///
+/// The map borrows the parsed string and does not allocate. Lookups scan the entries, and when
+/// a key occurs more than once, the last entry wins. Entries whose values are not valid SGR parameters, like
+/// `ln=target` in `LS_COLORS`, are skipped by the lookups.
+///
+/// ```
+/// use fluent_ansi::{prelude::*, Style, StyleMap};
+///
+/// let ls_colors = StyleMap::parse_lossy("rs=0:di=01;34:ln=target:*.tar=01;31");
+///
+/// assert_eq!(ls_colors.get("di"), Some(Color::BLUE.bold()));
+/// assert_eq!(ls_colors.get("*.tar"), Some(Color::RED.bold()));
+/// assert_eq!(ls_colors.get("rs"), Some(Style::new()));
+/// assert_eq!(ls_colors.get("ln"), None);
+/// assert_eq!(ls_colors.get("ex"), None);
+///
+/// let grep_colors = StyleMap::parse("ms=01;31:mc=01;31:sl=:cx=:fn=35:ln=32:bn=32:se=36:ne").unwrap();
+/// assert_eq!(grep_colors.get("fn"), Some(Style::new().fg(Color::MAGENTA)));
+/// assert_eq!(grep_colors.get("ne"), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyleMap<'a>(&'a str);
+
+impl<'a> StyleMap<'a> {
+    /// Parses a `key=SGR-params` list, validating all of its entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseStyleError`] if the parameters of any entry are invalid. Its span is relative to the whole
+    /// string.
+    pub fn parse(s: &'a str) -> Result<Self, ParseStyleError> {
+        let map = StyleMap(s);
+        for (_, params) in map.raw_entries() {
+            sgr::apply_params(Style::new(), params)
+                .map_err(|error| error.offset_by(word_span(s, params).start))?;
+        }
+        Ok(map)
+    }
+
+    /// Parses a `key=SGR-params` list without validating its entries.
+    ///
+    /// Entries whose parameters are invalid are skipped by [`get()`](Self::get) and [`iter()`](Self::iter), so a
+    /// single entry with a non-SGR value, like `ln=target` in `LS_COLORS`, does not discard the others.
+    #[must_use]
+    pub const fn parse_lossy(s: &'a str) -> Self {
+        StyleMap(s)
+    }
+
+    /// Gets the style associated with the given key.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<Style> {
+        self.iter()
+            .filter(|&(entry_key, _)| entry_key == key)
+            .last()
+            .map(|(_, style)| style)
+    }
+
+    /// Returns an iterator over the entries, in the order they appear in the parsed string.
+    #[must_use]
+    pub fn iter(&self) -> StyleMapIter<'a> {
+        StyleMapIter(self.raw_entries())
+    }
+
+    fn raw_entries(&self) -> RawEntries<'a> {
+        RawEntries(self.0.split(':'))
+    }
+}
+
+impl<'a> IntoIterator for StyleMap<'a> {
+    type Item = (&'a str, Style);
+    type IntoIter = StyleMapIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &StyleMap<'a> {
+    type Item = (&'a str, Style);
+    type IntoIter = StyleMapIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a [`StyleMap`].
+pub struct StyleMapIter<'a>(RawEntries<'a>);
+
+impl<'a> Iterator for StyleMapIter<'a> {
+    type Item = (&'a str, Style);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().find_map(|(key, params)| {
+            let style = sgr::apply_params(Style::new(), params).ok()?;
+            Some((key, style))
+        })
+    }
+}
+
+struct RawEntries<'a>(Split<'a, char>);
+
+impl<'a> Iterator for RawEntries<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().find_map(|entry| entry.split_once('='))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParseStyleErrorKind, ToStyleSet as _, color::BasicColor};
+
+    use super::*;
+
+    #[test]
+    fn get() {
+        let map = StyleMap::parse("di=01;34:ln=01;36:*.tar=01;31").unwrap();
+
+        assert_eq!(
+            map.get("di"),
+            Some(Style::new().bold().fg(BasicColor::Blue))
+        );
+        assert_eq!(
+            map.get("ln"),
+            Some(Style::new().bold().fg(BasicColor::Cyan))
+        );
+        assert_eq!(
+            map.get("*.tar"),
+            Some(Style::new().bold().fg(BasicColor::Red))
+        );
+        assert_eq!(map.get("ex"), None);
+    }
+
+    #[test]
+    fn last_entry_wins() {
+        let map = StyleMap::parse("di=34:di=35").unwrap();
+        assert_eq!(map.get("di"), Some(Style::new().fg(BasicColor::Magenta)));
+    }
+
+    #[test]
+    fn empty_values() {
+        let map = StyleMap::parse("sl=:cx=").unwrap();
+        assert_eq!(map.get("sl"), Some(Style::new()));
+        assert_eq!(map.get("cx"), Some(Style::new()));
+    }
+
+    #[test]
+    fn entries_without_value() {
+        let map = StyleMap::parse("ms=01;31:rv:ne").unwrap();
+        assert_eq!(map.get("rv"), None);
+        assert_eq!(map.get("ne"), None);
+        assert_eq!(map.iter().count(), 1);
+    }
+
+    #[test]
+    fn empty() {
+        let map = StyleMap::parse("").unwrap();
+        assert_eq!(map.iter().next(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let map = StyleMap::parse("rs=0:di=01;34:or=40;31;01").unwrap();
+        let mut iter = map.into_iter();
+
+        assert_eq!(iter.next(), Some(("rs", Style::new())));
+        assert_eq!(
+            iter.next(),
+            Some(("di", Style::new().bold().fg(BasicColor::Blue)))
+        );
+        assert_eq!(
+            iter.next(),
+            Some((
+                "or",
+                Style::new()
+                    .bold()
+                    .fg(BasicColor::Red)
+                    .bg(BasicColor::Black)
+            ))
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn lossy() {
+        let map = StyleMap::parse_lossy("di=01;34:ln=x:ex=01;32:ln=38;5");
+        assert_eq!(
+            map.get("di"),
+            Some(Style::new().bold().fg(BasicColor::Blue))
+        );
+        assert_eq!(map.get("ln"), None);
+        assert_eq!(map.iter().count(), 2);
+
+        let map = StyleMap::parse_lossy("ln=36:ln=target");
+        assert_eq!(map.get("ln"), Some(Style::new().fg(BasicColor::Cyan)));
+    }
+
+    #[test]
+    fn dircolors() {
+        // `dircolors -b` output for a configuration with `LINK target`
+        let ls_colors = "rs=0:di=01;34:ln=target:mh=00:pi=40;33:so=01;35:do=01;35:bd=40;33;01:cd=40;33;01:\
+            or=40;31;01:mi=00:su=37;41:sg=30;43:ca=00:tw=30;42:ow=34;42:st=37;44:ex=01;32:*.tar=01;31:\
+            *.tgz=01;31:*.jpg=01;35:*.flac=00;36:*~=00;90:*#=00;90:*.bak=00;90:";
+        assert!(StyleMap::parse(ls_colors).is_err());
+
+        let map = StyleMap::parse_lossy(ls_colors);
+        assert_eq!(map.get("rs"), Some(Style::new()));
+        assert_eq!(map.get("ln"), None);
+        assert_eq!(
+            map.get("or"),
+            Some(
+                Style::new()
+                    .bold()
+                    .fg(BasicColor::Red)
+                    .bg(BasicColor::Black)
+            )
+        );
+        assert_eq!(
+            map.get("*.bak"),
+            Some(Style::new().fg(BasicColor::Black.bright()))
+        );
+        assert_eq!(map.iter().count(), 24);
+    }
+
+    #[test]
+    fn invalid() {
+        let error = |kind, span| Err(ParseStyleError::new(kind, span));
+
+        assert_eq!(
+            StyleMap::parse("di=01;34:ln=x"),
+            error(ParseStyleErrorKind::InvalidParameter, 12..13)
+        );
+        assert_eq!(
+            StyleMap::parse("ne:di=38;5"),
+            error(ParseStyleErrorKind::IncompleteColor, 6..10)
+        );
+    }
+}
@@ -27,6 +27,21 @@ pub trait StyleSet: ToStyleSet<StyleSet = Self> {
         self.set(effect.into(), value)
     }
 
+    /// Sets the given effect if it is not set, or unsets it if it is.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// assert_eq!(Style::new().toggle_effect(Effect::Reverse), Style::new().reverse());
+    /// assert_eq!(Style::new().reverse().toggle_effect(Effect::Reverse), Style::new());
+    /// ```
+    #[must_use]
+    fn toggle_effect(self, effect: impl Into<Effect>) -> Self {
+        let effect = effect.into();
+        let value = !self.get_effect(effect);
+        self.set_effect(effect, value)
+    }
+
     /// Gets whether the given effect is set.
     #[must_use]
     fn get_effect(&self, effect: impl Into<Effect>) -> bool {
@@ -37,6 +52,50 @@ pub trait StyleSet: ToStyleSet<StyleSet = Self> {
     #[must_use]
     fn get_effects(&self) -> GetEffects;
 
+    /// Sets all the given effects, keeping the ones that are already set.
+    ///
+    /// The effects are set in order, so when two of them are mutually exclusive, the last one wins.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Effects, Style};
+    ///
+    /// let style = Style::new().italic().set_effects([Effect::Bold, Effect::Underline]);
+    /// assert_eq!(style, Style::new().bold().italic().underline());
+    ///
+    /// let emphasis = Effects::from_iter([Effect::Bold, Effect::Overline]);
+    /// assert_eq!(Style::new().set_effects(emphasis), Style::new().bold().overline());
+    /// ```
+    #[must_use]
+    fn set_effects(self, effects: impl IntoIterator<Item = Effect>) -> Self {
+        effects
+            .into_iter()
+            .fold(self, |style_set, effect| style_set.set_effect(effect, true))
+    }
+
+    /// Unsets all the effects, keeping the colors.
+    #[must_use]
+    fn clear_effects(self) -> Self {
+        self.retain_effects(|_| false)
+    }
+
+    /// Keeps only the effects for which the predicate returns `true`, unsetting the others.
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, Style};
+    ///
+    /// let style = Color::RED.bold().blink().underline();
+    /// let still = style.retain_effects(|effect| !matches!(effect, Effect::Blink | Effect::RapidBlink));
+    /// assert_eq!(still, Color::RED.bold().underline());
+    /// ```
+    #[must_use]
+    fn retain_effects(self, mut predicate: impl FnMut(Effect) -> bool) -> Self {
+        self.get_effects()
+            .filter(|&effect| !predicate(effect))
+            .fold(self, |style_set, effect| {
+                style_set.set_effect(effect, false)
+            })
+    }
+
     /// Sets the underline style.
     #[must_use]
     fn set_underline_style(self, underline_style: Option<UnderlineStyle>) -> Self {
@@ -46,7 +105,7 @@ pub trait StyleSet: ToStyleSet<StyleSet = Self> {
     /// Gets the underline style.
     #[must_use]
     fn get_underline_style(&self) -> Option<UnderlineStyle> {
-        UnderlineStyle::all().find(|&underline_style| self.get_effect(underline_style.to_effect()))
+        UnderlineStyle::iter().find(|&underline_style| self.get_effect(underline_style.to_effect()))
     }
 
     /// Sets the color for the given color target.
@@ -140,6 +199,38 @@ mod tests {
                     assert_eq!(effects.next(), None);
                 }
 
+                #[test]
+                fn bulk_effects() {
+                    let style_set = $empty_style_set.fg(BasicColor::Red).set_effects([
+                        Effect::Bold,
+                        Effect::Blink,
+                        Effect::RapidBlink,
+                    ]);
+                    assert_eq!(
+                        style_set,
+                        $empty_style_set.fg(BasicColor::Red).bold().rapid_blink()
+                    );
+                    assert_eq!(
+                        style_set.set_effects(Effects::from(Effect::Italic)),
+                        $empty_style_set
+                            .fg(BasicColor::Red)
+                            .bold()
+                            .italic()
+                            .rapid_blink()
+                    );
+
+                    assert_eq!(
+                        style_set.retain_effects(|effect| effect != Effect::Bold),
+                        $empty_style_set.fg(BasicColor::Red).rapid_blink()
+                    );
+                    assert_eq!(style_set.retain_effects(|_| true), style_set);
+                    assert_eq!(
+                        style_set.clear_effects(),
+                        $empty_style_set.fg(BasicColor::Red)
+                    );
+                    assert_eq!($empty_style_set.clear_effects(), $empty_style_set);
+                }
+
                 #[test]
                 fn underline_styles() {
                     let style_set = $empty_style_set;
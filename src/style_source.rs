@@ -0,0 +1,102 @@
+use crate::{AppliedTo, CompiledStyle, Style, ToStyle, ToStyleSet};
+
+/// A provider of a [`Style`] that can be used as a trait object.
+///
+/// The other styling traits, like [`ToStyle`] and [`ToStyleSet`], take `self` by value and have generic methods, so
+/// they can't be used as trait objects. This trait allows styles to be supplied through `&dyn DynStyleSource`
+/// references, like by plugins across crate boundaries. It is implemented by all the types that implement
+/// [`ToStyle`] and [`Copy`], like [`Style`], [`Effect`](crate::Effect) and the color types, and it can be
+/// implemented by other types that compute a style:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, DynStyleSource, Style, ToStyle as _};
+///
+/// struct Severity(u8);
+///
+/// impl DynStyleSource for Severity {
+///     fn style(&self) -> Style {
+///         if self.0 > 2 { Color::RED.bold() } else { Color::YELLOW.to_style() }
+///     }
+/// }
+///
+/// let sources: [&dyn DynStyleSource; 3] = [&Severity(3), &Effect::Italic, &Color::BLUE];
+/// let rendered: Vec<String> = sources.iter().map(|source| source.applied_to("x").to_string()).collect();
+/// assert_eq!(rendered, ["\x1b[1;31mx\x1b[0m", "\x1b[3mx\x1b[0m", "\x1b[34mx\x1b[0m"]);
+/// ```
+///
+/// A `&dyn DynStyleSource` implements [`ToStyle`], [`ToStyleSet`] and [`AppliedTo`], so it can be used wherever the
+/// elements of a style can.
+pub trait DynStyleSource {
+    /// Returns the style.
+    #[must_use]
+    fn style(&self) -> Style;
+}
+
+impl<T: ToStyle + Copy> DynStyleSource for T {
+    fn style(&self) -> Style {
+        (*self).to_style()
+    }
+}
+
+impl DynStyleSource for CompiledStyle {
+    fn style(&self) -> Style {
+        self.get_style()
+    }
+}
+
+impl From<&dyn DynStyleSource> for Style {
+    fn from(source: &dyn DynStyleSource) -> Self {
+        source.style()
+    }
+}
+
+impl ToStyle for &dyn DynStyleSource {
+    fn to_style(self) -> Style {
+        self.style()
+    }
+}
+
+impl ToStyleSet for &dyn DynStyleSource {
+    type StyleSet = Style;
+
+    fn to_style_set(self) -> Self::StyleSet {
+        self.style()
+    }
+}
+
+impl AppliedTo for &dyn DynStyleSource {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Effect, Reset,
+        color::{BasicColor, Color},
+    };
+
+    use super::*;
+
+    #[test]
+    fn implementations() {
+        let style = Style::new().bold().fg(Color::RED);
+        let compiled = style.compile().unwrap();
+        let sources: [(&dyn DynStyleSource, Style); 5] = [
+            (&style, style),
+            (&Effect::Italic, Style::new().italic()),
+            (&BasicColor::Blue, Style::new().fg(BasicColor::Blue)),
+            (&Reset, Style::new()),
+            (&compiled, style),
+        ];
+        for (source, expected) in sources {
+            assert_eq!(source.style(), expected);
+            assert_eq!(Style::from(source), expected);
+            assert_eq!(source.to_style(), expected);
+        }
+    }
+
+    #[test]
+    fn dyn_styling() {
+        let source: &dyn DynStyleSource = &Effect::Bold;
+        assert_eq!(source.fg(Color::RED), Style::new().bold().fg(Color::RED));
+        assert_eq!(source.applied_to("x").to_string(), "\x1b[1mx\x1b[0m");
+    }
+}
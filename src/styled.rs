@@ -1,13 +1,74 @@
-use core::fmt::{Display, Formatter, Result};
+use core::{
+    fmt::{
+        Binary, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, Result, UpperExp, UpperHex,
+    },
+    ops::Deref,
+};
 
-use crate::{GetEffects, Style, StyleElement, StyleSet, ToStyleSet};
+use crate::{GetEffects, RenderOptions, Reset, Style, StyleElement, StyleSet, ToStyleSet};
 
 /// A value that associates some content with a specific style.
+///
+/// When formatted with a width, the padding is computed from the visible width of the content, ignoring escape
+/// sequences, and is written outside the styling:
+///
+/// ```
+/// use fluent_ansi::prelude::*;
+///
+/// let styled = Color::RED.applied_to("Error");
+/// assert_eq!(format!("[{styled:>7}]"), "[  \x1b[31mError\x1b[0m]");
+/// assert_eq!(format!("[{styled:-^9}]"), "[--\x1b[31mError\x1b[0m--]");
+/// ```
+///
+/// In that case, the content is formatted twice: once to measure it, and once to write it.
+///
+/// The numeric formatting traits, like [`LowerHex`] and [`Binary`], are also implemented when the content implements
+/// them. The content is formatted with the same options, so the padding is written inside the styling:
+///
+/// ```
+/// use fluent_ansi::prelude::*;
+///
+/// let styled = Color::RED.applied_to(255);
+/// assert_eq!(format!("{styled:x}"), "\x1b[31mff\x1b[0m");
+/// assert_eq!(format!("{styled:#06X}"), "\x1b[31m0x00FF\x1b[0m");
+/// ```
+///
+/// The content can be accessed with [`AsRef`]. When it is string-like, like [`&str`](str) or `String`, the value also
+/// dereferences to a [`str`], so it can be used where a `&str` is expected for purposes other than rendering:
+///
+/// ```
+/// use fluent_ansi::prelude::*;
+///
+/// let styled = Color::RED.applied_to("Error");
+/// assert_eq!(styled.len(), 5);
+/// assert!(styled.starts_with("Err"));
+/// assert_eq!(&*styled, "Error");
+/// ```
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct Styled<C: Display> {
     content: C,
     style: Style,
 }
+
+/// A [`Styled<C>`] value that borrows its content, which can be unsized.
+///
+/// The content type defaults to `dyn Display`, which is the type returned by [`display()`](crate::AppliedTo::display).
+/// Other borrowed values can be obtained with [`applied_to()`](crate::AppliedTo::applied_to) or
+/// [`by_ref()`](Styled::by_ref):
+///
+/// ```
+/// use fluent_ansi::{prelude::*, StyledRef};
+///
+/// let content: &str = "Error";
+/// let styled: StyledRef<str> = Color::RED.applied_to(content);
+/// assert_eq!(styled.to_string(), "\x1b[31mError\x1b[0m");
+///
+/// let owned = Color::RED.applied_to(String::from("Error"));
+/// let borrowed: StyledRef<String> = owned.by_ref();
+/// assert_eq!(borrowed.to_string(), owned.to_string());
+/// ```
+pub type StyledRef<'a, C = dyn Display + 'a> = Styled<&'a C>;
+
 impl<C: Display> Styled<C> {
     /// Creates a new `Styled<C>` value with the given content and empty style.
     #[must_use]
@@ -24,6 +85,11 @@ impl<C: Display> Styled<C> {
         &self.content
     }
 
+    #[cfg(feature = "alloc")]
+    pub(crate) fn get_content_mut(&mut self) -> &mut C {
+        &mut self.content
+    }
+
     /// Returns a new `Styled<C2>` value with the same style and the given content.
     #[must_use]
     pub const fn with_content<C2: Display>(&self, content: C2) -> Styled<C2> {
@@ -33,12 +99,34 @@ impl<C: Display> Styled<C> {
         }
     }
 
+    /// Returns a new `Styled<C2>` value with the same style and the content transformed by the given function.
+    ///
+    /// ```
+    /// use fluent_ansi::prelude::*;
+    ///
+    /// let styled = Color::RED.applied_to("error").map_content(str::to_uppercase);
+    /// assert_eq!(styled.to_string(), "\x1b[31mERROR\x1b[0m");
+    /// ```
+    #[must_use]
+    pub fn map_content<C2: Display>(self, f: impl FnOnce(C) -> C2) -> Styled<C2> {
+        Styled {
+            content: f(self.content),
+            style: self.style,
+        }
+    }
+
     /// Consumes the `Styled<C>` value and returns the enclosed content.
     #[must_use]
     pub fn into_content(self) -> C {
         self.content
     }
 
+    /// Returns a new [`StyledRef<C>`] value with the same style, borrowing the content.
+    #[must_use]
+    pub const fn by_ref(&self) -> StyledRef<'_, C> {
+        self.with_content(&self.content)
+    }
+
     /// Gets the current style.
     #[must_use]
     pub const fn get_style(&self) -> Style {
@@ -50,6 +138,22 @@ impl<C: Display> Styled<C> {
     pub fn with_style(self, style: Style) -> Styled<C> {
         Self { style, ..self }
     }
+
+    /// Returns a new `Styled<C>` value with the same content and the style transformed by the given function.
+    ///
+    /// ```
+    /// use fluent_ansi::prelude::*;
+    ///
+    /// let styled = Color::RED.applied_to("error").map_style(|style| style.bg(Color::WHITE));
+    /// assert_eq!(styled.to_string(), "\x1b[31;47merror\x1b[0m");
+    /// ```
+    #[must_use]
+    pub fn map_style(self, f: impl FnOnce(Style) -> Style) -> Styled<C> {
+        Self {
+            style: f(self.style),
+            ..self
+        }
+    }
 }
 impl<C: Display> ToStyleSet for Styled<C> {
     type StyleSet = Self;
@@ -79,20 +183,62 @@ impl<C: Display> StyleSet for Styled<C> {
 }
 impl<C: Display> Display for Styled<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        if self.style == Style::default() {
-            write!(f, "{}", self.content)
-        } else {
-            let start = self.style;
-            let end = Style::default();
-            write!(f, "{start}{}{end}", self.content)
+        self.render(RenderOptions::new()).fmt(f)
+    }
+}
+
+impl<C: Display> AsRef<C> for Styled<C> {
+    fn as_ref(&self) -> &C {
+        &self.content
+    }
+}
+
+impl<C: Display + Deref<Target = str>> Deref for Styled<C> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.content
+    }
+}
+
+impl<C: Display> Styled<C> {
+    fn fmt_with(
+        &self,
+        f: &mut Formatter<'_>,
+        fmt_content: impl FnOnce(&C, &mut Formatter<'_>) -> Result,
+    ) -> Result {
+        if self.style == Style::new() {
+            return fmt_content(&self.content, f);
         }
+        write!(f, "{}", self.style)?;
+        fmt_content(&self.content, f)?;
+        write!(f, "{Reset}")
     }
 }
 
+macro_rules! impl_fmt_traits {
+    ($($trait:ident),* $(,)?) => {
+        $(
+            impl<C: Display + $trait> $trait for Styled<C> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                    self.fmt_with(f, $trait::fmt)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt_traits!(
+    LowerHex, UpperHex, Binary, Octal, LowerExp, UpperExp, Pointer
+);
+
 #[cfg(test)]
 mod tests {
+    use std::string::String;
+
     use crate::{
-        assert_display, color::BasicColor, test_style_set_methods, test_to_style_set_methods,
+        AppliedTo as _, Effect, assert_display, color::BasicColor, test_style_set_methods,
+        test_to_style_set_methods,
     };
 
     use super::*;
@@ -100,6 +246,46 @@ mod tests {
     test_to_style_set_methods!(Styled::new("CONTENT"), Styled::new("CONTENT"));
     test_style_set_methods!(Styled::new("CONTENT"));
 
+    #[test]
+    fn padding() {
+        let stld = Styled::new("abc").bold();
+
+        assert_eq!(format!("{stld:6}"), "\x1b[1mabc\x1b[0m   ");
+        assert_eq!(format!("{stld:<6}"), "\x1b[1mabc\x1b[0m   ");
+        assert_eq!(format!("{stld:>6}"), "   \x1b[1mabc\x1b[0m");
+        assert_eq!(format!("{stld:*^6}"), "*\x1b[1mabc\x1b[0m**");
+        assert_eq!(format!("{stld:2}"), "\x1b[1mabc\x1b[0m");
+        assert_eq!(format!("{:>5}", Styled::new("abc")), "  abc");
+        assert_eq!(
+            format!("{:>5}", Styled::new(Styled::new("abc").italic()).bold()),
+            "  \x1b[1m\x1b[3mabc\x1b[0m\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn numeric_formatting() {
+        let stld = Styled::new(255).bold();
+
+        assert_eq!(format!("{stld:x}"), "\x1b[1mff\x1b[0m");
+        assert_eq!(format!("{stld:X}"), "\x1b[1mFF\x1b[0m");
+        assert_eq!(format!("{stld:b}"), "\x1b[1m11111111\x1b[0m");
+        assert_eq!(format!("{stld:o}"), "\x1b[1m377\x1b[0m");
+        assert_eq!(format!("{stld:#06x}"), "\x1b[1m0x00ff\x1b[0m");
+        assert_eq!(format!("{:x}", Styled::new(255)), "ff");
+
+        let stld = Styled::new(1234.5).italic();
+        assert_eq!(format!("{stld:e}"), "\x1b[3m1.2345e3\x1b[0m");
+        assert_eq!(format!("{stld:E}"), "\x1b[3m1.2345E3\x1b[0m");
+    }
+
+    #[test]
+    fn pointer_formatting() {
+        let value = 42;
+        let stld = Styled::new(&value).underline();
+
+        assert_eq!(format!("{stld:p}"), format!("\x1b[4m{:p}\x1b[0m", &value));
+    }
+
     #[test]
     fn content_and_style() {
         let stld = Styled::new("CONTENT").bold();
@@ -118,6 +304,57 @@ mod tests {
         assert_eq!(content, "NEW CONTENT");
     }
 
+    #[test]
+    fn content_access() {
+        let stld = Styled::new(42).bold();
+        let content: &i32 = stld.as_ref();
+        assert_eq!(content, &42);
+
+        let stld = Styled::new("abc").bold();
+        assert_eq!(stld.as_ref(), &"abc");
+        assert_eq!(stld.len(), 3);
+        assert_eq!(&*stld, "abc");
+
+        let stld = Styled::new(String::from("abc")).bold();
+        let takes_str = |s: &str| s.to_uppercase();
+        assert_eq!(takes_str(&stld), "ABC");
+        assert!(stld.eq_ignore_ascii_case("ABC"));
+    }
+
+    #[test]
+    fn map_content_and_style() {
+        let stld = Styled::new(21).bold().map_content(|n| n * 2);
+        assert_eq!(stld.get_content(), &42);
+        assert_eq!(stld.get_style(), Style::new().bold());
+
+        let stld = stld.map_content(|n| std::format!("n{n}"));
+        assert_eq!(stld.get_content(), "n42");
+
+        let stld = stld.map_style(|style| style.italic().set_effect(Effect::Bold, false));
+        assert_eq!(stld.get_content(), "n42");
+        assert_eq!(stld.get_style(), Style::new().italic());
+    }
+
+    #[test]
+    fn borrowed_content() {
+        let error = core::fmt::Error;
+        let content: &dyn Display = &error;
+        let stld: StyledRef = Style::new().bold().display(content);
+        assert_eq!(
+            stld.to_string(),
+            "\x1b[1man error occurred when formatting an argument\x1b[0m"
+        );
+        assert_eq!(
+            stld.bold().italic().get_style(),
+            Style::new().bold().italic()
+        );
+
+        let stld = Styled::new(String::from("CONTENT")).underline();
+        let borrowed: StyledRef<String> = stld.by_ref();
+        assert_eq!(*borrowed.get_content(), stld.get_content());
+        assert_eq!(borrowed.to_string(), stld.to_string());
+    }
+
     #[test]
     fn effects_display() {
         let stld = Styled::new("CONTENT");
@@ -127,9 +364,18 @@ mod tests {
         assert_display!(stld.faint(), "\x1b[2mCONTENT\x1b[0m");
         assert_display!(stld.italic(), "\x1b[3mCONTENT\x1b[0m");
         assert_display!(stld.underline(), "\x1b[4mCONTENT\x1b[0m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(stld.curly_underline(), "\x1b[4:3mCONTENT\x1b[0m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(stld.curly_underline(), "\x1b[4mCONTENT\x1b[0m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(stld.dotted_underline(), "\x1b[4:4mCONTENT\x1b[0m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(stld.dotted_underline(), "\x1b[4mCONTENT\x1b[0m");
+        #[cfg(feature = "extended-underline")]
         assert_display!(stld.dashed_underline(), "\x1b[4:5mCONTENT\x1b[0m");
+        #[cfg(not(feature = "extended-underline"))]
+        assert_display!(stld.dashed_underline(), "\x1b[4mCONTENT\x1b[0m");
         assert_display!(stld.blink(), "\x1b[5mCONTENT\x1b[0m");
         assert_display!(stld.reverse(), "\x1b[7mCONTENT\x1b[0m");
         assert_display!(stld.conceal(), "\x1b[8mCONTENT\x1b[0m");
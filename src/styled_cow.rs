@@ -0,0 +1,137 @@
+use alloc::{borrow::Cow, string::String};
+
+use crate::Styled;
+
+/// A [`Styled<C>`] value whose content is either a borrowed or an owned string.
+///
+/// APIs can accept `impl Into<StyledCow>` to take either kind of content, styled or not, uniformly:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, StyledCow};
+///
+/// fn label<'a>(text: impl Into<StyledCow<'a>>) -> String {
+///     format!("[{}]", text.into())
+/// }
+///
+/// assert_eq!(label("plain"), "[plain]");
+/// assert_eq!(label(Color::RED.applied_to("borrowed")), "[\x1b[31mborrowed\x1b[0m]");
+/// assert_eq!(label(Color::RED.applied_to(String::from("owned"))), "[\x1b[31mowned\x1b[0m]");
+/// ```
+///
+/// Requires the `alloc` feature.
+pub type StyledCow<'a> = Styled<Cow<'a, str>>;
+
+impl<'a> StyledCow<'a> {
+    /// Creates a new `StyledCow` value with the given borrowed content and empty style.
+    #[must_use]
+    pub const fn borrowed(content: &'a str) -> Self {
+        Styled::new(Cow::Borrowed(content))
+    }
+
+    /// Creates a new `StyledCow` value with the given owned content and empty style.
+    #[must_use]
+    pub const fn owned(content: String) -> Self {
+        Styled::new(Cow::Owned(content))
+    }
+
+    /// Returns whether the content is borrowed.
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self.get_content(), Cow::Borrowed(_))
+    }
+
+    /// Gets the content as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.get_content()
+    }
+
+    /// Returns a new `StyledCow` value with the same style and an owned copy of the content.
+    #[must_use]
+    pub fn to_owned(&self) -> StyledCow<'static> {
+        self.with_content(Cow::Owned(self.as_str().into()))
+    }
+
+    /// Consumes the `StyledCow` value and returns one with the same style and owned content, copying the content
+    /// only if it is borrowed.
+    #[must_use]
+    pub fn into_owned(self) -> StyledCow<'static> {
+        self.map_content(|content| Cow::Owned(content.into_owned()))
+    }
+}
+
+impl<'a> From<&'a str> for StyledCow<'a> {
+    fn from(content: &'a str) -> Self {
+        Self::borrowed(content)
+    }
+}
+
+impl From<String> for StyledCow<'_> {
+    fn from(content: String) -> Self {
+        Self::owned(content)
+    }
+}
+
+impl<'a> From<Styled<&'a str>> for StyledCow<'a> {
+    fn from(styled: Styled<&'a str>) -> Self {
+        styled.map_content(Cow::Borrowed)
+    }
+}
+
+impl From<Styled<String>> for StyledCow<'_> {
+    fn from(styled: Styled<String>) -> Self {
+        styled.map_content(Cow::Owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString as _;
+
+    use crate::{Style, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn constructors() {
+        let stld = StyledCow::borrowed("abc");
+        assert!(stld.is_borrowed());
+        assert_eq!(stld.as_str(), "abc");
+        assert_eq!(stld.get_style(), Style::new());
+
+        let stld = StyledCow::owned(String::from("abc")).bold();
+        assert!(!stld.is_borrowed());
+        assert_eq!(stld.to_string(), "\x1b[1mabc\x1b[0m");
+    }
+
+    #[test]
+    fn conversions() {
+        let stld = StyledCow::from("abc");
+        assert!(stld.is_borrowed());
+        assert!(!StyledCow::from(String::from("abc")).is_borrowed());
+
+        let stld = StyledCow::from(Styled::new("abc").fg(Color::RED));
+        assert!(stld.is_borrowed());
+        assert_eq!(stld.to_string(), "\x1b[31mabc\x1b[0m");
+
+        let stld = StyledCow::from(Styled::new(String::from("abc")).fg(Color::RED));
+        assert!(!stld.is_borrowed());
+        assert_eq!(stld.to_string(), "\x1b[31mabc\x1b[0m");
+    }
+
+    #[test]
+    fn owned() {
+        let content = String::from("abc");
+        let stld = StyledCow::borrowed(&content).italic();
+
+        let owned = stld.to_owned();
+        assert!(!owned.is_borrowed());
+        assert_eq!(owned, stld);
+
+        let owned = stld.clone().into_owned();
+        drop(content);
+        assert!(!owned.is_borrowed());
+        assert_eq!(owned.as_str(), "abc");
+        assert_eq!(owned.get_style(), Style::new().italic());
+    }
+}
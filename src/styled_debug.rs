@@ -0,0 +1,160 @@
+use core::fmt::{Debug, Formatter, Result};
+
+use crate::{GetEffects, Reset, Style, StyleAttribute, StyleElement, StyleSet, ToStyleSet};
+
+/// A value that associates some content with a specific style, and renders the content with its [`Debug`]
+/// implementation.
+///
+/// It is the counterpart of [`Styled<C>`](crate::Styled) for values that do not implement
+/// [`Display`](core::fmt::Display), like most errors and structs. The styled content is rendered with `{:?}`, and the
+/// formatting options, like the alternate flag for pretty-printing, are passed to the content:
+///
+/// ```
+/// use fluent_ansi::prelude::*;
+///
+/// #[derive(Debug)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let styled = Color::RED.applied_to_debug(Point { x: 1, y: 2 });
+/// assert_eq!(format!("{styled:?}"), "\x1b[31mPoint { x: 1, y: 2 }\x1b[0m");
+/// assert_eq!(
+///     format!("{styled:#?}"),
+///     "\x1b[31mPoint {\n    x: 1,\n    y: 2,\n}\x1b[0m"
+/// );
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StyledDebug<C: Debug> {
+    content: C,
+    style: Style,
+}
+impl<C: Debug> StyledDebug<C> {
+    /// Creates a new `StyledDebug<C>` value with the given content and empty style.
+    #[must_use]
+    pub const fn new(content: C) -> Self {
+        Self {
+            content,
+            style: Style::new(),
+        }
+    }
+
+    /// Gets a reference to the enclosed content.
+    #[must_use]
+    pub const fn get_content(&self) -> &C {
+        &self.content
+    }
+
+    /// Consumes the `StyledDebug<C>` value and returns the enclosed content.
+    #[must_use]
+    pub fn into_content(self) -> C {
+        self.content
+    }
+
+    /// Gets the current style.
+    #[must_use]
+    pub const fn get_style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns a new `StyledDebug<C>` value with the same content and the given style.
+    #[must_use]
+    pub fn with_style(self, style: Style) -> StyledDebug<C> {
+        Self { style, ..self }
+    }
+}
+impl<C: Debug> ToStyleSet for StyledDebug<C> {
+    type StyleSet = Self;
+
+    fn add(self, element: impl StyleElement) -> Self::StyleSet {
+        let style = self.style.add(element);
+        self.with_style(style)
+    }
+
+    fn to_style_set(self) -> Self::StyleSet {
+        self
+    }
+}
+impl<C: Debug> StyleSet for StyledDebug<C> {
+    fn get_effects(&self) -> GetEffects {
+        self.style.get_effects()
+    }
+
+    fn set<A: StyleAttribute>(self, attr: A, value: A::Value) -> Self {
+        let style = self.style.set(attr, value);
+        self.with_style(style)
+    }
+
+    fn get<A: StyleAttribute>(&self, attr: A) -> A::Value {
+        self.style.get(attr)
+    }
+}
+impl<C: Debug> Debug for StyledDebug<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.style == Style::new() {
+            return self.content.fmt(f);
+        }
+        write!(f, "{}", self.style)?;
+        self.content.fmt(f)?;
+        write!(f, "{Reset}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AppliedTo as _, color::BasicColor, test_style_set_methods, test_to_style_set_methods,
+    };
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    test_to_style_set_methods!(StyledDebug::new([1, 2]), StyledDebug::new([1, 2]));
+    test_style_set_methods!(StyledDebug::new([1, 2]));
+
+    #[test]
+    fn content_and_style() {
+        let stld = StyledDebug::new(Point { x: 1, y: 2 }).bold();
+        assert_eq!(stld.get_content(), &Point { x: 1, y: 2 });
+        assert_eq!(stld.get_style(), Style::new().bold());
+
+        let stld = stld.with_style(Style::new().fg(BasicColor::Red));
+        assert_eq!(stld.get_style(), Style::new().fg(BasicColor::Red));
+        assert_eq!(stld.into_content(), Point { x: 1, y: 2 });
+    }
+
+    #[test]
+    fn debug() {
+        let point = Point { x: 1, y: 2 };
+
+        assert_eq!(
+            format!("{:?}", StyledDebug::new(point)),
+            "Point { x: 1, y: 2 }"
+        );
+        assert_eq!(
+            format!("{:?}", StyledDebug::new(point).bold().fg(BasicColor::Red)),
+            "\x1b[1;31mPoint { x: 1, y: 2 }\x1b[0m"
+        );
+        assert_eq!(
+            format!("{:?}", StyledDebug::new("quoted").italic()),
+            "\x1b[3m\"quoted\"\x1b[0m"
+        );
+        assert_eq!(
+            format!("{:#?}", StyledDebug::new(point).underline()),
+            "\x1b[4mPoint {\n    x: 1,\n    y: 2,\n}\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn applied_to_debug() {
+        let stld = BasicColor::Green.applied_to_debug([1, 2]);
+        assert_eq!(stld.get_style(), Style::new().fg(BasicColor::Green));
+        assert_eq!(format!("{stld:?}"), "\x1b[32m[1, 2]\x1b[0m");
+    }
+}
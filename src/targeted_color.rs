@@ -73,6 +73,12 @@ impl ToStyle for TargetedColor {
 
 impl AppliedTo for TargetedColor {}
 
+impl PartialEq<Style> for TargetedColor {
+    fn eq(&self, other: &Style) -> bool {
+        self.to_style() == *other
+    }
+}
+
 impl Display for TargetedColor {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         self.to_style().fmt(f)
@@ -96,6 +102,15 @@ pub enum ColorTarget {
     Underline,
 }
 
+impl ColorTarget {
+    /// All the color targets, in the order they are rendered.
+    pub const ALL: [ColorTarget; 3] = [
+        ColorTarget::Foreground,
+        ColorTarget::Background,
+        ColorTarget::Underline,
+    ];
+}
+
 impl StyleAttribute for ColorTarget {
     type Value = Option<Color>;
 
@@ -152,6 +167,17 @@ mod tests {
     test_to_style_set_methods!(red_underline; TargetedColor::new_for_underline(BasicColor::Red), Style::new().underline_color(BasicColor::Red));
     test_to_style_set_methods!(green_underline; TargetedColor::new_for_underline(BasicColor::Green), Style::new().underline_color(BasicColor::Green));
 
+    #[test]
+    fn eq_style() {
+        assert_eq!(BasicColor::Red.for_fg(), Style::new().fg(BasicColor::Red));
+        assert_ne!(BasicColor::Red.for_fg(), Style::new().bg(BasicColor::Red));
+        assert_eq!(Style::new().bg(BasicColor::Red), BasicColor::Red.for_bg());
+        assert_ne!(
+            Style::new().bold().bg(BasicColor::Red),
+            BasicColor::Red.for_bg()
+        );
+    }
+
     #[test]
     fn applied_to() {
         let stld = BasicColor::Red.for_fg().applied_to("CONTENT");
@@ -192,14 +218,21 @@ mod tests {
         assert_display!(BasicColor::Cyan.for_bg(), "\x1b[46m");
         assert_display!(BasicColor::White.for_bg(), "\x1b[47m");
 
-        assert_display!(BasicColor::Black.for_underline(), "\x1b[58;5;0m");
-        assert_display!(BasicColor::Red.for_underline(), "\x1b[58;5;1m");
-        assert_display!(BasicColor::Green.for_underline(), "\x1b[58;5;2m");
-        assert_display!(BasicColor::Yellow.for_underline(), "\x1b[58;5;3m");
-        assert_display!(BasicColor::Blue.for_underline(), "\x1b[58;5;4m");
-        assert_display!(BasicColor::Magenta.for_underline(), "\x1b[58;5;5m");
-        assert_display!(BasicColor::Cyan.for_underline(), "\x1b[58;5;6m");
-        assert_display!(BasicColor::White.for_underline(), "\x1b[58;5;7m");
+        #[cfg(feature = "underline-color")]
+        {
+            assert_display!(BasicColor::Black.for_underline(), "\x1b[58;5;0m");
+            assert_display!(BasicColor::Red.for_underline(), "\x1b[58;5;1m");
+            assert_display!(BasicColor::Green.for_underline(), "\x1b[58;5;2m");
+            assert_display!(BasicColor::Yellow.for_underline(), "\x1b[58;5;3m");
+            assert_display!(BasicColor::Blue.for_underline(), "\x1b[58;5;4m");
+            assert_display!(BasicColor::Magenta.for_underline(), "\x1b[58;5;5m");
+            assert_display!(BasicColor::Cyan.for_underline(), "\x1b[58;5;6m");
+            assert_display!(BasicColor::White.for_underline(), "\x1b[58;5;7m");
+        }
+        #[cfg(not(feature = "underline-color"))]
+        {
+            assert_display!(BasicColor::Red.for_underline(), "");
+        }
     }
 
     #[test]
@@ -212,18 +245,25 @@ mod tests {
         assert_display!(SimpleColor::new(BasicColor::Red).for_bg(), "\x1b[41m");
         assert_display!(SimpleColor::new(BasicColor::White).for_bg(), "\x1b[47m");
 
-        assert_display!(
-            SimpleColor::new(BasicColor::Black).for_underline(),
-            "\x1b[58;5;0m"
-        );
-        assert_display!(
-            SimpleColor::new(BasicColor::Red).for_underline(),
-            "\x1b[58;5;1m"
-        );
-        assert_display!(
-            SimpleColor::new(BasicColor::White).for_underline(),
-            "\x1b[58;5;7m"
-        );
+        #[cfg(feature = "underline-color")]
+        {
+            assert_display!(
+                SimpleColor::new(BasicColor::Black).for_underline(),
+                "\x1b[58;5;0m"
+            );
+            assert_display!(
+                SimpleColor::new(BasicColor::Red).for_underline(),
+                "\x1b[58;5;1m"
+            );
+            assert_display!(
+                SimpleColor::new(BasicColor::White).for_underline(),
+                "\x1b[58;5;7m"
+            );
+        }
+        #[cfg(not(feature = "underline-color"))]
+        {
+            assert_display!(SimpleColor::new(BasicColor::Red).for_underline(), "");
+        }
 
         assert_display!(
             SimpleColor::new_bright(BasicColor::Black).for_fg(),
@@ -251,18 +291,25 @@ mod tests {
             "\x1b[107m"
         );
 
-        assert_display!(
-            SimpleColor::new_bright(BasicColor::Black).for_underline(),
-            "\x1b[58;5;8m"
-        );
-        assert_display!(
-            SimpleColor::new_bright(BasicColor::Red).for_underline(),
-            "\x1b[58;5;9m"
-        );
-        assert_display!(
-            SimpleColor::new_bright(BasicColor::White).for_underline(),
-            "\x1b[58;5;15m"
-        );
+        #[cfg(feature = "underline-color")]
+        {
+            assert_display!(
+                SimpleColor::new_bright(BasicColor::Black).for_underline(),
+                "\x1b[58;5;8m"
+            );
+            assert_display!(
+                SimpleColor::new_bright(BasicColor::Red).for_underline(),
+                "\x1b[58;5;9m"
+            );
+            assert_display!(
+                SimpleColor::new_bright(BasicColor::White).for_underline(),
+                "\x1b[58;5;15m"
+            );
+        }
+        #[cfg(not(feature = "underline-color"))]
+        {
+            assert_display!(SimpleColor::new_bright(BasicColor::Red).for_underline(), "");
+        }
     }
 
     #[test]
@@ -275,32 +322,55 @@ mod tests {
         assert_display!(IndexedColor(7).for_bg(), "\x1b[48;5;7m");
         assert_display!(IndexedColor(255).for_bg(), "\x1b[48;5;255m");
 
-        assert_display!(IndexedColor(0).for_underline(), "\x1b[58;5;0m");
-        assert_display!(IndexedColor(7).for_underline(), "\x1b[58;5;7m");
-        assert_display!(IndexedColor(255).for_underline(), "\x1b[58;5;255m");
+        #[cfg(feature = "underline-color")]
+        {
+            assert_display!(IndexedColor(0).for_underline(), "\x1b[58;5;0m");
+            assert_display!(IndexedColor(7).for_underline(), "\x1b[58;5;7m");
+            assert_display!(IndexedColor(255).for_underline(), "\x1b[58;5;255m");
+        }
+        #[cfg(not(feature = "underline-color"))]
+        {
+            assert_display!(IndexedColor(7).for_underline(), "");
+        }
     }
 
     #[test]
     fn rgb_color_display() {
-        assert_display!(RGBColor::new(0, 128, 255).for_fg(), "\x1b[38;2;0;128;255m");
-        assert_display!(RGBColor::new(128, 255, 0).for_fg(), "\x1b[38;2;128;255;0m");
-        assert_display!(RGBColor::new(255, 0, 128).for_fg(), "\x1b[38;2;255;0;128m");
-
-        assert_display!(RGBColor::new(0, 128, 255).for_bg(), "\x1b[48;2;0;128;255m");
-        assert_display!(RGBColor::new(128, 255, 0).for_bg(), "\x1b[48;2;128;255;0m");
-        assert_display!(RGBColor::new(255, 0, 128).for_bg(), "\x1b[48;2;255;0;128m");
+        #[cfg(feature = "truecolor")]
+        {
+            assert_display!(RGBColor::new(0, 128, 255).for_fg(), "\x1b[38;2;0;128;255m");
+            assert_display!(RGBColor::new(128, 255, 0).for_fg(), "\x1b[38;2;128;255;0m");
+            assert_display!(RGBColor::new(255, 0, 128).for_fg(), "\x1b[38;2;255;0;128m");
+
+            assert_display!(RGBColor::new(0, 128, 255).for_bg(), "\x1b[48;2;0;128;255m");
+            assert_display!(RGBColor::new(128, 255, 0).for_bg(), "\x1b[48;2;128;255;0m");
+            assert_display!(RGBColor::new(255, 0, 128).for_bg(), "\x1b[48;2;255;0;128m");
+        }
+        #[cfg(not(feature = "truecolor"))]
+        {
+            assert_display!(RGBColor::new(0, 128, 255).for_fg(), "\x1b[36m");
+            assert_display!(RGBColor::new(128, 255, 0).for_fg(), "\x1b[33m");
+            assert_display!(RGBColor::new(255, 0, 128).for_bg(), "\x1b[45m");
+        }
 
-        assert_display!(
-            RGBColor::new(0, 128, 255).for_underline(),
-            "\x1b[58;2;0;128;255m"
-        );
-        assert_display!(
-            RGBColor::new(128, 255, 0).for_underline(),
-            "\x1b[58;2;128;255;0m"
-        );
-        assert_display!(
-            RGBColor::new(255, 0, 128).for_underline(),
-            "\x1b[58;2;255;0;128m"
-        );
+        #[cfg(all(feature = "truecolor", feature = "underline-color"))]
+        {
+            assert_display!(
+                RGBColor::new(0, 128, 255).for_underline(),
+                "\x1b[58;2;0;128;255m"
+            );
+            assert_display!(
+                RGBColor::new(128, 255, 0).for_underline(),
+                "\x1b[58;2;128;255;0m"
+            );
+            assert_display!(
+                RGBColor::new(255, 0, 128).for_underline(),
+                "\x1b[58;2;255;0;128m"
+            );
+        }
+        #[cfg(all(not(feature = "truecolor"), feature = "underline-color"))]
+        assert_display!(RGBColor::new(0, 128, 255).for_underline(), "\x1b[58;5;6m");
+        #[cfg(not(feature = "underline-color"))]
+        assert_display!(RGBColor::new(0, 128, 255).for_underline(), "");
     }
 }
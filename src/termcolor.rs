@@ -0,0 +1,260 @@
+//! Conversions from and to [`termcolor`] types, enabled by the `termcolor` feature.
+//!
+//! `termcolor` represents bright colors with a single "intense" flag that applies to both the foreground and the
+//! background colors. When only one of them is bright, the bright color is converted to its equivalent
+//! [`Ansi256`](termcolor::Color::Ansi256) color instead.
+//!
+//! The conversions are lossy in some cases:
+//! - [`Color::Default`] and underline colors are dropped;
+//! - the underline styles are all converted to a solid underline;
+//! - the effects not supported by `termcolor` are dropped.
+
+use termcolor::ColorSpec;
+
+use crate::{
+    ColorTarget, Effect, Style, StyleSet as _,
+    color::{BasicColor, Color, IndexedColor, RGBColor, SimpleColor},
+};
+
+impl From<Style> for ColorSpec {
+    fn from(style: Style) -> Self {
+        let brightness = |color: Option<Color>| match color {
+            Some(Color::Simple(simple)) => Some(simple.is_bright()),
+            _ => None,
+        };
+        let intense = match (brightness(style.fg), brightness(style.bg)) {
+            (Some(fg), Some(bg)) => fg && bg,
+            (Some(bright), None) | (None, Some(bright)) => bright,
+            (None, None) => false,
+        };
+        let convert = |color: Option<Color>| match color {
+            Some(Color::Simple(simple)) if simple.is_bright() == intense => {
+                Some(simple.get_basic_color().into())
+            }
+            Some(color) => color.into(),
+            None => None,
+        };
+
+        let mut spec = ColorSpec::new();
+        spec.set_fg(convert(style.fg))
+            .set_bg(convert(style.bg))
+            .set_intense(intense)
+            .set_bold(style.get_effect(Effect::Bold))
+            .set_dimmed(style.get_effect(Effect::Faint))
+            .set_italic(style.get_effect(Effect::Italic))
+            .set_underline(style.get_underline_style().is_some())
+            .set_strikethrough(style.get_effect(Effect::Strikethrough));
+        spec
+    }
+}
+
+impl From<&ColorSpec> for Style {
+    fn from(spec: &ColorSpec) -> Self {
+        let convert = |color: Option<&termcolor::Color>| {
+            color.map(|&color| match Color::from(color) {
+                Color::Simple(simple) if spec.intense() => Color::Simple(simple.bright()),
+                color => color,
+            })
+        };
+
+        Style::new()
+            .set_color(ColorTarget::Foreground, convert(spec.fg()))
+            .set_color(ColorTarget::Background, convert(spec.bg()))
+            .set_effect(Effect::Bold, spec.bold())
+            .set_effect(Effect::Faint, spec.dimmed())
+            .set_effect(Effect::Italic, spec.italic())
+            .set_effect(Effect::Underline, spec.underline())
+            .set_effect(Effect::Strikethrough, spec.strikethrough())
+    }
+}
+
+impl From<ColorSpec> for Style {
+    fn from(spec: ColorSpec) -> Self {
+        Style::from(&spec)
+    }
+}
+
+/// Converts to a `termcolor` color, where [`Color::Default`] is converted to `None`.
+///
+/// Bright simple colors are converted to their equivalent [`Ansi256`](termcolor::Color::Ansi256) colors.
+impl From<Color> for Option<termcolor::Color> {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Simple(simple) => Some(simple.into()),
+            Color::Indexed(indexed) => Some(indexed.into()),
+            Color::RGB(rgb) => Some(rgb.into()),
+            Color::Default => None,
+        }
+    }
+}
+
+/// Converts a `termcolor` color. The basic colors are converted to non-bright simple colors.
+impl From<termcolor::Color> for Color {
+    fn from(color: termcolor::Color) -> Self {
+        let basic_color = match color {
+            termcolor::Color::Black => BasicColor::Black,
+            termcolor::Color::Red => BasicColor::Red,
+            termcolor::Color::Green => BasicColor::Green,
+            termcolor::Color::Yellow => BasicColor::Yellow,
+            termcolor::Color::Blue => BasicColor::Blue,
+            termcolor::Color::Magenta => BasicColor::Magenta,
+            termcolor::Color::Cyan => BasicColor::Cyan,
+            termcolor::Color::White => BasicColor::White,
+            termcolor::Color::Ansi256(index) => return Color::Indexed(IndexedColor::new(index)),
+            termcolor::Color::Rgb(r, g, b) => return Color::RGB(RGBColor::new(r, g, b)),
+            // Hidden variant, not meant to be used.
+            _ => return Color::Default,
+        };
+        Color::Simple(basic_color.to_simple_color())
+    }
+}
+
+impl From<BasicColor> for termcolor::Color {
+    fn from(color: BasicColor) -> Self {
+        match color {
+            BasicColor::Black => termcolor::Color::Black,
+            BasicColor::Red => termcolor::Color::Red,
+            BasicColor::Green => termcolor::Color::Green,
+            BasicColor::Yellow => termcolor::Color::Yellow,
+            BasicColor::Blue => termcolor::Color::Blue,
+            BasicColor::Magenta => termcolor::Color::Magenta,
+            BasicColor::Cyan => termcolor::Color::Cyan,
+            BasicColor::White => termcolor::Color::White,
+        }
+    }
+}
+
+/// Converts a simple color. Bright colors are converted to their equivalent [`Ansi256`](termcolor::Color::Ansi256)
+/// colors.
+impl From<SimpleColor> for termcolor::Color {
+    fn from(color: SimpleColor) -> Self {
+        if color.is_bright() {
+            termcolor::Color::Ansi256(color.index())
+        } else {
+            color.get_basic_color().into()
+        }
+    }
+}
+
+impl From<IndexedColor> for termcolor::Color {
+    fn from(color: IndexedColor) -> Self {
+        termcolor::Color::Ansi256(color.get_index())
+    }
+}
+
+impl From<RGBColor> for termcolor::Color {
+    fn from(color: RGBColor) -> Self {
+        termcolor::Color::Rgb(color.r, color.g, color.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ToStyleSet as _, color::ColorKind as _};
+
+    use super::*;
+
+    #[test]
+    fn style_to_color_spec() {
+        let style = Style::new()
+            .bold()
+            .faint()
+            .italic()
+            .curly_underline()
+            .strikethrough()
+            .blink()
+            .fg(BasicColor::Red)
+            .bg(RGBColor::new(1, 2, 3))
+            .underline_color(BasicColor::Blue);
+        let mut expected = ColorSpec::new();
+        expected
+            .set_fg(Some(termcolor::Color::Red))
+            .set_bg(Some(termcolor::Color::Rgb(1, 2, 3)))
+            .set_bold(true)
+            .set_dimmed(true)
+            .set_italic(true)
+            .set_underline(true)
+            .set_strikethrough(true);
+
+        assert_eq!(ColorSpec::from(style), expected);
+    }
+
+    #[test]
+    fn intense() {
+        let mut expected = ColorSpec::new();
+        expected
+            .set_fg(Some(termcolor::Color::Red))
+            .set_intense(true);
+        assert_eq!(
+            ColorSpec::from(Style::new().fg(BasicColor::Red.bright())),
+            expected
+        );
+
+        expected.set_bg(Some(termcolor::Color::Blue));
+        assert_eq!(
+            ColorSpec::from(
+                Style::new()
+                    .fg(BasicColor::Red.bright())
+                    .bg(BasicColor::Blue.bright())
+            ),
+            expected
+        );
+
+        let mut expected = ColorSpec::new();
+        expected
+            .set_fg(Some(termcolor::Color::Ansi256(9)))
+            .set_bg(Some(termcolor::Color::Blue));
+        assert_eq!(
+            ColorSpec::from(
+                Style::new()
+                    .fg(BasicColor::Red.bright())
+                    .bg(BasicColor::Blue)
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn color_spec_to_style() {
+        let mut spec = ColorSpec::new();
+        spec.set_fg(Some(termcolor::Color::Green))
+            .set_bg(Some(termcolor::Color::Ansi256(42)))
+            .set_bold(true)
+            .set_underline(true);
+        assert_eq!(
+            Style::from(&spec),
+            Style::new()
+                .bold()
+                .underline()
+                .fg(BasicColor::Green)
+                .bg(IndexedColor::new(42))
+        );
+
+        spec.set_intense(true);
+        assert_eq!(
+            Style::from(spec),
+            Style::new()
+                .bold()
+                .underline()
+                .fg(BasicColor::Green.bright())
+                .bg(IndexedColor::new(42))
+        );
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(Option::<termcolor::Color>::from(Color::Default), None);
+        assert_eq!(
+            termcolor::Color::from(BasicColor::White.bright()),
+            termcolor::Color::Ansi256(15)
+        );
+        assert_eq!(
+            Color::from(termcolor::Color::Yellow),
+            BasicColor::Yellow.to_color()
+        );
+        assert_eq!(
+            Color::from(termcolor::Color::from(RGBColor::new(1, 2, 3))),
+            Color::rgb(1, 2, 3)
+        );
+    }
+}
@@ -0,0 +1,409 @@
+//! Helpers for testing styled output.
+//!
+//! A [`VirtualTerminal`] interprets rendered output, like a terminal does, and exposes the resulting grid of styled
+//! [`Cell`]s. This allows tests to assert on what the user sees, instead of on the exact escape sequences, which may
+//! change without changing the appearance:
+//!
+//! ```
+//! use fluent_ansi::{prelude::*, Style, testing::VirtualTerminal};
+//!
+//! let mut terminal = VirtualTerminal::new(80);
+//! terminal.feed(&format!("{}: {}", Color::RED.bold().applied_to("Error"), "disk full"));
+//!
+//! assert_eq!(terminal.text(), "Error: disk full");
+//! assert_eq!(terminal.cell(0, 0).get_style(), Color::RED.bold());
+//! assert_eq!(terminal.cell(0, 7).get_style(), Style::new());
+//! ```
+//!
+//! Requires the `testing` feature.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    Style,
+    parse::Sequence,
+    sgr,
+    wrap::{Unit, Units},
+};
+
+/// A character cell of a [`VirtualTerminal`], with the style it was written with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    char: char,
+    style: Style,
+}
+
+impl Cell {
+    /// A cell with no content: a space with no styling.
+    pub const BLANK: Cell = Cell::new(' ', Style::new());
+
+    /// Creates a new cell.
+    #[must_use]
+    pub const fn new(char: char, style: Style) -> Self {
+        Self { char, style }
+    }
+
+    /// Gets the character.
+    #[must_use]
+    pub const fn get_char(&self) -> char {
+        self.char
+    }
+
+    /// Gets the style.
+    #[must_use]
+    pub const fn get_style(&self) -> Style {
+        self.style
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell::BLANK
+    }
+}
+
+/// A tiny terminal emulator that interprets rendered output into a grid of styled cells.
+///
+/// The grid has a fixed number of columns, and grows by as many lines as are written. Rows and columns are 0-based.
+/// Each character takes one cell, and the cursor wraps to the next line when it passes the last column. The
+/// following are interpreted:
+/// - SGR sequences, which change the style of the characters written after them;
+/// - line feed, carriage return, backspace and tab;
+/// - the [`cursor`](crate::cursor) moves, and saving and restoring the cursor;
+/// - the [`erase`](crate::erase) sequences. The erased cells become [blank](Cell::BLANK).
+///
+/// Any other escape sequence or control character is ignored. A line feed also moves the cursor to the start of the
+/// line, like in terminals that translate it into a carriage return and a line feed.
+///
+/// The cursor moves made by escape sequences are limited to the first [`MAX_MOVE_ROWS`](Self::MAX_MOVE_ROWS)
+/// lines, so arbitrary output can't make the grid grow without bound. Only line feeds and wrapping move the cursor
+/// beyond them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VirtualTerminal {
+    width: usize,
+    lines: Vec<Vec<Cell>>,
+    row: usize,
+    column: usize,
+    style: Style,
+    saved: (usize, usize, Style),
+}
+
+impl VirtualTerminal {
+    const TAB_WIDTH: usize = 8;
+
+    /// The number of lines the cursor can be moved within by escape sequences.
+    pub const MAX_MOVE_ROWS: usize = 10_000;
+
+    /// Creates a new, empty terminal with the given number of columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is zero.
+    #[must_use]
+    pub fn new(width: usize) -> Self {
+        assert!(width > 0, "the width must not be zero");
+        Self {
+            width,
+            lines: Vec::new(),
+            row: 0,
+            column: 0,
+            style: Style::new(),
+            saved: (0, 0, Style::new()),
+        }
+    }
+
+    /// Interprets the given output.
+    ///
+    /// Escape sequences must be complete in each call.
+    pub fn feed(&mut self, output: &str) {
+        for unit in Units(output) {
+            match unit {
+                Unit::Sequence(Sequence::Sgr(params), _) => {
+                    if let Ok(style) = sgr::apply_params(self.style, params) {
+                        self.style = style;
+                    }
+                }
+                Unit::Sequence(Sequence::Other, raw) => self.apply_sequence(raw),
+                Unit::Char(c) => self.apply_char(c),
+            }
+        }
+    }
+
+    /// Gets the number of columns.
+    #[must_use]
+    pub const fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the cursor position, as a row and a column.
+    #[must_use]
+    pub const fn get_cursor(&self) -> (usize, usize) {
+        (self.row, self.column)
+    }
+
+    /// Gets the current style, which is applied to the characters written next.
+    #[must_use]
+    pub const fn get_style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns the number of lines that have been written to, or that the cursor has been on.
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.lines.len().max(self.row + 1)
+    }
+
+    /// Returns the cell at the given row and column. Cells that have never been written to are blank.
+    #[must_use]
+    pub fn cell(&self, row: usize, column: usize) -> Cell {
+        self.line(row).get(column).copied().unwrap_or_default()
+    }
+
+    /// Returns the cells of a line, up to the last one that has been written to.
+    #[must_use]
+    pub fn line(&self, row: usize) -> &[Cell] {
+        self.lines.get(row).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the text of a line, without styling and without trailing spaces.
+    #[must_use]
+    pub fn line_text(&self, row: usize) -> String {
+        let text: String = self.line(row).iter().map(Cell::get_char).collect();
+        String::from(text.trim_end_matches(' '))
+    }
+
+    /// Returns the text of all the lines, without styling and without trailing spaces, separated by line breaks.
+    #[must_use]
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for row in 0..self.line_count() {
+            if row > 0 {
+                text.push('\n');
+            }
+            text.push_str(&self.line_text(row));
+        }
+        text
+    }
+
+    fn apply_char(&mut self, c: char) {
+        match c {
+            '\n' => {
+                self.row += 1;
+                self.column = 0;
+            }
+            '\r' => self.column = 0,
+            '\x08' => self.column = self.column.saturating_sub(1),
+            '\t' => {
+                let next_stop = (self.column / Self::TAB_WIDTH + 1) * Self::TAB_WIDTH;
+                self.column = next_stop.min(self.width - 1);
+            }
+            c if c.is_control() => {}
+            c => {
+                if self.column >= self.width {
+                    self.row += 1;
+                    self.column = 0;
+                }
+                let (row, column) = (self.row, self.column);
+                let cell = Cell::new(c, self.style);
+                *self.cell_mut(row, column) = cell;
+                self.column += 1;
+            }
+        }
+    }
+
+    fn apply_sequence(&mut self, raw: &str) {
+        match raw {
+            "\x1b7" => self.saved = (self.row, self.column, self.style),
+            "\x1b8" => (self.row, self.column, self.style) = self.saved,
+            _ => {
+                if let Some(csi) = raw.strip_prefix("\x1b[")
+                    && let Some(final_char) = csi.chars().last()
+                {
+                    self.apply_csi(&csi[..csi.len() - 1], final_char);
+                }
+            }
+        }
+    }
+
+    fn apply_csi(&mut self, params: &str, final_char: char) {
+        let mut numbers = params.split(';').map(|param| param.parse::<usize>().ok());
+        let mut next = |default: usize| match numbers.next().flatten() {
+            None | Some(0) => default,
+            Some(n) => n,
+        };
+        let last_column = self.width - 1;
+        match final_char {
+            'A' => self.row = self.row.saturating_sub(next(1)),
+            'B' => self.row = self.moved_row(self.row.saturating_add(next(1))),
+            'C' => self.column = self.column.saturating_add(next(1)).min(last_column),
+            'D' => self.column = self.column.min(last_column).saturating_sub(next(1)),
+            'E' => (self.row, self.column) = (self.moved_row(self.row.saturating_add(next(1))), 0),
+            'F' => (self.row, self.column) = (self.row.saturating_sub(next(1)), 0),
+            'G' => self.column = (next(1) - 1).min(last_column),
+            'H' => {
+                let row = next(1) - 1;
+                let column = next(1) - 1;
+                (self.row, self.column) = (self.moved_row(row), column.min(last_column));
+            }
+            'J' if params.is_empty() || params == "0" => {
+                self.erase_line(self.column..usize::MAX);
+                self.lines.truncate(self.row + 1);
+            }
+            'J' if params == "1" => {
+                for row in 0..self.row.min(self.lines.len()) {
+                    self.lines[row].clear();
+                }
+                self.erase_line(0..self.column + 1);
+            }
+            'J' if params == "2" => self.lines.clear(),
+            'K' if params.is_empty() || params == "0" => self.erase_line(self.column..usize::MAX),
+            'K' if params == "1" => self.erase_line(0..self.column + 1),
+            'K' if params == "2" => self.erase_line(0..usize::MAX),
+            _ => {}
+        }
+    }
+
+    /// Limits the row a cursor move goes to. The cursor is not moved up if it is already beyond the limit.
+    fn moved_row(&self, row: usize) -> usize {
+        row.min(self.row.max(Self::MAX_MOVE_ROWS - 1))
+    }
+
+    fn erase_line(&mut self, columns: core::ops::Range<usize>) {
+        if let Some(line) = self.lines.get_mut(self.row) {
+            let end = columns.end.min(line.len());
+            if columns.start < end {
+                line[columns.start..end].fill(Cell::BLANK);
+            }
+        }
+    }
+
+    fn cell_mut(&mut self, row: usize, column: usize) -> &mut Cell {
+        if self.lines.len() <= row {
+            self.lines.resize(row + 1, Vec::new());
+        }
+        let line = &mut self.lines[row];
+        if line.len() <= column {
+            line.resize(column + 1, Cell::BLANK);
+        }
+        &mut line[column]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AppliedTo as _, ToStyle as _, ToStyleSet as _,
+        color::Color,
+        cursor,
+        erase::{EraseInDisplay, EraseInLine},
+    };
+
+    use super::*;
+
+    fn terminal(output: &str) -> VirtualTerminal {
+        let mut terminal = VirtualTerminal::new(10);
+        terminal.feed(output);
+        terminal
+    }
+
+    #[test]
+    fn text() {
+        let vt = terminal("");
+        assert_eq!(vt.get_width(), 10);
+        assert_eq!(vt.line_count(), 1);
+        assert_eq!(vt.text(), "");
+        assert_eq!(vt.cell(5, 5), Cell::BLANK);
+
+        let vt = terminal("abc\ndef");
+        assert_eq!(vt.text(), "abc\ndef");
+        assert_eq!(vt.get_cursor(), (1, 3));
+        assert_eq!(
+            vt.line(1),
+            ['d', 'e', 'f'].map(|c| Cell::new(c, Style::new()))
+        );
+        assert_eq!(vt.line(2), []);
+
+        assert_eq!(terminal("0123456789abc").text(), "0123456789\nabc");
+        assert_eq!(terminal("abc\rd\x08e\tf").text(), "ebc     f");
+        assert_eq!(terminal("a\n\n").text(), "a\n\n");
+    }
+
+    #[test]
+    fn styles() {
+        let output = format!(
+            "{}{}\x1b[999m",
+            Color::RED.bold().applied_to("ab"),
+            Color::BLUE.applied_to("c")
+        );
+        let vt = terminal(&output);
+        assert_eq!(vt.text(), "abc");
+        assert_eq!(vt.cell(0, 0), Cell::new('a', Color::RED.bold()));
+        assert_eq!(vt.cell(0, 1), Cell::new('b', Color::RED.bold()));
+        assert_eq!(vt.cell(0, 2), Cell::new('c', Color::BLUE.to_style()));
+        assert_eq!(vt.get_style(), Style::new());
+    }
+
+    #[test]
+    fn cursor_moves() {
+        let output = format!(
+            "abc\ndef{}X{}Y{}Z{}W",
+            cursor::Up(1),
+            cursor::MoveTo::new(2, 1),
+            cursor::Column(9),
+            cursor::PreviousLine(1)
+        );
+        assert_eq!(terminal(&output).text(), "WbcX\nYef     Z");
+
+        let output = format!("ab{}c{}d", cursor::Left(5), cursor::Right(20));
+        assert_eq!(terminal(&output).text(), "cb       d");
+
+        let output = format!("ab{}cd{}e", cursor::Save, cursor::Restore);
+        assert_eq!(terminal(&output).text(), "abed");
+    }
+
+    #[test]
+    fn huge_cursor_moves() {
+        let max_row = VirtualTerminal::MAX_MOVE_ROWS - 1;
+
+        let vt = terminal("\x1b[18446744073709551615B");
+        assert_eq!(vt.get_cursor(), (max_row, 0));
+        assert_eq!(vt.line_count(), VirtualTerminal::MAX_MOVE_ROWS);
+
+        let vt = terminal("a\x1b[18446744073709551615E\x1b[18446744073709551615Cb");
+        assert_eq!(vt.get_cursor(), (max_row, 10));
+        assert_eq!(vt.line_text(max_row), "         b");
+
+        let vt = terminal("\x1b[1000000000;1000000000Hx\x1b[3A\ry");
+        assert_eq!(vt.line_count(), VirtualTerminal::MAX_MOVE_ROWS);
+        assert_eq!(vt.line_text(max_row), "         x");
+        assert_eq!(vt.line_text(max_row - 3), "y");
+
+        let vt = terminal(&format!("{}\n\x1b[5B", "\n".repeat(max_row)));
+        assert_eq!(vt.get_cursor(), (max_row + 1, 0));
+    }
+
+    #[test]
+    fn erase() {
+        let output = format!("abcdef{}{}", cursor::Column(3), EraseInLine::ToEnd);
+        assert_eq!(terminal(&output).text(), "ab");
+        let output = format!("abcdef{}{}", cursor::Column(3), EraseInLine::ToStart);
+        assert_eq!(terminal(&output).text(), "   def");
+        let output = format!("abc\ndef{}", EraseInLine::All);
+        assert_eq!(terminal(&output).text(), "abc\n");
+
+        let output = format!(
+            "abc\ndef\nghi{}{}",
+            cursor::MoveTo::new(2, 2),
+            EraseInDisplay::ToEnd
+        );
+        assert_eq!(terminal(&output).text(), "abc\nd");
+        let output = format!(
+            "abc\ndef\nghi{}{}",
+            cursor::MoveTo::new(2, 2),
+            EraseInDisplay::ToStart
+        );
+        assert_eq!(terminal(&output).text(), "\n  f\nghi");
+        let output = format!("abc\ndef{}", EraseInDisplay::All);
+        assert_eq!(terminal(&output).text(), "\n");
+    }
+}
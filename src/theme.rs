@@ -0,0 +1,418 @@
+//! Semantic themes.
+//!
+//! A [`Theme`] maps semantic [`Key`]s, like [`Key::Error`] or [`Key::Prompt`], to [`Style`]s, so that
+//! styling decisions can be made in a single place:
+//!
+//! ```
+//! use fluent_ansi::{prelude::*, theme::{Key, Theme}};
+//!
+//! let theme = Theme::default();
+//! let message = theme.style(Key::Error).applied_to("Something went wrong");
+//!
+//! assert_eq!(format!("{message}"), "\x1b[1;31mSomething went wrong\x1b[0m");
+//! ```
+//!
+//! A theme can be customized by setting or overriding entries:
+//!
+//! ```
+//! use fluent_ansi::{prelude::*, theme::{Key, Theme}};
+//!
+//! let overrides = Theme::new().with(Key::Error, Color::MAGENTA.underline());
+//! let theme = Theme::default().merge(&overrides);
+//!
+//! assert_eq!(theme.style(Key::Error), Color::MAGENTA.underline());
+//! assert_eq!(theme.style(Key::Warning), Theme::default().style(Key::Warning));
+//! ```
+
+use enum_iterator::Sequence;
+
+use crate::{ParseStyleError, Style, StyleMap, ToStyleSet as _, color::Color, style::word_span};
+
+/// A semantic key in a [`Theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
+pub enum Key {
+    /// Error messages.
+    Error,
+    /// Warning messages.
+    Warning,
+    /// Informational messages.
+    Info,
+    /// Debugging messages.
+    Debug,
+    /// Tracing messages.
+    Trace,
+    /// Success messages.
+    Success,
+    /// Hints and suggestions.
+    Hint,
+    /// Prompts asking for user input.
+    Prompt,
+    /// Emphasized content.
+    Emphasis,
+    /// De-emphasized content.
+    Muted,
+}
+
+impl Key {
+    const COUNT: usize = enum_iterator::cardinality::<Key>();
+
+    /// Returns the name of the key, like `"error"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Key::Error => "error",
+            Key::Warning => "warning",
+            Key::Info => "info",
+            Key::Debug => "debug",
+            Key::Trace => "trace",
+            Key::Success => "success",
+            Key::Hint => "hint",
+            Key::Prompt => "prompt",
+            Key::Emphasis => "emphasis",
+            Key::Muted => "muted",
+        }
+    }
+
+    /// Returns the key with the given name, ignoring ASCII case.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Key> {
+        enum_iterator::all::<Key>().find(|key| name.eq_ignore_ascii_case(key.name()))
+    }
+}
+
+/// A mapping from semantic [`Key`]s to [`Style`]s.
+///
+/// [`Theme::new()`] creates an empty theme, and [`Theme::default()`] creates a theme with a style
+/// for every key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    styles: [Option<Style>; Key::COUNT],
+}
+
+impl Theme {
+    /// Creates a new theme without any entries.
+    #[must_use]
+    pub const fn new() -> Self {
+        Theme {
+            styles: [None; Key::COUNT],
+        }
+    }
+
+    /// Creates a theme with the styles used by [`env_logger`](https://docs.rs/env_logger) for the log levels.
+    ///
+    /// Only the [`Error`](Key::Error), [`Warning`](Key::Warning), [`Info`](Key::Info), [`Debug`](Key::Debug) and
+    /// [`Trace`](Key::Trace) keys have entries. Merge it into [`Theme::default()`] to have a style for every key:
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, theme::{Key, Theme}, Style};
+    ///
+    /// let theme = Theme::default().merge(&Theme::env_logger());
+    /// assert_eq!(theme.style(Key::Warning), Style::new().fg(Color::YELLOW));
+    /// assert_eq!(theme.style(Key::Muted), Theme::default().style(Key::Muted));
+    /// ```
+    #[must_use]
+    pub fn env_logger() -> Self {
+        Theme::new()
+            .with(Key::Error, Color::RED.bold())
+            .with(Key::Warning, Color::YELLOW)
+            .with(Key::Info, Color::GREEN)
+            .with(Key::Debug, Color::BLUE)
+            .with(Key::Trace, Color::CYAN)
+    }
+
+    /// Parses theme entries from a `key=SGR-params` list, like the ones read from environment variables.
+    ///
+    /// The list has the same format as the one parsed by [`StyleMap`], with entries separated by `:` and SGR
+    /// parameters separated by `;`. The keys are [`Key`] names, and `warn` is also accepted for
+    /// [`Warning`](Key::Warning), as in the log level names. Entries with unknown keys are ignored, even if their values
+    /// are not valid SGR parameters, so lists shared with other programs can be used.
+    ///
+    /// The result is usually merged into another theme:
+    ///
+    /// ```
+    /// use fluent_ansi::{prelude::*, theme::{Key, Theme}, Style};
+    ///
+    /// // Read from an environment variable, like `MY_APP_LOG_STYLE`
+    /// let overrides = Theme::parse_overrides("error=01;35:warn=4:fn=32").unwrap();
+    /// let theme = Theme::env_logger().merge(&overrides);
+    ///
+    /// assert_eq!(theme.style(Key::Error), Color::MAGENTA.bold());
+    /// assert_eq!(theme.style(Key::Warning), Style::new().underline());
+    /// assert_eq!(theme.style(Key::Info), Style::new().fg(Color::GREEN));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseStyleError`] if the parameters of any entry with a known key are invalid. Its span is
+    /// relative to the whole string.
+    pub fn parse_overrides(s: &str) -> Result<Self, ParseStyleError> {
+        let known_entries = s.split(':').filter(|entry| {
+            entry
+                .split_once('=')
+                .is_some_and(|(name, _)| override_key(name).is_some())
+        });
+        for entry in known_entries {
+            StyleMap::parse(entry).map_err(|error| error.offset_by(word_span(s, entry).start))?;
+        }
+
+        let map = StyleMap::parse_lossy(s);
+        Ok(map.iter().fold(Theme::new(), |theme, (name, style)| {
+            match override_key(name) {
+                Some(key) => theme.with(key, style),
+                None => theme,
+            }
+        }))
+    }
+
+    /// Returns a new theme with the given style associated with the given key.
+    #[must_use]
+    pub fn with(self, key: Key, style: impl Into<Style>) -> Self {
+        self.set(key, Some(style.into()))
+    }
+
+    /// Returns a new theme without an entry for the given key.
+    #[must_use]
+    pub fn without(self, key: Key) -> Self {
+        self.set(key, None)
+    }
+
+    /// Returns a new theme with the given entry for the given key.
+    #[must_use]
+    pub fn set(self, key: Key, style: Option<Style>) -> Self {
+        let mut styles = self.styles;
+        styles[key as usize] = style;
+        Theme { styles }
+    }
+
+    /// Gets the entry for the given key.
+    #[must_use]
+    pub fn get(&self, key: Key) -> Option<Style> {
+        self.styles[key as usize]
+    }
+
+    /// Gets the style for the given key, or an empty style if there is no entry for it.
+    #[must_use]
+    pub fn style(&self, key: Key) -> Style {
+        self.get(key).unwrap_or_default()
+    }
+
+    /// Returns a new theme with the entries from `overrides` replacing the entries in this theme.
+    ///
+    /// The keys without an entry in `overrides` keep the entries from this theme.
+    #[must_use]
+    pub fn merge(self, overrides: &Theme) -> Self {
+        overrides
+            .iter()
+            .fold(self, |theme, (key, style)| theme.with(key, style))
+    }
+
+    /// Returns an iterator over the keys that have an entry and their styles.
+    #[must_use]
+    pub fn iter(&self) -> ThemeIter<'_> {
+        ThemeIter {
+            keys: enum_iterator::all(),
+            theme: self,
+        }
+    }
+}
+
+impl Default for Theme {
+    /// Creates a theme with a style for every key.
+    fn default() -> Self {
+        Theme::new()
+            .with(Key::Error, Color::RED.bold())
+            .with(Key::Warning, Color::YELLOW.bold())
+            .with(Key::Info, Color::GREEN)
+            .with(Key::Debug, Color::BLUE)
+            .with(Key::Trace, Color::CYAN)
+            .with(Key::Success, Color::GREEN.bold())
+            .with(Key::Hint, Color::CYAN.italic())
+            .with(Key::Prompt, Style::new().bold())
+            .with(Key::Emphasis, Style::new().bold())
+            .with(Key::Muted, Style::new().faint())
+    }
+}
+
+impl<'a> IntoIterator for &'a Theme {
+    type Item = (Key, Style);
+    type IntoIter = ThemeIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a [`Theme`].
+pub struct ThemeIter<'a> {
+    keys: enum_iterator::All<Key>,
+    theme: &'a Theme,
+}
+
+impl Iterator for ThemeIter<'_> {
+    type Item = (Key, Style);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let theme = self.theme;
+        self.keys
+            .by_ref()
+            .find_map(|key| theme.get(key).map(|style| (key, style)))
+    }
+}
+
+/// Gets the key of an entry parsed by [`Theme::parse_overrides()`].
+fn override_key(name: &str) -> Option<Key> {
+    if name.eq_ignore_ascii_case("warn") {
+        Some(Key::Warning)
+    } else {
+        Key::from_name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, assert_display, color::BasicColor};
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let theme = Theme::new();
+
+        for key in enum_iterator::all::<Key>() {
+            assert_eq!(theme.get(key), None);
+            assert_eq!(theme.style(key), Style::new());
+        }
+    }
+
+    #[test]
+    fn default() {
+        let theme = Theme::default();
+
+        for key in enum_iterator::all::<Key>() {
+            assert!(theme.get(key).is_some(), "{key:?}");
+        }
+        assert_eq!(
+            theme.style(Key::Error),
+            Style::new().bold().fg(BasicColor::Red)
+        );
+    }
+
+    #[test]
+    fn with_and_without() {
+        let theme = Theme::new().with(Key::Error, BasicColor::Red);
+        assert_eq!(
+            theme.get(Key::Error),
+            Some(Style::new().fg(BasicColor::Red))
+        );
+        assert_eq!(theme.get(Key::Warning), None);
+
+        let theme = theme.without(Key::Error);
+        assert_eq!(theme, Theme::new());
+    }
+
+    #[test]
+    fn merge() {
+        let base = Theme::new()
+            .with(Key::Error, BasicColor::Red)
+            .with(Key::Warning, BasicColor::Yellow);
+        let overrides = Theme::new()
+            .with(Key::Error, BasicColor::Magenta)
+            .with(Key::Info, BasicColor::Green);
+
+        let theme = base.merge(&overrides);
+
+        assert_eq!(
+            theme.style(Key::Error),
+            Style::new().fg(BasicColor::Magenta)
+        );
+        assert_eq!(
+            theme.style(Key::Warning),
+            Style::new().fg(BasicColor::Yellow)
+        );
+        assert_eq!(theme.style(Key::Info), Style::new().fg(BasicColor::Green));
+        assert_eq!(theme.get(Key::Debug), None);
+    }
+
+    #[test]
+    fn iter() {
+        let theme = Theme::new()
+            .with(Key::Warning, BasicColor::Yellow)
+            .with(Key::Error, BasicColor::Red);
+        let mut iter = theme.iter();
+
+        assert_eq!(
+            iter.next(),
+            Some((Key::Error, Style::new().fg(BasicColor::Red)))
+        );
+        assert_eq!(
+            iter.next(),
+            Some((Key::Warning, Style::new().fg(BasicColor::Yellow)))
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn env_logger() {
+        let theme = Theme::env_logger();
+        assert_eq!(
+            theme.style(Key::Error),
+            Style::new().bold().fg(BasicColor::Red)
+        );
+        assert_eq!(
+            theme.style(Key::Warning),
+            Style::new().fg(BasicColor::Yellow)
+        );
+        assert_eq!(theme.style(Key::Trace), Style::new().fg(BasicColor::Cyan));
+        assert_eq!(theme.iter().count(), 5);
+        assert_eq!(theme.get(Key::Success), None);
+    }
+
+    #[test]
+    fn parse_overrides() {
+        assert_eq!(Theme::parse_overrides(""), Ok(Theme::new()));
+        assert_eq!(
+            Theme::parse_overrides("WARN=33:info=1;32:info=34:unknown=31:ne"),
+            Ok(Theme::new()
+                .with(Key::Warning, BasicColor::Yellow)
+                .with(Key::Info, BasicColor::Blue))
+        );
+        assert_eq!(
+            Theme::parse_overrides("muted=2:hint=3;x")
+                .unwrap_err()
+                .span(),
+            15..16
+        );
+    }
+
+    #[test]
+    fn parse_overrides_with_foreign_entries() {
+        assert_eq!(
+            Theme::parse_overrides("ln=target:error=31:*.tar=x;y"),
+            Ok(Theme::new().with(Key::Error, BasicColor::Red))
+        );
+        assert_eq!(
+            Theme::parse_overrides("ln=target:error=31;x")
+                .unwrap_err()
+                .span(),
+            19..20
+        );
+    }
+
+    #[test]
+    fn key_names() {
+        for key in enum_iterator::all::<Key>() {
+            assert_eq!(Key::from_name(key.name()), Some(key));
+        }
+        assert_eq!(Key::from_name("ERROR"), Some(Key::Error));
+        assert_eq!(Key::from_name("err"), None);
+    }
+
+    #[test]
+    fn applied_to() {
+        let theme = Theme::new().with(Key::Error, BasicColor::Red);
+        assert_display!(
+            theme.style(Key::Error).applied_to("CONTENT"),
+            "\x1b[31mCONTENT\x1b[0m"
+        );
+    }
+}
@@ -66,6 +66,12 @@ pub trait ToStyleSet: Sized {
         self.effect(Effect::Blink)
     }
 
+    /// Sets the rapid blink effect.
+    #[must_use]
+    fn rapid_blink(self) -> Self::StyleSet {
+        self.effect(Effect::RapidBlink)
+    }
+
     /// Sets the reverse effect.
     #[must_use]
     fn reverse(self) -> Self::StyleSet {
@@ -84,18 +90,48 @@ pub trait ToStyleSet: Sized {
         self.effect(Effect::Strikethrough)
     }
 
+    /// Sets the Fraktur effect.
+    #[must_use]
+    fn fraktur(self) -> Self::StyleSet {
+        self.effect(Effect::Fraktur)
+    }
+
     /// Sets the double underline effect.
     #[must_use]
     fn double_underline(self) -> Self::StyleSet {
         self.effect(Effect::DoubleUnderline)
     }
 
+    /// Sets the framed effect.
+    #[must_use]
+    fn framed(self) -> Self::StyleSet {
+        self.effect(Effect::Framed)
+    }
+
+    /// Sets the encircled effect.
+    #[must_use]
+    fn encircled(self) -> Self::StyleSet {
+        self.effect(Effect::Encircled)
+    }
+
     /// Sets the overline effect.
     #[must_use]
     fn overline(self) -> Self::StyleSet {
         self.effect(Effect::Overline)
     }
 
+    /// Sets the superscript effect.
+    #[must_use]
+    fn superscript(self) -> Self::StyleSet {
+        self.effect(Effect::Superscript)
+    }
+
+    /// Sets the subscript effect.
+    #[must_use]
+    fn subscript(self) -> Self::StyleSet {
+        self.effect(Effect::Subscript)
+    }
+
     /// Sets the given effect.
     #[must_use]
     fn effect(self, effect: impl Into<Effect>) -> Self::StyleSet {
@@ -199,11 +235,17 @@ mod tests {
                     assert_effect_method!(Effect::Italic, italic);
                     assert_effect_method!(Effect::Underline, underline);
                     assert_effect_method!(Effect::Blink, blink);
+                    assert_effect_method!(Effect::RapidBlink, rapid_blink);
                     assert_effect_method!(Effect::Reverse, reverse);
                     assert_effect_method!(Effect::Conceal, conceal);
                     assert_effect_method!(Effect::Strikethrough, strikethrough);
+                    assert_effect_method!(Effect::Fraktur, fraktur);
                     assert_effect_method!(Effect::DoubleUnderline, double_underline);
+                    assert_effect_method!(Effect::Framed, framed);
+                    assert_effect_method!(Effect::Encircled, encircled);
                     assert_effect_method!(Effect::Overline, overline);
+                    assert_effect_method!(Effect::Superscript, superscript);
+                    assert_effect_method!(Effect::Subscript, subscript);
                 }
 
                 #[test]
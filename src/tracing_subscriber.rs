@@ -0,0 +1,318 @@
+use core::fmt::{self, Debug, Display};
+
+use alloc::vec::Vec;
+use tracing_core::{
+    Event, Level, Subscriber,
+    field::{Field, Visit},
+};
+use tracing_subscriber::{
+    field::RecordFields,
+    fmt::{FmtContext, FormatEvent, FormatFields, FormattedFields, format::Writer},
+    registry::LookupSpan,
+};
+
+use crate::{
+    AppliedTo as _, Sanitized, Style,
+    theme::{Key, Theme},
+};
+
+/// Maps a tracing level to the theme key with the same meaning.
+impl From<Level> for Key {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::ERROR => Key::Error,
+            Level::WARN => Key::Warning,
+            Level::INFO => Key::Info,
+            Level::DEBUG => Key::Debug,
+            Level::TRACE => Key::Trace,
+        }
+    }
+}
+
+/// A [`tracing-subscriber`](https://docs.rs/tracing-subscriber) event formatter that styles the events with a
+/// [`Theme`].
+///
+/// An event is rendered as its level padded to 5 characters, the names and fields of the spans it is in, its target,
+/// and its fields, like `WARN  request{id=7}: app: disk full free=0`. The level is styled with the theme style for
+/// its [`Key`], the span names with the [`Key::Emphasis`] style, and the target with the [`Key::Muted`] style.
+///
+/// The fields are formatted by the subscriber's field formatter, which is usually set to a [`TracingFields`] value:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, TracingFields, TracingFormatter, theme::Theme};
+///
+/// let theme = Theme::default();
+/// let subscriber = tracing_subscriber::fmt()
+///     .event_format(TracingFormatter::new(theme))
+///     .fmt_fields(TracingFields::new(theme).with_field("user", Color::CYAN))
+///     .finish();
+/// ```
+///
+/// No escape sequences are written when the subscriber is configured to not emit ANSI escape codes.
+///
+/// Requires the `tracing-subscriber` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TracingFormatter {
+    theme: Theme,
+}
+
+impl TracingFormatter {
+    /// Creates a new `TracingFormatter` value with the given theme.
+    #[must_use]
+    pub const fn new(theme: Theme) -> Self {
+        Self { theme }
+    }
+
+    /// Gets the theme.
+    #[must_use]
+    pub const fn get_theme(&self) -> &Theme {
+        &self.theme
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for TracingFormatter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let ansi = writer.has_ansi_escapes();
+        let style = |key: Key| {
+            if ansi {
+                self.theme.style(key)
+            } else {
+                Style::new()
+            }
+        };
+        let metadata = event.metadata();
+
+        write!(
+            writer,
+            "{:<5} ",
+            style(Key::from(*metadata.level())).applied_to(metadata.level().as_str())
+        )?;
+        if let Some(scope) = ctx.event_scope() {
+            for span in scope.from_root() {
+                write!(writer, "{}", style(Key::Emphasis).applied_to(span.name()))?;
+                let extensions = span.extensions();
+                if let Some(fields) = extensions.get::<FormattedFields<N>>()
+                    && !fields.is_empty()
+                {
+                    write!(writer, "{{{fields}}}")?;
+                }
+                writer.write_str(": ")?;
+            }
+        }
+        write!(
+            writer,
+            "{}: ",
+            style(Key::Muted).applied_to(metadata.target())
+        )?;
+        ctx.format_fields(writer.by_ref(), event)?;
+        writeln!(writer)
+    }
+}
+
+/// A [`tracing-subscriber`](https://docs.rs/tracing-subscriber) field formatter that styles the fields with a
+/// [`Theme`] and per-field styles.
+///
+/// The `message` field is rendered as its value, and the other fields as `name=value`, separated by spaces. The
+/// names are styled with the [`Key::Muted`] theme style, and the values with the style set for the field with
+/// [`with_field()`](TracingFields::with_field), if any. The values are wrapped in [`Sanitized`], so the escape
+/// sequences they contain can't mess with the terminal.
+///
+/// See [`TracingFormatter`].
+///
+/// Requires the `tracing-subscriber` feature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct TracingFields {
+    theme: Theme,
+    field_styles: Vec<(&'static str, Style)>,
+}
+
+impl TracingFields {
+    /// Creates a new `TracingFields` value with the given theme, and no per-field styles.
+    #[must_use]
+    pub const fn new(theme: Theme) -> Self {
+        Self {
+            theme,
+            field_styles: Vec::new(),
+        }
+    }
+
+    /// Gets the theme.
+    #[must_use]
+    pub const fn get_theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Returns a new value with the given style for the values of the field with the given name.
+    #[must_use]
+    pub fn with_field(mut self, name: &'static str, style: impl Into<Style>) -> Self {
+        let style = style.into();
+        match self.field_styles.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = style,
+            None => self.field_styles.push((name, style)),
+        }
+        self
+    }
+
+    /// Gets the style for the values of the field with the given name, or an empty style if none is set.
+    #[must_use]
+    pub fn field_style(&self, name: &str) -> Style {
+        self.field_styles
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|&(_, style)| style)
+            .unwrap_or_default()
+    }
+}
+
+impl<'writer> FormatFields<'writer> for TracingFields {
+    fn format_fields<R: RecordFields>(&self, writer: Writer<'writer>, fields: R) -> fmt::Result {
+        let mut visitor = FieldVisitor {
+            fields: self,
+            ansi: writer.has_ansi_escapes(),
+            writer,
+            first: true,
+            result: Ok(()),
+        };
+        fields.record(&mut visitor);
+        visitor.result
+    }
+}
+
+/// Writes the fields visited with the styles from a [`TracingFields`] value.
+struct FieldVisitor<'a, 'writer> {
+    fields: &'a TracingFields,
+    writer: Writer<'writer>,
+    ansi: bool,
+    first: bool,
+    result: fmt::Result,
+}
+
+impl FieldVisitor<'_, '_> {
+    fn record(&mut self, field: &Field, value: &dyn Display) {
+        if self.result.is_err() {
+            return;
+        }
+        let style = |style: Style| if self.ansi { style } else { Style::new() };
+        let value = style(self.fields.field_style(field.name())).applied_to(Sanitized::new(value));
+        let separator = if self.first { "" } else { " " };
+        self.first = false;
+        self.result = if field.name() == "message" {
+            write!(self.writer, "{separator}{value}")
+        } else {
+            let name = style(self.fields.theme.style(Key::Muted)).applied_to(field.name());
+            write!(self.writer, "{separator}{name}={value}")
+        };
+    }
+}
+
+impl Visit for FieldVisitor<'_, '_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, &value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.record(field, &format_args!("{value:?}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io,
+        string::String,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing_subscriber::fmt::MakeWriter;
+
+    use crate::{ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl MakeWriter<'_> for Buffer {
+        type Writer = Buffer;
+
+        fn make_writer(&self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn capture(ansi: bool, f: impl FnOnce()) -> String {
+        let theme = Theme::new()
+            .with(Key::Info, Color::GREEN)
+            .with(Key::Muted, Style::new().faint());
+        let buffer = Buffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(buffer.clone())
+            .with_ansi(ansi)
+            .event_format(TracingFormatter::new(theme))
+            .fmt_fields(TracingFields::new(theme).with_field("user", Color::CYAN))
+            .finish();
+        tracing::subscriber::with_default(subscriber, f);
+        let output = buffer.0.lock().unwrap().clone();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn level_keys() {
+        assert_eq!(Key::from(Level::ERROR), Key::Error);
+        assert_eq!(Key::from(Level::WARN), Key::Warning);
+        assert_eq!(Key::from(Level::INFO), Key::Info);
+        assert_eq!(Key::from(Level::DEBUG), Key::Debug);
+        assert_eq!(Key::from(Level::TRACE), Key::Trace);
+    }
+
+    #[test]
+    fn format_event() {
+        let output = capture(true, || {
+            tracing::info!(target: "app", user = "eve\x1b[2J", count = 2, "logged in");
+        });
+        assert_eq!(
+            output,
+            "\x1b[32mINFO\x1b[0m  \x1b[2mapp\x1b[0m: logged in \x1b[2muser\x1b[0m=\x1b[36meve\\u{1b}[2J\x1b[0m \
+             \x1b[2mcount\x1b[0m=2\n"
+        );
+    }
+
+    #[test]
+    fn format_event_in_spans() {
+        let output = capture(false, || {
+            let _outer = tracing::info_span!("request", id = 7).entered();
+            let _inner = tracing::info_span!("handler").entered();
+            tracing::warn!(target: "app", "disk full");
+        });
+        assert_eq!(output, "WARN  request{id=7}: handler: app: disk full\n");
+    }
+
+    #[test]
+    fn field_styles() {
+        let fields = TracingFields::default()
+            .with_field("user", Color::CYAN)
+            .with_field("user", Color::RED);
+        assert_eq!(fields.field_style("user"), Style::new().fg(Color::RED));
+        assert_eq!(fields.field_style("other"), Style::new());
+        assert_eq!(fields.get_theme(), &Theme::default());
+    }
+}
@@ -0,0 +1,190 @@
+use core::fmt::{Alignment, Display, Formatter, Result, Write};
+
+/// Returns the visible width of the rendered value, skipping escape sequences and control characters.
+pub(crate) fn visible_width(value: &impl Display) -> usize {
+    measure(value, char_width)
+}
+
+/// Returns the number of visible characters of the rendered value, skipping escape sequences and control characters.
+pub(crate) fn visible_chars(value: &impl Display) -> usize {
+    measure(value, |_| 1)
+}
+
+fn measure(value: &impl Display, char_width: fn(char) -> usize) -> usize {
+    let mut counter = WidthCounter {
+        width: 0,
+        char_width,
+        state: EscapeState::Text,
+    };
+    // Writing to the counter never fails, but the value's `Display` implementation may
+    let _ = write!(counter, "{value}");
+    counter.width
+}
+
+/// Writes a value whose visible width is `content_width`, padded according to the formatter's width, fill and
+/// alignment, which defaults to the left.
+pub(crate) fn write_padded(
+    f: &mut Formatter<'_>,
+    content_width: usize,
+    write: impl FnOnce(&mut Formatter<'_>) -> Result,
+) -> Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(content_width);
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    write(f)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Returns the width of a visible character, in columns.
+///
+/// With the `unicode-width` feature, wide characters, like CJK ideographs and emoji, take two columns, and combining
+/// characters take none. Otherwise, every character takes one column.
+#[cfg(feature = "unicode-width")]
+pub(crate) fn char_width(c: char) -> usize {
+    unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
+/// Returns the width of a visible character, in columns.
+///
+/// With the `unicode-width` feature, wide characters, like CJK ideographs and emoji, take two columns, and combining
+/// characters take none. Otherwise, every character takes one column.
+#[cfg(not(feature = "unicode-width"))]
+pub(crate) fn char_width(_c: char) -> usize {
+    1
+}
+
+/// A writer that measures the visible width of the text written to it.
+struct WidthCounter {
+    width: usize,
+    char_width: fn(char) -> usize,
+    state: EscapeState,
+}
+
+impl Write for WidthCounter {
+    fn write_str(&mut self, s: &str) -> Result {
+        for c in s.chars() {
+            if self.state.advance(c) {
+                self.width += (self.char_width)(c);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The state of a scanner that tells escape sequences apart from visible text, one character at a time.
+///
+/// Since the state is kept between characters, escape sequences split across several writes are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EscapeState {
+    /// In plain text.
+    Text,
+    /// After an `ESC`, possibly followed by intermediate bytes.
+    Escape,
+    /// In a CSI sequence, before its final byte.
+    Csi,
+    /// In a control string, like an OSC sequence, before its terminator.
+    String { bel_terminates: bool },
+    /// After an `ESC` in a control string, which may start the `ESC \` terminator.
+    StringEscape { bel_terminates: bool },
+}
+
+impl EscapeState {
+    /// Advances the state with the given character, returning whether it is visible text.
+    pub(crate) fn advance(&mut self, c: char) -> bool {
+        let (next, visible) = match *self {
+            EscapeState::Text if c == '\x1b' => (EscapeState::Escape, false),
+            EscapeState::Text => (EscapeState::Text, !c.is_control()),
+            EscapeState::Escape => match c {
+                '[' => (EscapeState::Csi, false),
+                ']' => (
+                    EscapeState::String {
+                        bel_terminates: true,
+                    },
+                    false,
+                ),
+                'P' | 'X' | '^' | '_' => (
+                    EscapeState::String {
+                        bel_terminates: false,
+                    },
+                    false,
+                ),
+                '\x20'..='\x2f' => (EscapeState::Escape, false),
+                _ => (EscapeState::Text, false),
+            },
+            EscapeState::Csi if ('\x40'..='\x7e').contains(&c) => (EscapeState::Text, false),
+            EscapeState::Csi => (EscapeState::Csi, false),
+            EscapeState::String { bel_terminates } => match c {
+                '\x07' if bel_terminates => (EscapeState::Text, false),
+                '\x1b' => (EscapeState::StringEscape { bel_terminates }, false),
+                _ => (EscapeState::String { bel_terminates }, false),
+            },
+            EscapeState::StringEscape { bel_terminates } => match c {
+                '\\' => (EscapeState::Text, false),
+                _ => (EscapeState::String { bel_terminates }, false),
+            },
+        };
+        *self = next;
+        visible
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(visible_width(&""), 0);
+        assert_eq!(visible_width(&"abc"), 3);
+        assert_eq!(visible_width(&"ção"), 3);
+        assert_eq!(visible_width(&42), 2);
+        assert_eq!(visible_width(&"a\tb\n"), 2);
+    }
+
+    #[test]
+    fn wide_characters() {
+        let expected = if cfg!(feature = "unicode-width") {
+            9
+        } else {
+            8
+        };
+        assert_eq!(visible_width(&"日本 cafe\u{301}"), expected);
+    }
+
+    #[test]
+    fn escape_sequences() {
+        assert_eq!(visible_width(&Color::RED.bold().applied_to("abc")), 3);
+        assert_eq!(visible_width(&"\x1b[1;31mabc\x1b[0m"), 3);
+        assert_eq!(
+            visible_width(&"\x1b]8;;http://example.com\x1b\\link\x1b]8;;\x07"),
+            4
+        );
+        assert_eq!(visible_width(&"\x1b7a\x1b8\x1b(Bb\x1bPq#0\x1b\\c"), 3);
+    }
+
+    #[test]
+    fn split_escape_sequence() {
+        let mut state = EscapeState::Text;
+        let visible: usize = "a\x1b[3"
+            .chars()
+            .map(|c| usize::from(state.advance(c)))
+            .sum();
+        assert_eq!(visible, 1);
+        assert_eq!(state, EscapeState::Csi);
+        let visible: usize = "1mb".chars().map(|c| usize::from(state.advance(c))).sum();
+        assert_eq!(visible, 1);
+        assert_eq!(state, EscapeState::Text);
+    }
+}
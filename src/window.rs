@@ -0,0 +1,75 @@
+//! Window sequences.
+//!
+//! The size of the terminal's text area can be queried with [`QueryTextAreaSize`], and the terminal's response parsed
+//! with [`TextAreaSizeReport::parse()`]:
+//!
+//! ```
+//! use fluent_ansi::window::{QueryTextAreaSize, TextAreaSizeReport};
+//!
+//! assert_eq!(format!("{QueryTextAreaSize}"), "\x1b[18t");
+//!
+//! // Read from the terminal after writing the query
+//! let response = "\x1b[8;24;80t";
+//! let report = TextAreaSizeReport::parse(response).unwrap();
+//! assert_eq!(report, TextAreaSizeReport { rows: 24, columns: 80 });
+//! ```
+
+use crate::{ParseResponseError, response::parse_csi_response, sequence::fixed_sequence};
+
+fixed_sequence!(
+    /// Queries the size of the terminal's text area, in characters (XTWINOPS 18).
+    ///
+    /// Supporting terminals respond with a report that can be parsed with [`TextAreaSizeReport::parse()`].
+    QueryTextAreaSize,
+    "\x1b[18t"
+);
+
+/// The terminal's response to a [`QueryTextAreaSize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextAreaSizeReport {
+    /// The number of rows.
+    pub rows: u16,
+    /// The number of columns.
+    pub columns: u16,
+}
+
+impl TextAreaSizeReport {
+    /// Parses a response like `ESC [ 8 ; 24 ; 80 t`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response is not a valid text area size report.
+    pub fn parse(response: &str) -> Result<Self, ParseResponseError> {
+        match parse_csi_response(response, 't')? {
+            [8, rows, columns] => Ok(TextAreaSizeReport { rows, columns }),
+            _ => Err(ParseResponseError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_display;
+
+    use super::*;
+
+    #[test]
+    fn text_area_size() {
+        assert_display!(QueryTextAreaSize, "\x1b[18t");
+        assert_eq!(
+            TextAreaSizeReport::parse("\x1b[8;50;132t"),
+            Ok(TextAreaSizeReport {
+                rows: 50,
+                columns: 132
+            })
+        );
+        assert_eq!(
+            TextAreaSizeReport::parse("\x1b[4;600;800t"),
+            Err(ParseResponseError)
+        );
+        assert_eq!(
+            TextAreaSizeReport::parse("\x1b[8;50t"),
+            Err(ParseResponseError)
+        );
+    }
+}
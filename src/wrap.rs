@@ -0,0 +1,245 @@
+use core::fmt::{Display, Formatter, Result, Write};
+
+use crate::{
+    Style,
+    parse::{Sequence, split_sequence},
+    sgr,
+    width::char_width,
+};
+
+/// Wraps text containing escape sequences to the given visible width.
+///
+/// Lines are broken at whitespace, and words wider than the width are broken wherever needed. Escape sequences don't
+/// count towards the width. The style that is active at a line break is reset before the break and applied again
+/// after it, so each line is styled on its own:
+///
+/// ```
+/// use fluent_ansi::{prelude::*, wrap_visible};
+///
+/// let text = format!("{} and more", Color::RED.applied_to("some red words"));
+/// assert_eq!(
+///     wrap_visible(&text, 10).to_string(),
+///     "\x1b[31msome red\x1b[0m\n\x1b[31mwords\x1b[0m and\nmore"
+/// );
+/// ```
+///
+/// The whitespace at a line break is dropped, and the other whitespace between words is written as spaces. Existing
+/// line breaks are kept.
+#[must_use]
+pub fn wrap_visible(text: &str, width: usize) -> WrapVisible<'_> {
+    WrapVisible { text, width }
+}
+
+/// Text wrapped to a visible width.
+///
+/// It is returned by [`wrap_visible()`], and renders the wrapped text when formatted with [`Display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WrapVisible<'a> {
+    text: &'a str,
+    width: usize,
+}
+
+impl Display for WrapVisible<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut wrapper = Wrapper {
+            f,
+            width: self.width,
+            column: 0,
+            wrapped: false,
+            style: Style::new(),
+        };
+        let mut pending_spaces = 0;
+        let mut in_word = false;
+        let mut units = Units(self.text);
+        while let Some(unit) = units.next() {
+            match unit {
+                Unit::Char('\n') => {
+                    pending_spaces = 0;
+                    in_word = false;
+                    wrapper.f.write_char('\n')?;
+                    wrapper.column = 0;
+                    wrapper.wrapped = false;
+                }
+                Unit::Char(c) if c.is_whitespace() => {
+                    pending_spaces += 1;
+                    in_word = false;
+                }
+                unit => {
+                    if !in_word {
+                        in_word = true;
+                        let word_width = unit.width() + Units(units.0).word_width();
+                        wrapper.start_word(pending_spaces, word_width)?;
+                        pending_spaces = 0;
+                    }
+                    match unit {
+                        Unit::Sequence(sequence, raw) => wrapper.write_sequence(sequence, raw)?,
+                        Unit::Char(c) => wrapper.write_char(c)?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Wrapper<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    width: usize,
+    column: usize,
+    wrapped: bool,
+    style: Style,
+}
+
+impl Wrapper<'_, '_> {
+    /// Writes the whitespace before a word, or breaks the line if the word doesn't fit.
+    fn start_word(&mut self, spaces: usize, word_width: usize) -> Result {
+        if self.column > 0 && self.column + spaces + word_width > self.width {
+            self.break_line()
+        } else if self.column > 0 || !self.wrapped {
+            for _ in 0..spaces {
+                self.f.write_char(' ')?;
+            }
+            self.column += spaces;
+            Ok(())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn write_sequence(&mut self, sequence: Sequence<'_>, raw: &str) -> Result {
+        if let Sequence::Sgr(params) = sequence
+            && let Ok(style) = sgr::apply_params(self.style, params)
+        {
+            self.style = style;
+        }
+        self.f.write_str(raw)
+    }
+
+    /// Writes a visible character of a word, breaking the word if it doesn't fit.
+    fn write_char(&mut self, c: char) -> Result {
+        if c.is_control() {
+            return self.f.write_char(c);
+        }
+        let width = char_width(c);
+        if self.column > 0 && self.column + width > self.width {
+            self.break_line()?;
+        }
+        self.f.write_char(c)?;
+        self.column += width;
+        Ok(())
+    }
+
+    fn break_line(&mut self) -> Result {
+        if self.style == Style::new() {
+            self.f.write_char('\n')?;
+        } else {
+            write!(self.f, "{}\n{}", Style::new(), self.style)?;
+        }
+        self.column = 0;
+        self.wrapped = true;
+        Ok(())
+    }
+}
+
+pub(crate) enum Unit<'a> {
+    Sequence(Sequence<'a>, &'a str),
+    Char(char),
+}
+
+impl Unit<'_> {
+    fn width(&self) -> usize {
+        match *self {
+            Unit::Char(c) if !c.is_control() => char_width(c),
+            _ => 0,
+        }
+    }
+}
+
+/// An iterator over the escape sequences and characters of a text.
+pub(crate) struct Units<'a>(pub(crate) &'a str);
+
+impl Units<'_> {
+    /// Consumes the rest of the current word, returning its visible width.
+    fn word_width(&mut self) -> usize {
+        let mut width = 0;
+        for unit in self {
+            if matches!(unit, Unit::Char(c) if c.is_whitespace()) {
+                break;
+            }
+            width += unit.width();
+        }
+        width
+    }
+}
+
+impl<'a> Iterator for Units<'a> {
+    type Item = Unit<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.0;
+        if let Some((sequence, rest)) = split_sequence(text) {
+            self.0 = rest;
+            return Some(Unit::Sequence(sequence, &text[..text.len() - rest.len()]));
+        }
+        let c = text.chars().next()?;
+        self.0 = &text[c.len_utf8()..];
+        Some(Unit::Char(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppliedTo as _, ToStyleSet as _, color::Color};
+
+    use super::*;
+
+    fn wrap(text: &str, width: usize) -> String {
+        wrap_visible(text, width).to_string()
+    }
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(wrap("", 10), "");
+        assert_eq!(wrap("one two three", 20), "one two three");
+        assert_eq!(wrap("one two three", 7), "one two\nthree");
+        assert_eq!(wrap("one two three", 6), "one\ntwo\nthree");
+        assert_eq!(wrap("one   two", 5), "one\ntwo");
+        assert_eq!(wrap("  one two", 6), "  one\ntwo");
+        assert_eq!(wrap("one\n  two three", 9), "one\n  two\nthree");
+        assert_eq!(wrap("one\n\ntwo three", 5), "one\n\ntwo\nthree");
+    }
+
+    #[test]
+    fn long_words() {
+        assert_eq!(wrap("abcdefgh", 3), "abc\ndef\ngh");
+        assert_eq!(wrap("ab cdefgh", 3), "ab\ncde\nfgh");
+        assert_eq!(wrap("abc", 0), "a\nb\nc");
+    }
+
+    #[test]
+    fn styled_text() {
+        let text = Color::RED.bold().applied_to("one two").to_string();
+        assert_eq!(wrap(&text, 4), "\x1b[1;31mone\x1b[0m\n\x1b[1;31mtwo\x1b[0m");
+
+        let text = format!(
+            "{} {}",
+            Color::RED.applied_to("one"),
+            Color::BLUE.applied_to("two")
+        );
+        assert_eq!(wrap(&text, 4), "\x1b[31mone\x1b[0m\n\x1b[34mtwo\x1b[0m");
+
+        assert_eq!(wrap("\x1b[1mab\x1b[22mcd", 3), "\x1b[1mab\x1b[22mc\nd");
+        assert_eq!(
+            wrap("\x1b[1mab\x1b[3mcd", 3),
+            "\x1b[1mab\x1b[3mc\x1b[0m\n\x1b[1;3md"
+        );
+    }
+
+    #[test]
+    fn other_sequences() {
+        assert_eq!(
+            wrap("\x1b]8;;http://example.com\x1b\\link\x1b]8;;\x1b\\ text", 4),
+            "\x1b]8;;http://example.com\x1b\\link\x1b]8;;\x1b\\\ntext"
+        );
+    }
+}